@@ -0,0 +1,19 @@
+-- Store the starting XI and substitutes in separate JSON columns,
+-- matching the shape SquadRepository reads and writes
+ALTER TABLE squads ADD COLUMN starting_xi TEXT NOT NULL DEFAULT '[]'; -- JSON array of SquadPlayer
+ALTER TABLE squads ADD COLUMN substitutes TEXT NOT NULL DEFAULT '[]'; -- JSON array of player IDs
+ALTER TABLE squads DROP COLUMN configuration;
+
+-- Seeded formations used side-specific slot names (DCR, MCL, STR, ...)
+-- that do not exist in the Position enum; map them onto their base positions
+UPDATE formations SET positions =
+    replace(replace(replace(replace(replace(replace(replace(replace(positions,
+        '"DCR"', '"DC"'),
+        '"DCL"', '"DC"'),
+        '"DMCR"', '"DMC"'),
+        '"DMCL"', '"DMC"'),
+        '"MCR"', '"MC"'),
+        '"MCL"', '"MC"'),
+        '"STR"', '"STC"'),
+        '"STL"', '"STC"')
+WHERE is_custom = 0;
@@ -0,0 +1,29 @@
+-- Named mappings from a data source's column headers onto Player fields
+CREATE TABLE IF NOT EXISTS mapping_profiles (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE, -- e.g., "FM Genie Scout"
+    description TEXT,
+
+    -- Column mapping as JSON
+    -- Structure: {source_header: player_field}; a null field marks a column that is deliberately skipped
+    columns TEXT NOT NULL, -- JSON object
+
+    -- Metadata
+    is_custom BOOLEAN DEFAULT 0, -- 0 for built-in, 1 for user-created
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Built-in profiles for the common data sources
+INSERT INTO mapping_profiles (name, description, columns, is_custom) VALUES
+('Football Manager', 'FM squad view exports (text or HTML) using the in-game column abbreviations',
+'{"Name": "name", "Position": "position", "Age": "age", "Transfer Value": "value", "Wage": "wage", "Expires": "contract_expiry", "Cor": "corners", "Cro": "crossing", "Dri": "dribbling", "Fin": "finishing", "Fir": "first_touch", "Fre": "free_kick_taking", "Hea": "heading", "Lon": "long_shots", "L Th": "long_throws", "Mar": "marking", "Pas": "passing", "Pen": "penalty_taking", "Tck": "tackling", "Tec": "technique", "Agg": "aggression", "Ant": "anticipation", "Bra": "bravery", "Cmp": "composure", "Cnt": "concentration", "Dec": "decisions", "Det": "determination", "Fla": "flair", "Ldr": "leadership", "Otb": "off_the_ball", "Pos": "positioning", "Tea": "teamwork", "Vis": "vision", "Wor": "work_rate", "Acc": "acceleration", "Agi": "agility", "Bal": "balance", "Jum": "jumping_reach", "Pac": "pace", "Sta": "stamina", "Str": "strength", "Aer": "aerial_reach", "Cmd": "command_of_area", "Com": "communication", "Ecc": "eccentricity", "Han": "handling", "Kic": "kicking", "1v1": "one_on_ones", "Ref": "reflexes", "Tro": "rushing_out", "Pun": "punching", "Thr": "throwing", "Inf": null, "Club": null, "Division": null, "Personality": null, "Media Handling": null, "Height": null, "Weight": null, "Left Foot": null, "Right Foot": null, "Rec": null}',
+0),
+
+('FM Genie Scout', 'FM Genie Scout CSV exports',
+'{"Name": "name", "Position": "position", "Age": "age", "Nation": "nationality", "Value": "value", "Wage": "wage", "Contract": "contract_expiry", "Corners": "corners", "Crossing": "crossing", "Dribbling": "dribbling", "Finishing": "finishing", "First Touch": "first_touch", "Free Kicks": "free_kick_taking", "Heading": "heading", "Long Shots": "long_shots", "Long Throws": "long_throws", "Marking": "marking", "Passing": "passing", "Penalty Taking": "penalty_taking", "Tackling": "tackling", "Technique": "technique", "Aggression": "aggression", "Anticipation": "anticipation", "Bravery": "bravery", "Composure": "composure", "Concentration": "concentration", "Decisions": "decisions", "Determination": "determination", "Flair": "flair", "Leadership": "leadership", "Off The Ball": "off_the_ball", "Positioning": "positioning", "Teamwork": "teamwork", "Vision": "vision", "Work Rate": "work_rate", "Acceleration": "acceleration", "Agility": "agility", "Balance": "balance", "Jumping": "jumping_reach", "Natural Fitness": "natural_fitness", "Pace": "pace", "Stamina": "stamina", "Strength": "strength", "Aerial Ability": "aerial_reach", "Command Of Area": "command_of_area", "Communication": "communication", "Eccentricity": "eccentricity", "Handling": "handling", "Kicking": "kicking", "One On Ones": "one_on_ones", "Reflexes": "reflexes", "Rushing Out": "rushing_out", "Tendency To Punch": "punching", "Throwing": "throwing", "Club": null, "CA": null, "PA": null}',
+0),
+
+('FMRTE', 'FMRTE CSV exports',
+'{"Name": "name", "Positions": "position", "Age": "age", "Nationality": "nationality", "Value": "value", "Wage": "wage", "ContractExpiryDate": "contract_expiry", "Corners": "corners", "Crossing": "crossing", "Dribbling": "dribbling", "Finishing": "finishing", "FirstTouch": "first_touch", "FreeKicks": "free_kick_taking", "Heading": "heading", "LongShots": "long_shots", "LongThrows": "long_throws", "Marking": "marking", "Passing": "passing", "PenaltyTaking": "penalty_taking", "Tackling": "tackling", "Technique": "technique", "Aggression": "aggression", "Anticipation": "anticipation", "Bravery": "bravery", "Composure": "composure", "Concentration": "concentration", "Decisions": "decisions", "Determination": "determination", "Flair": "flair", "Leadership": "leadership", "OffTheBall": "off_the_ball", "Positioning": "positioning", "Teamwork": "teamwork", "Vision": "vision", "WorkRate": "work_rate", "Acceleration": "acceleration", "Agility": "agility", "Balance": "balance", "Jumping": "jumping_reach", "NaturalFitness": "natural_fitness", "Pace": "pace", "Stamina": "stamina", "Strength": "strength", "AerialAbility": "aerial_reach", "CommandOfArea": "command_of_area", "Communication": "communication", "Eccentricity": "eccentricity", "Handling": "handling", "Kicking": "kicking", "OneOnOnes": "one_on_ones", "Reflexes": "reflexes", "RushingOut": "rushing_out", "TendencyToPunch": "punching", "Throwing": "throwing", "UID": null, "Club": null, "CurrentAbility": null, "PotentialAbility": null}',
+0);
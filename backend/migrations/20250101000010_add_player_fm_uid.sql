@@ -0,0 +1,12 @@
+-- Stable identity for players across re-imports
+ALTER TABLE players ADD COLUMN fm_uid INTEGER; -- FM's unique player ID, when the source provides it
+
+-- NULLs are distinct, so players without an ID are not constrained
+CREATE UNIQUE INDEX idx_players_fm_uid ON players(fm_uid);
+
+-- Fallback identity for sources without an ID
+CREATE INDEX idx_players_identity ON players(name, age, nationality);
+
+-- Let the built-in mapping profiles pick up the ID column
+UPDATE mapping_profiles SET columns = json_set(columns, '$.UID', 'fm_uid')
+WHERE is_custom = 0;
@@ -0,0 +1,13 @@
+-- Dated snapshots of a player's attributes, recorded on every import
+CREATE TABLE IF NOT EXISTS player_attribute_snapshots (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    player_id INTEGER NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    recorded_at DATETIME NOT NULL,
+
+    -- Attribute values as JSON
+    -- Structure: {attribute_name: value}; attributes that were unknown are left out
+    attributes TEXT NOT NULL -- JSON object
+);
+
+-- Create composite index for per-player timelines
+CREATE INDEX idx_player_attribute_snapshots_player ON player_attribute_snapshots(player_id, recorded_at);
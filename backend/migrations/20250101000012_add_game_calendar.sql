@@ -0,0 +1,10 @@
+-- The save's in-game date; ages, contract lengths and snapshot dates follow it
+CREATE TABLE IF NOT EXISTS game_calendar (
+    id INTEGER PRIMARY KEY CHECK(id = 1), -- single row
+    game_date DATE NOT NULL,
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+INSERT INTO game_calendar (id, game_date) VALUES (1, date('now'));
+
+ALTER TABLE players ADD COLUMN date_of_birth DATE;
@@ -0,0 +1,134 @@
+-- One workspace per FM save
+CREATE TABLE IF NOT EXISTS workspaces (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE, -- e.g., "Wrexham 2031"
+    game_date DATE NOT NULL, -- the save's in-game date
+
+    -- Metadata
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Existing data moves into a default workspace, which takes over the game calendar
+INSERT INTO workspaces (id, name, game_date)
+SELECT 1, 'Default', game_date FROM game_calendar WHERE id = 1;
+
+DROP TABLE game_calendar;
+
+-- Save data belongs to a workspace. SQLite can only add a REFERENCES column with a NULL default,
+-- so existing rows are assigned afterwards; the repositories always set it.
+ALTER TABLE players ADD COLUMN workspace_id INTEGER REFERENCES workspaces(id) ON DELETE CASCADE;
+ALTER TABLE squads ADD COLUMN workspace_id INTEGER REFERENCES workspaces(id) ON DELETE CASCADE;
+ALTER TABLE tactics ADD COLUMN workspace_id INTEGER REFERENCES workspaces(id) ON DELETE CASCADE;
+
+UPDATE players SET workspace_id = 1;
+UPDATE squads SET workspace_id = 1;
+UPDATE tactics SET workspace_id = 1;
+
+CREATE INDEX idx_players_workspace ON players(workspace_id);
+CREATE INDEX idx_squads_workspace ON squads(workspace_id);
+CREATE INDEX idx_tactics_workspace ON tactics(workspace_id);
+
+-- Player identity is per workspace: the same FM player can appear in several saves
+DROP INDEX idx_players_fm_uid;
+DROP INDEX idx_players_identity;
+CREATE UNIQUE INDEX idx_players_fm_uid ON players(workspace_id, fm_uid);
+CREATE INDEX idx_players_identity ON players(workspace_id, name, age, nationality);
+
+-- Formations, roles and mapping profiles: predefined rows stay global (workspace_id NULL),
+-- custom rows belong to a workspace. Names are unique within those scopes, which means
+-- rebuilding the tables to drop their global UNIQUE(name).
+CREATE TABLE formations_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL, -- e.g., "4-4-2", "4-3-3", "4-2-3-1"
+    description TEXT,
+
+    -- Formation configuration as JSON
+    -- Structure: array of positions with {position: string, x: number, y: number, role?: string}
+    positions TEXT NOT NULL, -- JSON array
+
+    -- Metadata
+    is_custom BOOLEAN DEFAULT 0, -- 0 for predefined, 1 for user-created
+    workspace_id INTEGER REFERENCES workspaces(id) ON DELETE CASCADE, -- NULL for predefined
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+INSERT INTO formations_new (id, name, description, positions, is_custom, workspace_id, created_at, updated_at)
+SELECT id, name, description, positions, is_custom, CASE WHEN is_custom THEN 1 END, created_at, updated_at
+FROM formations;
+
+-- Dropping formations cascades to squads (and unlinks their tactics), so both are restored afterwards
+CREATE TEMP TABLE squads_backup AS SELECT * FROM squads;
+CREATE TEMP TABLE tactics_squads_backup AS SELECT id, squad_id FROM tactics WHERE squad_id IS NOT NULL;
+
+DROP TABLE formations;
+ALTER TABLE formations_new RENAME TO formations;
+
+INSERT INTO squads SELECT * FROM temp.squads_backup;
+UPDATE tactics
+SET squad_id = (SELECT b.squad_id FROM temp.tactics_squads_backup b WHERE b.id = tactics.id)
+WHERE id IN (SELECT id FROM temp.tactics_squads_backup);
+
+DROP TABLE temp.squads_backup;
+DROP TABLE temp.tactics_squads_backup;
+
+CREATE UNIQUE INDEX idx_formations_workspace_name ON formations(IFNULL(workspace_id, 0), name);
+CREATE INDEX idx_formations_name ON formations(name);
+CREATE INDEX idx_formations_is_custom ON formations(is_custom);
+
+CREATE TABLE roles_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL, -- e.g., "Advanced Playmaker", "Ball-Winning Midfielder"
+    position TEXT NOT NULL, -- GK, DR, DC, DL, WBR, WBL, DMC, MC, MR, ML, AMR, AML, AMC, STC
+    duty TEXT NOT NULL, -- Support, Attack, Defend, Automatic (for GK)
+    description TEXT,
+
+    -- Attribute weights as JSON
+    -- Structure: {attribute_name: weight} where weight is 0-1
+    -- Higher weight = more important for this role
+    attribute_weights TEXT NOT NULL, -- JSON object
+
+    -- Metadata
+    is_custom BOOLEAN DEFAULT 0, -- 0 for predefined, 1 for user-created
+    workspace_id INTEGER REFERENCES workspaces(id) ON DELETE CASCADE, -- NULL for predefined
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+INSERT INTO roles_new (id, name, position, duty, description, attribute_weights, is_custom, workspace_id, created_at, updated_at)
+SELECT id, name, position, duty, description, attribute_weights, is_custom, CASE WHEN is_custom THEN 1 END, created_at, updated_at
+FROM roles;
+
+DROP TABLE roles;
+ALTER TABLE roles_new RENAME TO roles;
+
+CREATE UNIQUE INDEX idx_roles_workspace_name ON roles(IFNULL(workspace_id, 0), name);
+CREATE INDEX idx_roles_position ON roles(position);
+CREATE INDEX idx_roles_name ON roles(name);
+CREATE INDEX idx_roles_position_duty ON roles(position, duty);
+
+CREATE TABLE mapping_profiles_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL, -- e.g., "FM Genie Scout"
+    description TEXT,
+
+    -- Column mapping as JSON
+    -- Structure: {source_header: player_field}; a null field marks a column that is deliberately skipped
+    columns TEXT NOT NULL, -- JSON object
+
+    -- Metadata
+    is_custom BOOLEAN DEFAULT 0, -- 0 for built-in, 1 for user-created
+    workspace_id INTEGER REFERENCES workspaces(id) ON DELETE CASCADE, -- NULL for built-in
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+INSERT INTO mapping_profiles_new (id, name, description, columns, is_custom, workspace_id, created_at, updated_at)
+SELECT id, name, description, columns, is_custom, CASE WHEN is_custom THEN 1 END, created_at, updated_at
+FROM mapping_profiles;
+
+DROP TABLE mapping_profiles;
+ALTER TABLE mapping_profiles_new RENAME TO mapping_profiles;
+
+CREATE UNIQUE INDEX idx_mapping_profiles_workspace_name ON mapping_profiles(IFNULL(workspace_id, 0), name);
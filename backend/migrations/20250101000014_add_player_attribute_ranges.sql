@@ -0,0 +1,4 @@
+-- Scouted ranges ("11-15") for attributes that are not fully known, as a JSON object
+-- keyed by attribute field name: {"finishing": {"low": 11, "high": 15}}.
+-- The attribute column itself keeps the midpoint.
+ALTER TABLE players ADD COLUMN attribute_ranges TEXT NOT NULL DEFAULT '{}';
@@ -0,0 +1,60 @@
+-- FM marks each role's attributes as key (green) or preferred (blue). Roles can be
+-- scored from those tiers instead of hand-set weights; the predefined roles now are.
+ALTER TABLE roles ADD COLUMN attribute_tiers TEXT NOT NULL DEFAULT '{}'; -- JSON object: {attribute_name: "key" | "preferred"}
+ALTER TABLE roles ADD COLUMN weighting TEXT NOT NULL DEFAULT 'weights'; -- weights, tiers
+
+UPDATE roles SET weighting = 'tiers', attribute_tiers =
+'{"aerial_reach": "key", "command_of_area": "key", "communication": "key", "handling": "key", "kicking": "key", "reflexes": "key", "concentration": "key", "positioning": "key", "agility": "key", "one_on_ones": "preferred", "throwing": "preferred", "anticipation": "preferred", "decisions": "preferred"}'
+WHERE workspace_id IS NULL AND name = 'Goalkeeper';
+
+UPDATE roles SET weighting = 'tiers', attribute_tiers =
+'{"command_of_area": "key", "kicking": "key", "one_on_ones": "key", "reflexes": "key", "rushing_out": "key", "anticipation": "key", "composure": "key", "concentration": "key", "positioning": "key", "agility": "key", "aerial_reach": "preferred", "communication": "preferred", "first_touch": "preferred", "handling": "preferred", "passing": "preferred", "throwing": "preferred", "decisions": "preferred", "vision": "preferred", "acceleration": "preferred"}'
+WHERE workspace_id IS NULL AND name = 'Sweeper Keeper';
+
+UPDATE roles SET weighting = 'tiers', attribute_tiers =
+'{"marking": "key", "tackling": "key", "anticipation": "key", "concentration": "key", "positioning": "key", "teamwork": "key", "crossing": "preferred", "dribbling": "preferred", "passing": "preferred", "technique": "preferred", "decisions": "preferred", "work_rate": "preferred", "pace": "preferred", "stamina": "preferred"}'
+WHERE workspace_id IS NULL AND name = 'Full Back';
+
+UPDATE roles SET weighting = 'tiers', attribute_tiers =
+'{"crossing": "key", "dribbling": "key", "tackling": "key", "technique": "key", "off_the_ball": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "first_touch": "preferred", "marking": "preferred", "passing": "preferred", "anticipation": "preferred", "decisions": "preferred", "flair": "preferred", "positioning": "preferred", "agility": "preferred", "balance": "preferred", "pace": "preferred"}'
+WHERE workspace_id IS NULL AND name = 'Wing Back';
+
+UPDATE roles SET weighting = 'tiers', attribute_tiers =
+'{"heading": "key", "marking": "key", "passing": "key", "tackling": "key", "composure": "key", "positioning": "key", "jumping_reach": "key", "strength": "key", "first_touch": "preferred", "technique": "preferred", "aggression": "preferred", "anticipation": "preferred", "bravery": "preferred", "concentration": "preferred", "decisions": "preferred", "vision": "preferred", "pace": "preferred"}'
+WHERE workspace_id IS NULL AND name = 'Ball Playing Defender';
+
+UPDATE roles SET weighting = 'tiers', attribute_tiers =
+'{"heading": "key", "marking": "key", "tackling": "key", "positioning": "key", "jumping_reach": "key", "strength": "key", "aggression": "preferred", "anticipation": "preferred", "bravery": "preferred", "composure": "preferred", "concentration": "preferred", "decisions": "preferred", "pace": "preferred"}'
+WHERE workspace_id IS NULL AND name = 'Central Defender';
+
+UPDATE roles SET weighting = 'tiers', attribute_tiers =
+'{"tackling": "key", "aggression": "key", "anticipation": "key", "teamwork": "key", "work_rate": "key", "stamina": "key", "marking": "preferred", "bravery": "preferred", "concentration": "preferred", "positioning": "preferred", "agility": "preferred", "pace": "preferred", "strength": "preferred"}'
+WHERE workspace_id IS NULL AND name = 'Ball Winning Midfielder';
+
+UPDATE roles SET weighting = 'tiers', attribute_tiers =
+'{"first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "teamwork": "key", "vision": "key", "anticipation": "preferred", "off_the_ball": "preferred", "positioning": "preferred", "balance": "preferred"}'
+WHERE workspace_id IS NULL AND name = 'Deep Lying Playmaker';
+
+UPDATE roles SET weighting = 'tiers', attribute_tiers =
+'{"passing": "key", "tackling": "key", "off_the_ball": "key", "teamwork": "key", "work_rate": "key", "stamina": "key", "dribbling": "preferred", "first_touch": "preferred", "long_shots": "preferred", "technique": "preferred", "aggression": "preferred", "anticipation": "preferred", "composure": "preferred", "decisions": "preferred", "positioning": "preferred", "acceleration": "preferred", "balance": "preferred", "pace": "preferred", "strength": "preferred"}'
+WHERE workspace_id IS NULL AND name = 'Box to Box Midfielder';
+
+UPDATE roles SET weighting = 'tiers', attribute_tiers =
+'{"first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "off_the_ball": "key", "teamwork": "key", "vision": "key", "dribbling": "preferred", "anticipation": "preferred", "flair": "preferred", "agility": "preferred"}'
+WHERE workspace_id IS NULL AND name = 'Advanced Playmaker';
+
+UPDATE roles SET weighting = 'tiers', attribute_tiers =
+'{"crossing": "key", "dribbling": "key", "technique": "key", "acceleration": "key", "agility": "key", "first_touch": "preferred", "passing": "preferred", "off_the_ball": "preferred", "work_rate": "preferred", "balance": "preferred", "pace": "preferred", "stamina": "preferred"}'
+WHERE workspace_id IS NULL AND name = 'Winger';
+
+UPDATE roles SET weighting = 'tiers', attribute_tiers =
+'{"dribbling": "key", "finishing": "key", "first_touch": "key", "technique": "key", "composure": "key", "off_the_ball": "key", "acceleration": "key", "pace": "key", "passing": "preferred", "anticipation": "preferred", "decisions": "preferred", "work_rate": "preferred", "agility": "preferred", "balance": "preferred", "stamina": "preferred"}'
+WHERE workspace_id IS NULL AND name = 'Advanced Forward';
+
+UPDATE roles SET weighting = 'tiers', attribute_tiers =
+'{"heading": "key", "bravery": "key", "teamwork": "key", "balance": "key", "jumping_reach": "key", "strength": "key", "first_touch": "preferred", "aggression": "preferred", "anticipation": "preferred", "composure": "preferred", "decisions": "preferred", "off_the_ball": "preferred"}'
+WHERE workspace_id IS NULL AND name = 'Target Man';
+
+UPDATE roles SET weighting = 'tiers', attribute_tiers =
+'{"dribbling": "key", "first_touch": "key", "heading": "key", "long_shots": "key", "passing": "key", "technique": "key", "anticipation": "key", "composure": "key", "decisions": "key", "off_the_ball": "key", "teamwork": "key", "vision": "key", "acceleration": "key", "agility": "key", "strength": "key", "finishing": "preferred", "work_rate": "preferred", "balance": "preferred", "jumping_reach": "preferred", "pace": "preferred", "stamina": "preferred"}'
+WHERE workspace_id IS NULL AND name = 'Complete Forward';
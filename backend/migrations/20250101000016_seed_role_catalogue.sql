@@ -0,0 +1,680 @@
+-- Replace the hand-picked predefined roles with the full catalogue: one role for every
+-- valid position/role/duty combination (TacticalRole::is_valid_combination), named after
+-- TacticalRole::role_name. Tiers are FM's key and preferred attributes for the role and
+-- duty; the float weights mirror them (1.0 key, 0.5 preferred).
+DELETE FROM roles WHERE workspace_id IS NULL;
+
+-- FM reuses role names across positions and duties
+DROP INDEX idx_roles_workspace_name;
+CREATE UNIQUE INDEX idx_roles_workspace_role ON roles(IFNULL(workspace_id, 0), name, position, duty);
+
+-- Goalkeepers
+INSERT INTO roles (name, position, duty, description, attribute_weights, attribute_tiers, weighting, is_custom) VALUES
+('Goalkeeper', 'GK', 'Automatic', 'Traditional goalkeeper focused on shot-stopping',
+'{"aerial_reach": 1.0, "command_of_area": 1.0, "communication": 1.0, "handling": 1.0, "kicking": 1.0, "reflexes": 1.0, "concentration": 1.0, "positioning": 1.0, "agility": 1.0, "one_on_ones": 0.5, "throwing": 0.5, "anticipation": 0.5, "decisions": 0.5}',
+'{"aerial_reach": "key", "command_of_area": "key", "communication": "key", "handling": "key", "kicking": "key", "reflexes": "key", "concentration": "key", "positioning": "key", "agility": "key", "one_on_ones": "preferred", "throwing": "preferred", "anticipation": "preferred", "decisions": "preferred"}',
+'tiers', 0),
+
+('Sweeper Keeper', 'GK', 'Automatic', 'Keeper who leaves the line to sweep up behind the defence',
+'{"command_of_area": 1.0, "kicking": 1.0, "one_on_ones": 1.0, "reflexes": 1.0, "rushing_out": 1.0, "anticipation": 1.0, "composure": 1.0, "concentration": 1.0, "positioning": 1.0, "agility": 1.0, "aerial_reach": 0.5, "communication": 0.5, "first_touch": 0.5, "handling": 0.5, "passing": 0.5, "throwing": 0.5, "decisions": 0.5, "vision": 0.5, "acceleration": 0.5}',
+'{"command_of_area": "key", "kicking": "key", "one_on_ones": "key", "reflexes": "key", "rushing_out": "key", "anticipation": "key", "composure": "key", "concentration": "key", "positioning": "key", "agility": "key", "aerial_reach": "preferred", "communication": "preferred", "first_touch": "preferred", "handling": "preferred", "passing": "preferred", "throwing": "preferred", "decisions": "preferred", "vision": "preferred", "acceleration": "preferred"}',
+'tiers', 0);
+
+-- Defenders
+INSERT INTO roles (name, position, duty, description, attribute_weights, attribute_tiers, weighting, is_custom) VALUES
+('Full Back', 'DR', 'Defend', 'Defensive full-back who supports the attack down the flank',
+'{"marking": 1.0, "tackling": 1.0, "anticipation": 1.0, "concentration": 1.0, "positioning": 1.0, "passing": 0.5, "decisions": 0.5, "teamwork": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"marking": "key", "tackling": "key", "anticipation": "key", "concentration": "key", "positioning": "key", "passing": "preferred", "decisions": "preferred", "teamwork": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Full Back', 'DR', 'Support', 'Defensive full-back who supports the attack down the flank',
+'{"marking": 1.0, "tackling": 1.0, "anticipation": 1.0, "concentration": 1.0, "positioning": 1.0, "teamwork": 1.0, "crossing": 0.5, "dribbling": 0.5, "passing": 0.5, "technique": 0.5, "decisions": 0.5, "work_rate": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"marking": "key", "tackling": "key", "anticipation": "key", "concentration": "key", "positioning": "key", "teamwork": "key", "crossing": "preferred", "dribbling": "preferred", "passing": "preferred", "technique": "preferred", "decisions": "preferred", "work_rate": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Full Back', 'DR', 'Attack', 'Defensive full-back who supports the attack down the flank',
+'{"crossing": 1.0, "marking": 1.0, "tackling": 1.0, "anticipation": 1.0, "positioning": 1.0, "teamwork": 1.0, "dribbling": 0.5, "first_touch": 0.5, "passing": 0.5, "technique": 0.5, "concentration": 0.5, "decisions": 0.5, "off_the_ball": 0.5, "work_rate": 0.5, "agility": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"crossing": "key", "marking": "key", "tackling": "key", "anticipation": "key", "positioning": "key", "teamwork": "key", "dribbling": "preferred", "first_touch": "preferred", "passing": "preferred", "technique": "preferred", "concentration": "preferred", "decisions": "preferred", "off_the_ball": "preferred", "work_rate": "preferred", "agility": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Full Back', 'DL', 'Defend', 'Defensive full-back who supports the attack down the flank',
+'{"marking": 1.0, "tackling": 1.0, "anticipation": 1.0, "concentration": 1.0, "positioning": 1.0, "passing": 0.5, "decisions": 0.5, "teamwork": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"marking": "key", "tackling": "key", "anticipation": "key", "concentration": "key", "positioning": "key", "passing": "preferred", "decisions": "preferred", "teamwork": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Full Back', 'DL', 'Support', 'Defensive full-back who supports the attack down the flank',
+'{"marking": 1.0, "tackling": 1.0, "anticipation": 1.0, "concentration": 1.0, "positioning": 1.0, "teamwork": 1.0, "crossing": 0.5, "dribbling": 0.5, "passing": 0.5, "technique": 0.5, "decisions": 0.5, "work_rate": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"marking": "key", "tackling": "key", "anticipation": "key", "concentration": "key", "positioning": "key", "teamwork": "key", "crossing": "preferred", "dribbling": "preferred", "passing": "preferred", "technique": "preferred", "decisions": "preferred", "work_rate": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Full Back', 'DL', 'Attack', 'Defensive full-back who supports the attack down the flank',
+'{"crossing": 1.0, "marking": 1.0, "tackling": 1.0, "anticipation": 1.0, "positioning": 1.0, "teamwork": 1.0, "dribbling": 0.5, "first_touch": 0.5, "passing": 0.5, "technique": 0.5, "concentration": 0.5, "decisions": 0.5, "off_the_ball": 0.5, "work_rate": 0.5, "agility": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"crossing": "key", "marking": "key", "tackling": "key", "anticipation": "key", "positioning": "key", "teamwork": "key", "dribbling": "preferred", "first_touch": "preferred", "passing": "preferred", "technique": "preferred", "concentration": "preferred", "decisions": "preferred", "off_the_ball": "preferred", "work_rate": "preferred", "agility": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Wing Back', 'DR', 'Defend', 'Flank player covering the whole touchline',
+'{"marking": 1.0, "tackling": 1.0, "anticipation": 1.0, "concentration": 1.0, "positioning": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "stamina": 1.0, "crossing": 0.5, "dribbling": 0.5, "first_touch": 0.5, "passing": 0.5, "technique": 0.5, "decisions": 0.5, "agility": 0.5, "balance": 0.5, "pace": 0.5}',
+'{"marking": "key", "tackling": "key", "anticipation": "key", "concentration": "key", "positioning": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "crossing": "preferred", "dribbling": "preferred", "first_touch": "preferred", "passing": "preferred", "technique": "preferred", "decisions": "preferred", "agility": "preferred", "balance": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Wing Back', 'DR', 'Support', 'Flank player covering the whole touchline',
+'{"crossing": 1.0, "dribbling": 1.0, "marking": 1.0, "tackling": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "stamina": 1.0, "first_touch": 0.5, "passing": 0.5, "technique": 0.5, "anticipation": 0.5, "concentration": 0.5, "decisions": 0.5, "off_the_ball": 0.5, "positioning": 0.5, "agility": 0.5, "balance": 0.5, "pace": 0.5}',
+'{"crossing": "key", "dribbling": "key", "marking": "key", "tackling": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "first_touch": "preferred", "passing": "preferred", "technique": "preferred", "anticipation": "preferred", "concentration": "preferred", "decisions": "preferred", "off_the_ball": "preferred", "positioning": "preferred", "agility": "preferred", "balance": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Wing Back', 'DR', 'Attack', 'Flank player covering the whole touchline',
+'{"crossing": 1.0, "dribbling": 1.0, "tackling": 1.0, "technique": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "stamina": 1.0, "first_touch": 0.5, "marking": 0.5, "passing": 0.5, "anticipation": 0.5, "decisions": 0.5, "flair": 0.5, "positioning": 0.5, "agility": 0.5, "balance": 0.5, "pace": 0.5}',
+'{"crossing": "key", "dribbling": "key", "tackling": "key", "technique": "key", "off_the_ball": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "first_touch": "preferred", "marking": "preferred", "passing": "preferred", "anticipation": "preferred", "decisions": "preferred", "flair": "preferred", "positioning": "preferred", "agility": "preferred", "balance": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Wing Back', 'DL', 'Defend', 'Flank player covering the whole touchline',
+'{"marking": 1.0, "tackling": 1.0, "anticipation": 1.0, "concentration": 1.0, "positioning": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "stamina": 1.0, "crossing": 0.5, "dribbling": 0.5, "first_touch": 0.5, "passing": 0.5, "technique": 0.5, "decisions": 0.5, "agility": 0.5, "balance": 0.5, "pace": 0.5}',
+'{"marking": "key", "tackling": "key", "anticipation": "key", "concentration": "key", "positioning": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "crossing": "preferred", "dribbling": "preferred", "first_touch": "preferred", "passing": "preferred", "technique": "preferred", "decisions": "preferred", "agility": "preferred", "balance": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Wing Back', 'DL', 'Support', 'Flank player covering the whole touchline',
+'{"crossing": 1.0, "dribbling": 1.0, "marking": 1.0, "tackling": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "stamina": 1.0, "first_touch": 0.5, "passing": 0.5, "technique": 0.5, "anticipation": 0.5, "concentration": 0.5, "decisions": 0.5, "off_the_ball": 0.5, "positioning": 0.5, "agility": 0.5, "balance": 0.5, "pace": 0.5}',
+'{"crossing": "key", "dribbling": "key", "marking": "key", "tackling": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "first_touch": "preferred", "passing": "preferred", "technique": "preferred", "anticipation": "preferred", "concentration": "preferred", "decisions": "preferred", "off_the_ball": "preferred", "positioning": "preferred", "agility": "preferred", "balance": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Wing Back', 'DL', 'Attack', 'Flank player covering the whole touchline',
+'{"crossing": 1.0, "dribbling": 1.0, "tackling": 1.0, "technique": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "stamina": 1.0, "first_touch": 0.5, "marking": 0.5, "passing": 0.5, "anticipation": 0.5, "decisions": 0.5, "flair": 0.5, "positioning": 0.5, "agility": 0.5, "balance": 0.5, "pace": 0.5}',
+'{"crossing": "key", "dribbling": "key", "tackling": "key", "technique": "key", "off_the_ball": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "first_touch": "preferred", "marking": "preferred", "passing": "preferred", "anticipation": "preferred", "decisions": "preferred", "flair": "preferred", "positioning": "preferred", "agility": "preferred", "balance": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Complete Wing Back', 'DR', 'Support', 'Attacking wing-back who is the main source of width',
+'{"crossing": 1.0, "dribbling": 1.0, "technique": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "stamina": 1.0, "first_touch": 0.5, "marking": 0.5, "passing": 0.5, "tackling": 0.5, "anticipation": 0.5, "decisions": 0.5, "flair": 0.5, "off_the_ball": 0.5, "positioning": 0.5, "agility": 0.5, "balance": 0.5, "pace": 0.5}',
+'{"crossing": "key", "dribbling": "key", "technique": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "first_touch": "preferred", "marking": "preferred", "passing": "preferred", "tackling": "preferred", "anticipation": "preferred", "decisions": "preferred", "flair": "preferred", "off_the_ball": "preferred", "positioning": "preferred", "agility": "preferred", "balance": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Complete Wing Back', 'DR', 'Attack', 'Attacking wing-back who is the main source of width',
+'{"crossing": 1.0, "dribbling": 1.0, "technique": 1.0, "flair": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "stamina": 1.0, "first_touch": 0.5, "marking": 0.5, "passing": 0.5, "tackling": 0.5, "anticipation": 0.5, "decisions": 0.5, "positioning": 0.5, "agility": 0.5, "balance": 0.5, "pace": 0.5}',
+'{"crossing": "key", "dribbling": "key", "technique": "key", "flair": "key", "off_the_ball": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "first_touch": "preferred", "marking": "preferred", "passing": "preferred", "tackling": "preferred", "anticipation": "preferred", "decisions": "preferred", "positioning": "preferred", "agility": "preferred", "balance": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Complete Wing Back', 'DL', 'Support', 'Attacking wing-back who is the main source of width',
+'{"crossing": 1.0, "dribbling": 1.0, "technique": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "stamina": 1.0, "first_touch": 0.5, "marking": 0.5, "passing": 0.5, "tackling": 0.5, "anticipation": 0.5, "decisions": 0.5, "flair": 0.5, "off_the_ball": 0.5, "positioning": 0.5, "agility": 0.5, "balance": 0.5, "pace": 0.5}',
+'{"crossing": "key", "dribbling": "key", "technique": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "first_touch": "preferred", "marking": "preferred", "passing": "preferred", "tackling": "preferred", "anticipation": "preferred", "decisions": "preferred", "flair": "preferred", "off_the_ball": "preferred", "positioning": "preferred", "agility": "preferred", "balance": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Complete Wing Back', 'DL', 'Attack', 'Attacking wing-back who is the main source of width',
+'{"crossing": 1.0, "dribbling": 1.0, "technique": 1.0, "flair": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "stamina": 1.0, "first_touch": 0.5, "marking": 0.5, "passing": 0.5, "tackling": 0.5, "anticipation": 0.5, "decisions": 0.5, "positioning": 0.5, "agility": 0.5, "balance": 0.5, "pace": 0.5}',
+'{"crossing": "key", "dribbling": "key", "technique": "key", "flair": "key", "off_the_ball": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "first_touch": "preferred", "marking": "preferred", "passing": "preferred", "tackling": "preferred", "anticipation": "preferred", "decisions": "preferred", "positioning": "preferred", "agility": "preferred", "balance": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Inverted Wing Back', 'DR', 'Defend', 'Wing-back who moves inside into midfield in possession',
+'{"passing": 1.0, "tackling": 1.0, "anticipation": 1.0, "decisions": 1.0, "positioning": 1.0, "teamwork": 1.0, "first_touch": 0.5, "marking": 0.5, "technique": 0.5, "composure": 0.5, "concentration": 0.5, "work_rate": 0.5, "acceleration": 0.5, "stamina": 0.5}',
+'{"passing": "key", "tackling": "key", "anticipation": "key", "decisions": "key", "positioning": "key", "teamwork": "key", "first_touch": "preferred", "marking": "preferred", "technique": "preferred", "composure": "preferred", "concentration": "preferred", "work_rate": "preferred", "acceleration": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Inverted Wing Back', 'DR', 'Support', 'Wing-back who moves inside into midfield in possession',
+'{"first_touch": 1.0, "passing": 1.0, "tackling": 1.0, "composure": 1.0, "decisions": 1.0, "teamwork": 1.0, "marking": 0.5, "technique": 0.5, "anticipation": 0.5, "concentration": 0.5, "positioning": 0.5, "vision": 0.5, "work_rate": 0.5, "acceleration": 0.5, "agility": 0.5, "stamina": 0.5}',
+'{"first_touch": "key", "passing": "key", "tackling": "key", "composure": "key", "decisions": "key", "teamwork": "key", "marking": "preferred", "technique": "preferred", "anticipation": "preferred", "concentration": "preferred", "positioning": "preferred", "vision": "preferred", "work_rate": "preferred", "acceleration": "preferred", "agility": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Inverted Wing Back', 'DR', 'Attack', 'Wing-back who moves inside into midfield in possession',
+'{"first_touch": 1.0, "passing": 1.0, "tackling": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "teamwork": 1.0, "vision": 1.0, "dribbling": 0.5, "marking": 0.5, "long_shots": 0.5, "anticipation": 0.5, "concentration": 0.5, "flair": 0.5, "off_the_ball": 0.5, "positioning": 0.5, "work_rate": 0.5, "acceleration": 0.5, "agility": 0.5, "stamina": 0.5}',
+'{"first_touch": "key", "passing": "key", "tackling": "key", "technique": "key", "composure": "key", "decisions": "key", "teamwork": "key", "vision": "key", "dribbling": "preferred", "marking": "preferred", "long_shots": "preferred", "anticipation": "preferred", "concentration": "preferred", "flair": "preferred", "off_the_ball": "preferred", "positioning": "preferred", "work_rate": "preferred", "acceleration": "preferred", "agility": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Inverted Wing Back', 'DL', 'Defend', 'Wing-back who moves inside into midfield in possession',
+'{"passing": 1.0, "tackling": 1.0, "anticipation": 1.0, "decisions": 1.0, "positioning": 1.0, "teamwork": 1.0, "first_touch": 0.5, "marking": 0.5, "technique": 0.5, "composure": 0.5, "concentration": 0.5, "work_rate": 0.5, "acceleration": 0.5, "stamina": 0.5}',
+'{"passing": "key", "tackling": "key", "anticipation": "key", "decisions": "key", "positioning": "key", "teamwork": "key", "first_touch": "preferred", "marking": "preferred", "technique": "preferred", "composure": "preferred", "concentration": "preferred", "work_rate": "preferred", "acceleration": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Inverted Wing Back', 'DL', 'Support', 'Wing-back who moves inside into midfield in possession',
+'{"first_touch": 1.0, "passing": 1.0, "tackling": 1.0, "composure": 1.0, "decisions": 1.0, "teamwork": 1.0, "marking": 0.5, "technique": 0.5, "anticipation": 0.5, "concentration": 0.5, "positioning": 0.5, "vision": 0.5, "work_rate": 0.5, "acceleration": 0.5, "agility": 0.5, "stamina": 0.5}',
+'{"first_touch": "key", "passing": "key", "tackling": "key", "composure": "key", "decisions": "key", "teamwork": "key", "marking": "preferred", "technique": "preferred", "anticipation": "preferred", "concentration": "preferred", "positioning": "preferred", "vision": "preferred", "work_rate": "preferred", "acceleration": "preferred", "agility": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Inverted Wing Back', 'DL', 'Attack', 'Wing-back who moves inside into midfield in possession',
+'{"first_touch": 1.0, "passing": 1.0, "tackling": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "teamwork": 1.0, "vision": 1.0, "dribbling": 0.5, "marking": 0.5, "long_shots": 0.5, "anticipation": 0.5, "concentration": 0.5, "flair": 0.5, "off_the_ball": 0.5, "positioning": 0.5, "work_rate": 0.5, "acceleration": 0.5, "agility": 0.5, "stamina": 0.5}',
+'{"first_touch": "key", "passing": "key", "tackling": "key", "technique": "key", "composure": "key", "decisions": "key", "teamwork": "key", "vision": "key", "dribbling": "preferred", "marking": "preferred", "long_shots": "preferred", "anticipation": "preferred", "concentration": "preferred", "flair": "preferred", "off_the_ball": "preferred", "positioning": "preferred", "work_rate": "preferred", "acceleration": "preferred", "agility": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('No-Challenge Defender', 'DR', 'Defend', 'No-nonsense defender who clears the danger',
+'{"heading": 1.0, "marking": 1.0, "tackling": 1.0, "positioning": 1.0, "jumping_reach": 1.0, "strength": 1.0, "anticipation": 0.5, "bravery": 0.5, "concentration": 0.5, "aggression": 0.5, "pace": 0.5}',
+'{"heading": "key", "marking": "key", "tackling": "key", "positioning": "key", "jumping_reach": "key", "strength": "key", "anticipation": "preferred", "bravery": "preferred", "concentration": "preferred", "aggression": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('No-Challenge Defender', 'DL', 'Defend', 'No-nonsense defender who clears the danger',
+'{"heading": 1.0, "marking": 1.0, "tackling": 1.0, "positioning": 1.0, "jumping_reach": 1.0, "strength": 1.0, "anticipation": 0.5, "bravery": 0.5, "concentration": 0.5, "aggression": 0.5, "pace": 0.5}',
+'{"heading": "key", "marking": "key", "tackling": "key", "positioning": "key", "jumping_reach": "key", "strength": "key", "anticipation": "preferred", "bravery": "preferred", "concentration": "preferred", "aggression": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Central Defender', 'DC', 'Defend', 'Centre-back who stops attacks and wins the ball',
+'{"heading": 1.0, "marking": 1.0, "tackling": 1.0, "positioning": 1.0, "jumping_reach": 1.0, "strength": 1.0, "aggression": 0.5, "anticipation": 0.5, "bravery": 0.5, "composure": 0.5, "concentration": 0.5, "decisions": 0.5, "pace": 0.5}',
+'{"heading": "key", "marking": "key", "tackling": "key", "positioning": "key", "jumping_reach": "key", "strength": "key", "aggression": "preferred", "anticipation": "preferred", "bravery": "preferred", "composure": "preferred", "concentration": "preferred", "decisions": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Central Defender', 'DC', 'Support', 'Centre-back who stops attacks and wins the ball',
+'{"heading": 1.0, "tackling": 1.0, "aggression": 1.0, "bravery": 1.0, "decisions": 1.0, "jumping_reach": 1.0, "strength": 1.0, "marking": 0.5, "anticipation": 0.5, "composure": 0.5, "concentration": 0.5, "positioning": 0.5}',
+'{"heading": "key", "tackling": "key", "aggression": "key", "bravery": "key", "decisions": "key", "jumping_reach": "key", "strength": "key", "marking": "preferred", "anticipation": "preferred", "composure": "preferred", "concentration": "preferred", "positioning": "preferred"}',
+'tiers', 0),
+
+('Ball-Playing Defender', 'DC', 'Defend', 'Centre-back who builds play from the back',
+'{"heading": 1.0, "marking": 1.0, "passing": 1.0, "tackling": 1.0, "composure": 1.0, "positioning": 1.0, "jumping_reach": 1.0, "strength": 1.0, "first_touch": 0.5, "technique": 0.5, "aggression": 0.5, "anticipation": 0.5, "bravery": 0.5, "concentration": 0.5, "decisions": 0.5, "vision": 0.5, "pace": 0.5}',
+'{"heading": "key", "marking": "key", "passing": "key", "tackling": "key", "composure": "key", "positioning": "key", "jumping_reach": "key", "strength": "key", "first_touch": "preferred", "technique": "preferred", "aggression": "preferred", "anticipation": "preferred", "bravery": "preferred", "concentration": "preferred", "decisions": "preferred", "vision": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Ball-Playing Defender', 'DC', 'Support', 'Centre-back who builds play from the back',
+'{"heading": 1.0, "passing": 1.0, "tackling": 1.0, "bravery": 1.0, "composure": 1.0, "decisions": 1.0, "jumping_reach": 1.0, "strength": 1.0, "first_touch": 0.5, "marking": 0.5, "technique": 0.5, "aggression": 0.5, "anticipation": 0.5, "concentration": 0.5, "positioning": 0.5, "vision": 0.5}',
+'{"heading": "key", "passing": "key", "tackling": "key", "bravery": "key", "composure": "key", "decisions": "key", "jumping_reach": "key", "strength": "key", "first_touch": "preferred", "marking": "preferred", "technique": "preferred", "aggression": "preferred", "anticipation": "preferred", "concentration": "preferred", "positioning": "preferred", "vision": "preferred"}',
+'tiers', 0),
+
+('Libero', 'DC', 'Defend', 'Defender who steps out of the line to join midfield',
+'{"first_touch": 1.0, "heading": 1.0, "marking": 1.0, "passing": 1.0, "tackling": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "positioning": 1.0, "teamwork": 1.0, "jumping_reach": 1.0, "strength": 1.0, "dribbling": 0.5, "anticipation": 0.5, "bravery": 0.5, "concentration": 0.5, "vision": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"first_touch": "key", "heading": "key", "marking": "key", "passing": "key", "tackling": "key", "technique": "key", "composure": "key", "decisions": "key", "positioning": "key", "teamwork": "key", "jumping_reach": "key", "strength": "key", "dribbling": "preferred", "anticipation": "preferred", "bravery": "preferred", "concentration": "preferred", "vision": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Libero', 'DC', 'Support', 'Defender who steps out of the line to join midfield',
+'{"first_touch": 1.0, "heading": 1.0, "marking": 1.0, "passing": 1.0, "tackling": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "positioning": 1.0, "teamwork": 1.0, "vision": 1.0, "jumping_reach": 1.0, "strength": 1.0, "dribbling": 0.5, "anticipation": 0.5, "bravery": 0.5, "concentration": 0.5, "off_the_ball": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"first_touch": "key", "heading": "key", "marking": "key", "passing": "key", "tackling": "key", "technique": "key", "composure": "key", "decisions": "key", "positioning": "key", "teamwork": "key", "vision": "key", "jumping_reach": "key", "strength": "key", "dribbling": "preferred", "anticipation": "preferred", "bravery": "preferred", "concentration": "preferred", "off_the_ball": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('No-Challenge Defender', 'DC', 'Defend', 'No-nonsense defender who clears the danger',
+'{"heading": 1.0, "marking": 1.0, "tackling": 1.0, "positioning": 1.0, "jumping_reach": 1.0, "strength": 1.0, "anticipation": 0.5, "bravery": 0.5, "concentration": 0.5, "aggression": 0.5, "pace": 0.5}',
+'{"heading": "key", "marking": "key", "tackling": "key", "positioning": "key", "jumping_reach": "key", "strength": "key", "anticipation": "preferred", "bravery": "preferred", "concentration": "preferred", "aggression": "preferred", "pace": "preferred"}',
+'tiers', 0);
+
+-- Wing-backs
+INSERT INTO roles (name, position, duty, description, attribute_weights, attribute_tiers, weighting, is_custom) VALUES
+('Wing Back', 'WBR', 'Defend', 'Flank player covering the whole touchline',
+'{"marking": 1.0, "tackling": 1.0, "anticipation": 1.0, "concentration": 1.0, "positioning": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "stamina": 1.0, "crossing": 0.5, "dribbling": 0.5, "first_touch": 0.5, "passing": 0.5, "technique": 0.5, "decisions": 0.5, "agility": 0.5, "balance": 0.5, "pace": 0.5}',
+'{"marking": "key", "tackling": "key", "anticipation": "key", "concentration": "key", "positioning": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "crossing": "preferred", "dribbling": "preferred", "first_touch": "preferred", "passing": "preferred", "technique": "preferred", "decisions": "preferred", "agility": "preferred", "balance": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Wing Back', 'WBR', 'Support', 'Flank player covering the whole touchline',
+'{"crossing": 1.0, "dribbling": 1.0, "marking": 1.0, "tackling": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "stamina": 1.0, "first_touch": 0.5, "passing": 0.5, "technique": 0.5, "anticipation": 0.5, "concentration": 0.5, "decisions": 0.5, "off_the_ball": 0.5, "positioning": 0.5, "agility": 0.5, "balance": 0.5, "pace": 0.5}',
+'{"crossing": "key", "dribbling": "key", "marking": "key", "tackling": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "first_touch": "preferred", "passing": "preferred", "technique": "preferred", "anticipation": "preferred", "concentration": "preferred", "decisions": "preferred", "off_the_ball": "preferred", "positioning": "preferred", "agility": "preferred", "balance": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Wing Back', 'WBR', 'Attack', 'Flank player covering the whole touchline',
+'{"crossing": 1.0, "dribbling": 1.0, "tackling": 1.0, "technique": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "stamina": 1.0, "first_touch": 0.5, "marking": 0.5, "passing": 0.5, "anticipation": 0.5, "decisions": 0.5, "flair": 0.5, "positioning": 0.5, "agility": 0.5, "balance": 0.5, "pace": 0.5}',
+'{"crossing": "key", "dribbling": "key", "tackling": "key", "technique": "key", "off_the_ball": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "first_touch": "preferred", "marking": "preferred", "passing": "preferred", "anticipation": "preferred", "decisions": "preferred", "flair": "preferred", "positioning": "preferred", "agility": "preferred", "balance": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Wing Back', 'WBL', 'Defend', 'Flank player covering the whole touchline',
+'{"marking": 1.0, "tackling": 1.0, "anticipation": 1.0, "concentration": 1.0, "positioning": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "stamina": 1.0, "crossing": 0.5, "dribbling": 0.5, "first_touch": 0.5, "passing": 0.5, "technique": 0.5, "decisions": 0.5, "agility": 0.5, "balance": 0.5, "pace": 0.5}',
+'{"marking": "key", "tackling": "key", "anticipation": "key", "concentration": "key", "positioning": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "crossing": "preferred", "dribbling": "preferred", "first_touch": "preferred", "passing": "preferred", "technique": "preferred", "decisions": "preferred", "agility": "preferred", "balance": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Wing Back', 'WBL', 'Support', 'Flank player covering the whole touchline',
+'{"crossing": 1.0, "dribbling": 1.0, "marking": 1.0, "tackling": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "stamina": 1.0, "first_touch": 0.5, "passing": 0.5, "technique": 0.5, "anticipation": 0.5, "concentration": 0.5, "decisions": 0.5, "off_the_ball": 0.5, "positioning": 0.5, "agility": 0.5, "balance": 0.5, "pace": 0.5}',
+'{"crossing": "key", "dribbling": "key", "marking": "key", "tackling": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "first_touch": "preferred", "passing": "preferred", "technique": "preferred", "anticipation": "preferred", "concentration": "preferred", "decisions": "preferred", "off_the_ball": "preferred", "positioning": "preferred", "agility": "preferred", "balance": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Wing Back', 'WBL', 'Attack', 'Flank player covering the whole touchline',
+'{"crossing": 1.0, "dribbling": 1.0, "tackling": 1.0, "technique": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "stamina": 1.0, "first_touch": 0.5, "marking": 0.5, "passing": 0.5, "anticipation": 0.5, "decisions": 0.5, "flair": 0.5, "positioning": 0.5, "agility": 0.5, "balance": 0.5, "pace": 0.5}',
+'{"crossing": "key", "dribbling": "key", "tackling": "key", "technique": "key", "off_the_ball": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "first_touch": "preferred", "marking": "preferred", "passing": "preferred", "anticipation": "preferred", "decisions": "preferred", "flair": "preferred", "positioning": "preferred", "agility": "preferred", "balance": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Complete Wing Back', 'WBR', 'Support', 'Attacking wing-back who is the main source of width',
+'{"crossing": 1.0, "dribbling": 1.0, "technique": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "stamina": 1.0, "first_touch": 0.5, "marking": 0.5, "passing": 0.5, "tackling": 0.5, "anticipation": 0.5, "decisions": 0.5, "flair": 0.5, "off_the_ball": 0.5, "positioning": 0.5, "agility": 0.5, "balance": 0.5, "pace": 0.5}',
+'{"crossing": "key", "dribbling": "key", "technique": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "first_touch": "preferred", "marking": "preferred", "passing": "preferred", "tackling": "preferred", "anticipation": "preferred", "decisions": "preferred", "flair": "preferred", "off_the_ball": "preferred", "positioning": "preferred", "agility": "preferred", "balance": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Complete Wing Back', 'WBR', 'Attack', 'Attacking wing-back who is the main source of width',
+'{"crossing": 1.0, "dribbling": 1.0, "technique": 1.0, "flair": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "stamina": 1.0, "first_touch": 0.5, "marking": 0.5, "passing": 0.5, "tackling": 0.5, "anticipation": 0.5, "decisions": 0.5, "positioning": 0.5, "agility": 0.5, "balance": 0.5, "pace": 0.5}',
+'{"crossing": "key", "dribbling": "key", "technique": "key", "flair": "key", "off_the_ball": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "first_touch": "preferred", "marking": "preferred", "passing": "preferred", "tackling": "preferred", "anticipation": "preferred", "decisions": "preferred", "positioning": "preferred", "agility": "preferred", "balance": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Complete Wing Back', 'WBL', 'Support', 'Attacking wing-back who is the main source of width',
+'{"crossing": 1.0, "dribbling": 1.0, "technique": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "stamina": 1.0, "first_touch": 0.5, "marking": 0.5, "passing": 0.5, "tackling": 0.5, "anticipation": 0.5, "decisions": 0.5, "flair": 0.5, "off_the_ball": 0.5, "positioning": 0.5, "agility": 0.5, "balance": 0.5, "pace": 0.5}',
+'{"crossing": "key", "dribbling": "key", "technique": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "first_touch": "preferred", "marking": "preferred", "passing": "preferred", "tackling": "preferred", "anticipation": "preferred", "decisions": "preferred", "flair": "preferred", "off_the_ball": "preferred", "positioning": "preferred", "agility": "preferred", "balance": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Complete Wing Back', 'WBL', 'Attack', 'Attacking wing-back who is the main source of width',
+'{"crossing": 1.0, "dribbling": 1.0, "technique": 1.0, "flair": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "stamina": 1.0, "first_touch": 0.5, "marking": 0.5, "passing": 0.5, "tackling": 0.5, "anticipation": 0.5, "decisions": 0.5, "positioning": 0.5, "agility": 0.5, "balance": 0.5, "pace": 0.5}',
+'{"crossing": "key", "dribbling": "key", "technique": "key", "flair": "key", "off_the_ball": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "first_touch": "preferred", "marking": "preferred", "passing": "preferred", "tackling": "preferred", "anticipation": "preferred", "decisions": "preferred", "positioning": "preferred", "agility": "preferred", "balance": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Inverted Wing Back', 'WBR', 'Defend', 'Wing-back who moves inside into midfield in possession',
+'{"passing": 1.0, "tackling": 1.0, "anticipation": 1.0, "decisions": 1.0, "positioning": 1.0, "teamwork": 1.0, "first_touch": 0.5, "marking": 0.5, "technique": 0.5, "composure": 0.5, "concentration": 0.5, "work_rate": 0.5, "acceleration": 0.5, "stamina": 0.5}',
+'{"passing": "key", "tackling": "key", "anticipation": "key", "decisions": "key", "positioning": "key", "teamwork": "key", "first_touch": "preferred", "marking": "preferred", "technique": "preferred", "composure": "preferred", "concentration": "preferred", "work_rate": "preferred", "acceleration": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Inverted Wing Back', 'WBR', 'Support', 'Wing-back who moves inside into midfield in possession',
+'{"first_touch": 1.0, "passing": 1.0, "tackling": 1.0, "composure": 1.0, "decisions": 1.0, "teamwork": 1.0, "marking": 0.5, "technique": 0.5, "anticipation": 0.5, "concentration": 0.5, "positioning": 0.5, "vision": 0.5, "work_rate": 0.5, "acceleration": 0.5, "agility": 0.5, "stamina": 0.5}',
+'{"first_touch": "key", "passing": "key", "tackling": "key", "composure": "key", "decisions": "key", "teamwork": "key", "marking": "preferred", "technique": "preferred", "anticipation": "preferred", "concentration": "preferred", "positioning": "preferred", "vision": "preferred", "work_rate": "preferred", "acceleration": "preferred", "agility": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Inverted Wing Back', 'WBR', 'Attack', 'Wing-back who moves inside into midfield in possession',
+'{"first_touch": 1.0, "passing": 1.0, "tackling": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "teamwork": 1.0, "vision": 1.0, "dribbling": 0.5, "marking": 0.5, "long_shots": 0.5, "anticipation": 0.5, "concentration": 0.5, "flair": 0.5, "off_the_ball": 0.5, "positioning": 0.5, "work_rate": 0.5, "acceleration": 0.5, "agility": 0.5, "stamina": 0.5}',
+'{"first_touch": "key", "passing": "key", "tackling": "key", "technique": "key", "composure": "key", "decisions": "key", "teamwork": "key", "vision": "key", "dribbling": "preferred", "marking": "preferred", "long_shots": "preferred", "anticipation": "preferred", "concentration": "preferred", "flair": "preferred", "off_the_ball": "preferred", "positioning": "preferred", "work_rate": "preferred", "acceleration": "preferred", "agility": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Inverted Wing Back', 'WBL', 'Defend', 'Wing-back who moves inside into midfield in possession',
+'{"passing": 1.0, "tackling": 1.0, "anticipation": 1.0, "decisions": 1.0, "positioning": 1.0, "teamwork": 1.0, "first_touch": 0.5, "marking": 0.5, "technique": 0.5, "composure": 0.5, "concentration": 0.5, "work_rate": 0.5, "acceleration": 0.5, "stamina": 0.5}',
+'{"passing": "key", "tackling": "key", "anticipation": "key", "decisions": "key", "positioning": "key", "teamwork": "key", "first_touch": "preferred", "marking": "preferred", "technique": "preferred", "composure": "preferred", "concentration": "preferred", "work_rate": "preferred", "acceleration": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Inverted Wing Back', 'WBL', 'Support', 'Wing-back who moves inside into midfield in possession',
+'{"first_touch": 1.0, "passing": 1.0, "tackling": 1.0, "composure": 1.0, "decisions": 1.0, "teamwork": 1.0, "marking": 0.5, "technique": 0.5, "anticipation": 0.5, "concentration": 0.5, "positioning": 0.5, "vision": 0.5, "work_rate": 0.5, "acceleration": 0.5, "agility": 0.5, "stamina": 0.5}',
+'{"first_touch": "key", "passing": "key", "tackling": "key", "composure": "key", "decisions": "key", "teamwork": "key", "marking": "preferred", "technique": "preferred", "anticipation": "preferred", "concentration": "preferred", "positioning": "preferred", "vision": "preferred", "work_rate": "preferred", "acceleration": "preferred", "agility": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Inverted Wing Back', 'WBL', 'Attack', 'Wing-back who moves inside into midfield in possession',
+'{"first_touch": 1.0, "passing": 1.0, "tackling": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "teamwork": 1.0, "vision": 1.0, "dribbling": 0.5, "marking": 0.5, "long_shots": 0.5, "anticipation": 0.5, "concentration": 0.5, "flair": 0.5, "off_the_ball": 0.5, "positioning": 0.5, "work_rate": 0.5, "acceleration": 0.5, "agility": 0.5, "stamina": 0.5}',
+'{"first_touch": "key", "passing": "key", "tackling": "key", "technique": "key", "composure": "key", "decisions": "key", "teamwork": "key", "vision": "key", "dribbling": "preferred", "marking": "preferred", "long_shots": "preferred", "anticipation": "preferred", "concentration": "preferred", "flair": "preferred", "off_the_ball": "preferred", "positioning": "preferred", "work_rate": "preferred", "acceleration": "preferred", "agility": "preferred", "stamina": "preferred"}',
+'tiers', 0);
+
+-- Defensive midfielders
+INSERT INTO roles (name, position, duty, description, attribute_weights, attribute_tiers, weighting, is_custom) VALUES
+('Anchor', 'DMC', 'Defend', 'Holding midfielder who screens the defence',
+'{"marking": 1.0, "tackling": 1.0, "anticipation": 1.0, "concentration": 1.0, "decisions": 1.0, "positioning": 1.0, "composure": 0.5, "teamwork": 0.5, "strength": 0.5}',
+'{"marking": "key", "tackling": "key", "anticipation": "key", "concentration": "key", "decisions": "key", "positioning": "key", "composure": "preferred", "teamwork": "preferred", "strength": "preferred"}',
+'tiers', 0),
+
+('Ball-Winning Midfielder', 'DMC', 'Defend', 'Midfielder who presses and wins the ball back',
+'{"tackling": 1.0, "aggression": 1.0, "anticipation": 1.0, "teamwork": 1.0, "work_rate": 1.0, "stamina": 1.0, "marking": 0.5, "bravery": 0.5, "concentration": 0.5, "positioning": 0.5, "agility": 0.5, "pace": 0.5, "strength": 0.5}',
+'{"tackling": "key", "aggression": "key", "anticipation": "key", "teamwork": "key", "work_rate": "key", "stamina": "key", "marking": "preferred", "bravery": "preferred", "concentration": "preferred", "positioning": "preferred", "agility": "preferred", "pace": "preferred", "strength": "preferred"}',
+'tiers', 0),
+
+('Ball-Winning Midfielder', 'DMC', 'Support', 'Midfielder who presses and wins the ball back',
+'{"tackling": 1.0, "aggression": 1.0, "anticipation": 1.0, "teamwork": 1.0, "work_rate": 1.0, "stamina": 1.0, "marking": 0.5, "passing": 0.5, "bravery": 0.5, "agility": 0.5, "pace": 0.5, "strength": 0.5}',
+'{"tackling": "key", "aggression": "key", "anticipation": "key", "teamwork": "key", "work_rate": "key", "stamina": "key", "marking": "preferred", "passing": "preferred", "bravery": "preferred", "agility": "preferred", "pace": "preferred", "strength": "preferred"}',
+'tiers', 0),
+
+('Deep-Lying Playmaker', 'DMC', 'Defend', 'Playmaker who dictates play from deep',
+'{"first_touch": 1.0, "passing": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "teamwork": 1.0, "vision": 1.0, "tackling": 0.5, "anticipation": 0.5, "positioning": 0.5, "balance": 0.5}',
+'{"first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "teamwork": "key", "vision": "key", "tackling": "preferred", "anticipation": "preferred", "positioning": "preferred", "balance": "preferred"}',
+'tiers', 0),
+
+('Deep-Lying Playmaker', 'DMC', 'Support', 'Playmaker who dictates play from deep',
+'{"first_touch": 1.0, "passing": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "teamwork": 1.0, "vision": 1.0, "anticipation": 0.5, "off_the_ball": 0.5, "positioning": 0.5, "balance": 0.5}',
+'{"first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "teamwork": "key", "vision": "key", "anticipation": "preferred", "off_the_ball": "preferred", "positioning": "preferred", "balance": "preferred"}',
+'tiers', 0),
+
+('Half Back', 'DMC', 'Defend', 'Midfielder who drops between the centre-backs',
+'{"marking": 1.0, "tackling": 1.0, "anticipation": 1.0, "composure": 1.0, "concentration": 1.0, "decisions": 1.0, "positioning": 1.0, "teamwork": 1.0, "first_touch": 0.5, "passing": 0.5, "aggression": 0.5, "bravery": 0.5, "jumping_reach": 0.5, "strength": 0.5}',
+'{"marking": "key", "tackling": "key", "anticipation": "key", "composure": "key", "concentration": "key", "decisions": "key", "positioning": "key", "teamwork": "key", "first_touch": "preferred", "passing": "preferred", "aggression": "preferred", "bravery": "preferred", "jumping_reach": "preferred", "strength": "preferred"}',
+'tiers', 0),
+
+('Regista', 'DMC', 'Support', 'Free-roaming deep playmaker',
+'{"dribbling": 1.0, "first_touch": 1.0, "passing": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "flair": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "vision": 1.0, "long_shots": 0.5, "anticipation": 0.5, "balance": 0.5}',
+'{"dribbling": "key", "first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "flair": "key", "off_the_ball": "key", "teamwork": "key", "vision": "key", "long_shots": "preferred", "anticipation": "preferred", "balance": "preferred"}',
+'tiers', 0),
+
+('Segundo Volante', 'DMC', 'Support', 'Defensive midfielder who arrives late in the box',
+'{"marking": 1.0, "passing": 1.0, "tackling": 1.0, "off_the_ball": 1.0, "positioning": 1.0, "work_rate": 1.0, "pace": 1.0, "stamina": 1.0, "finishing": 0.5, "first_touch": 0.5, "long_shots": 0.5, "anticipation": 0.5, "composure": 0.5, "concentration": 0.5, "decisions": 0.5, "acceleration": 0.5, "balance": 0.5, "strength": 0.5}',
+'{"marking": "key", "passing": "key", "tackling": "key", "off_the_ball": "key", "positioning": "key", "work_rate": "key", "pace": "key", "stamina": "key", "finishing": "preferred", "first_touch": "preferred", "long_shots": "preferred", "anticipation": "preferred", "composure": "preferred", "concentration": "preferred", "decisions": "preferred", "acceleration": "preferred", "balance": "preferred", "strength": "preferred"}',
+'tiers', 0),
+
+('Segundo Volante', 'DMC', 'Attack', 'Defensive midfielder who arrives late in the box',
+'{"finishing": 1.0, "long_shots": 1.0, "passing": 1.0, "tackling": 1.0, "anticipation": 1.0, "off_the_ball": 1.0, "positioning": 1.0, "work_rate": 1.0, "pace": 1.0, "stamina": 1.0, "first_touch": 0.5, "marking": 0.5, "composure": 0.5, "concentration": 0.5, "decisions": 0.5, "acceleration": 0.5, "balance": 0.5, "strength": 0.5}',
+'{"finishing": "key", "long_shots": "key", "passing": "key", "tackling": "key", "anticipation": "key", "off_the_ball": "key", "positioning": "key", "work_rate": "key", "pace": "key", "stamina": "key", "first_touch": "preferred", "marking": "preferred", "composure": "preferred", "concentration": "preferred", "decisions": "preferred", "acceleration": "preferred", "balance": "preferred", "strength": "preferred"}',
+'tiers', 0);
+
+-- Central midfielders
+INSERT INTO roles (name, position, duty, description, attribute_weights, attribute_tiers, weighting, is_custom) VALUES
+('Box-to-Box Midfielder', 'MC', 'Support', 'All-action midfielder covering the pitch',
+'{"passing": 1.0, "tackling": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "work_rate": 1.0, "stamina": 1.0, "dribbling": 0.5, "first_touch": 0.5, "long_shots": 0.5, "technique": 0.5, "aggression": 0.5, "anticipation": 0.5, "composure": 0.5, "decisions": 0.5, "positioning": 0.5, "acceleration": 0.5, "balance": 0.5, "pace": 0.5, "strength": 0.5}',
+'{"passing": "key", "tackling": "key", "off_the_ball": "key", "teamwork": "key", "work_rate": "key", "stamina": "key", "dribbling": "preferred", "first_touch": "preferred", "long_shots": "preferred", "technique": "preferred", "aggression": "preferred", "anticipation": "preferred", "composure": "preferred", "decisions": "preferred", "positioning": "preferred", "acceleration": "preferred", "balance": "preferred", "pace": "preferred", "strength": "preferred"}',
+'tiers', 0),
+
+('Central Midfielder', 'MC', 'Defend', 'Balanced central midfielder',
+'{"tackling": 1.0, "concentration": 1.0, "decisions": 1.0, "positioning": 1.0, "teamwork": 1.0, "first_touch": 0.5, "marking": 0.5, "passing": 0.5, "technique": 0.5, "aggression": 0.5, "anticipation": 0.5, "composure": 0.5, "work_rate": 0.5, "stamina": 0.5}',
+'{"tackling": "key", "concentration": "key", "decisions": "key", "positioning": "key", "teamwork": "key", "first_touch": "preferred", "marking": "preferred", "passing": "preferred", "technique": "preferred", "aggression": "preferred", "anticipation": "preferred", "composure": "preferred", "work_rate": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Central Midfielder', 'MC', 'Support', 'Balanced central midfielder',
+'{"first_touch": 1.0, "passing": 1.0, "tackling": 1.0, "decisions": 1.0, "teamwork": 1.0, "technique": 0.5, "anticipation": 0.5, "composure": 0.5, "concentration": 0.5, "off_the_ball": 0.5, "vision": 0.5, "work_rate": 0.5, "stamina": 0.5}',
+'{"first_touch": "key", "passing": "key", "tackling": "key", "decisions": "key", "teamwork": "key", "technique": "preferred", "anticipation": "preferred", "composure": "preferred", "concentration": "preferred", "off_the_ball": "preferred", "vision": "preferred", "work_rate": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Central Midfielder', 'MC', 'Attack', 'Balanced central midfielder',
+'{"first_touch": 1.0, "passing": 1.0, "decisions": 1.0, "off_the_ball": 1.0, "long_shots": 0.5, "tackling": 0.5, "technique": 0.5, "anticipation": 0.5, "composure": 0.5, "teamwork": 0.5, "vision": 0.5, "work_rate": 0.5, "acceleration": 0.5, "stamina": 0.5}',
+'{"first_touch": "key", "passing": "key", "decisions": "key", "off_the_ball": "key", "long_shots": "preferred", "tackling": "preferred", "technique": "preferred", "anticipation": "preferred", "composure": "preferred", "teamwork": "preferred", "vision": "preferred", "work_rate": "preferred", "acceleration": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Carrilero', 'MC', 'Support', 'Shuttler who covers the channels',
+'{"first_touch": 1.0, "passing": 1.0, "tackling": 1.0, "decisions": 1.0, "positioning": 1.0, "teamwork": 1.0, "stamina": 1.0, "technique": 0.5, "anticipation": 0.5, "composure": 0.5, "concentration": 0.5, "off_the_ball": 0.5, "vision": 0.5, "work_rate": 0.5}',
+'{"first_touch": "key", "passing": "key", "tackling": "key", "decisions": "key", "positioning": "key", "teamwork": "key", "stamina": "key", "technique": "preferred", "anticipation": "preferred", "composure": "preferred", "concentration": "preferred", "off_the_ball": "preferred", "vision": "preferred", "work_rate": "preferred"}',
+'tiers', 0),
+
+('Deep-Lying Midfielder', 'MC', 'Support', 'Midfielder who links defence and attack from deep',
+'{"first_touch": 1.0, "passing": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "teamwork": 1.0, "vision": 1.0, "tackling": 0.5, "anticipation": 0.5, "off_the_ball": 0.5, "positioning": 0.5, "work_rate": 0.5, "balance": 0.5}',
+'{"first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "teamwork": "key", "vision": "key", "tackling": "preferred", "anticipation": "preferred", "off_the_ball": "preferred", "positioning": "preferred", "work_rate": "preferred", "balance": "preferred"}',
+'tiers', 0),
+
+('Mezzala', 'MC', 'Support', 'Midfielder who drifts into the half-spaces',
+'{"passing": 1.0, "technique": 1.0, "decisions": 1.0, "off_the_ball": 1.0, "work_rate": 1.0, "acceleration": 1.0, "dribbling": 0.5, "first_touch": 0.5, "long_shots": 0.5, "tackling": 0.5, "anticipation": 0.5, "composure": 0.5, "vision": 0.5, "balance": 0.5, "stamina": 0.5}',
+'{"passing": "key", "technique": "key", "decisions": "key", "off_the_ball": "key", "work_rate": "key", "acceleration": "key", "dribbling": "preferred", "first_touch": "preferred", "long_shots": "preferred", "tackling": "preferred", "anticipation": "preferred", "composure": "preferred", "vision": "preferred", "balance": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Mezzala', 'MC', 'Attack', 'Midfielder who drifts into the half-spaces',
+'{"dribbling": 1.0, "passing": 1.0, "technique": 1.0, "decisions": 1.0, "off_the_ball": 1.0, "vision": 1.0, "work_rate": 1.0, "acceleration": 1.0, "first_touch": 0.5, "long_shots": 0.5, "anticipation": 0.5, "composure": 0.5, "flair": 0.5, "balance": 0.5, "stamina": 0.5}',
+'{"dribbling": "key", "passing": "key", "technique": "key", "decisions": "key", "off_the_ball": "key", "vision": "key", "work_rate": "key", "acceleration": "key", "first_touch": "preferred", "long_shots": "preferred", "anticipation": "preferred", "composure": "preferred", "flair": "preferred", "balance": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Roaming Playmaker', 'MC', 'Support', 'Playmaker who roams to find space',
+'{"first_touch": 1.0, "passing": 1.0, "technique": 1.0, "anticipation": 1.0, "composure": 1.0, "decisions": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "vision": 1.0, "work_rate": 1.0, "acceleration": 1.0, "stamina": 1.0, "dribbling": 0.5, "long_shots": 0.5, "agility": 0.5, "balance": 0.5, "pace": 0.5}',
+'{"first_touch": "key", "passing": "key", "technique": "key", "anticipation": "key", "composure": "key", "decisions": "key", "off_the_ball": "key", "teamwork": "key", "vision": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "dribbling": "preferred", "long_shots": "preferred", "agility": "preferred", "balance": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Ball-Winning Midfielder', 'MC', 'Defend', 'Midfielder who presses and wins the ball back',
+'{"tackling": 1.0, "aggression": 1.0, "anticipation": 1.0, "teamwork": 1.0, "work_rate": 1.0, "stamina": 1.0, "marking": 0.5, "bravery": 0.5, "concentration": 0.5, "positioning": 0.5, "agility": 0.5, "pace": 0.5, "strength": 0.5}',
+'{"tackling": "key", "aggression": "key", "anticipation": "key", "teamwork": "key", "work_rate": "key", "stamina": "key", "marking": "preferred", "bravery": "preferred", "concentration": "preferred", "positioning": "preferred", "agility": "preferred", "pace": "preferred", "strength": "preferred"}',
+'tiers', 0),
+
+('Ball-Winning Midfielder', 'MC', 'Support', 'Midfielder who presses and wins the ball back',
+'{"tackling": 1.0, "aggression": 1.0, "anticipation": 1.0, "teamwork": 1.0, "work_rate": 1.0, "stamina": 1.0, "marking": 0.5, "passing": 0.5, "bravery": 0.5, "agility": 0.5, "pace": 0.5, "strength": 0.5}',
+'{"tackling": "key", "aggression": "key", "anticipation": "key", "teamwork": "key", "work_rate": "key", "stamina": "key", "marking": "preferred", "passing": "preferred", "bravery": "preferred", "agility": "preferred", "pace": "preferred", "strength": "preferred"}',
+'tiers', 0),
+
+('Advanced Playmaker', 'MC', 'Support', 'Creative playmaker in advanced positions',
+'{"first_touch": 1.0, "passing": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "vision": 1.0, "dribbling": 0.5, "anticipation": 0.5, "flair": 0.5, "agility": 0.5}',
+'{"first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "off_the_ball": "key", "teamwork": "key", "vision": "key", "dribbling": "preferred", "anticipation": "preferred", "flair": "preferred", "agility": "preferred"}',
+'tiers', 0),
+
+('Advanced Playmaker', 'MC', 'Attack', 'Creative playmaker in advanced positions',
+'{"first_touch": 1.0, "passing": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "vision": 1.0, "dribbling": 0.5, "anticipation": 0.5, "flair": 0.5, "acceleration": 0.5, "agility": 0.5}',
+'{"first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "off_the_ball": "key", "teamwork": "key", "vision": "key", "dribbling": "preferred", "anticipation": "preferred", "flair": "preferred", "acceleration": "preferred", "agility": "preferred"}',
+'tiers', 0);
+
+-- Wide midfielders
+INSERT INTO roles (name, position, duty, description, attribute_weights, attribute_tiers, weighting, is_custom) VALUES
+('Winger', 'MR', 'Support', 'Wide player who beats his man and crosses',
+'{"crossing": 1.0, "dribbling": 1.0, "technique": 1.0, "acceleration": 1.0, "agility": 1.0, "first_touch": 0.5, "passing": 0.5, "off_the_ball": 0.5, "work_rate": 0.5, "balance": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"crossing": "key", "dribbling": "key", "technique": "key", "acceleration": "key", "agility": "key", "first_touch": "preferred", "passing": "preferred", "off_the_ball": "preferred", "work_rate": "preferred", "balance": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Winger', 'MR', 'Attack', 'Wide player who beats his man and crosses',
+'{"crossing": 1.0, "dribbling": 1.0, "technique": 1.0, "acceleration": 1.0, "agility": 1.0, "first_touch": 0.5, "passing": 0.5, "flair": 0.5, "off_the_ball": 0.5, "balance": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"crossing": "key", "dribbling": "key", "technique": "key", "acceleration": "key", "agility": "key", "first_touch": "preferred", "passing": "preferred", "flair": "preferred", "off_the_ball": "preferred", "balance": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Winger', 'ML', 'Support', 'Wide player who beats his man and crosses',
+'{"crossing": 1.0, "dribbling": 1.0, "technique": 1.0, "acceleration": 1.0, "agility": 1.0, "first_touch": 0.5, "passing": 0.5, "off_the_ball": 0.5, "work_rate": 0.5, "balance": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"crossing": "key", "dribbling": "key", "technique": "key", "acceleration": "key", "agility": "key", "first_touch": "preferred", "passing": "preferred", "off_the_ball": "preferred", "work_rate": "preferred", "balance": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Winger', 'ML', 'Attack', 'Wide player who beats his man and crosses',
+'{"crossing": 1.0, "dribbling": 1.0, "technique": 1.0, "acceleration": 1.0, "agility": 1.0, "first_touch": 0.5, "passing": 0.5, "flair": 0.5, "off_the_ball": 0.5, "balance": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"crossing": "key", "dribbling": "key", "technique": "key", "acceleration": "key", "agility": "key", "first_touch": "preferred", "passing": "preferred", "flair": "preferred", "off_the_ball": "preferred", "balance": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Inverted Winger', 'MR', 'Support', 'Wide player who cuts inside onto his stronger foot',
+'{"crossing": 1.0, "dribbling": 1.0, "passing": 1.0, "technique": 1.0, "acceleration": 1.0, "agility": 1.0, "first_touch": 0.5, "long_shots": 0.5, "composure": 0.5, "decisions": 0.5, "off_the_ball": 0.5, "vision": 0.5, "work_rate": 0.5, "balance": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"crossing": "key", "dribbling": "key", "passing": "key", "technique": "key", "acceleration": "key", "agility": "key", "first_touch": "preferred", "long_shots": "preferred", "composure": "preferred", "decisions": "preferred", "off_the_ball": "preferred", "vision": "preferred", "work_rate": "preferred", "balance": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Inverted Winger', 'MR', 'Attack', 'Wide player who cuts inside onto his stronger foot',
+'{"crossing": 1.0, "dribbling": 1.0, "passing": 1.0, "technique": 1.0, "acceleration": 1.0, "agility": 1.0, "first_touch": 0.5, "long_shots": 0.5, "anticipation": 0.5, "composure": 0.5, "decisions": 0.5, "flair": 0.5, "off_the_ball": 0.5, "vision": 0.5, "balance": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"crossing": "key", "dribbling": "key", "passing": "key", "technique": "key", "acceleration": "key", "agility": "key", "first_touch": "preferred", "long_shots": "preferred", "anticipation": "preferred", "composure": "preferred", "decisions": "preferred", "flair": "preferred", "off_the_ball": "preferred", "vision": "preferred", "balance": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Inverted Winger', 'ML', 'Support', 'Wide player who cuts inside onto his stronger foot',
+'{"crossing": 1.0, "dribbling": 1.0, "passing": 1.0, "technique": 1.0, "acceleration": 1.0, "agility": 1.0, "first_touch": 0.5, "long_shots": 0.5, "composure": 0.5, "decisions": 0.5, "off_the_ball": 0.5, "vision": 0.5, "work_rate": 0.5, "balance": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"crossing": "key", "dribbling": "key", "passing": "key", "technique": "key", "acceleration": "key", "agility": "key", "first_touch": "preferred", "long_shots": "preferred", "composure": "preferred", "decisions": "preferred", "off_the_ball": "preferred", "vision": "preferred", "work_rate": "preferred", "balance": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Inverted Winger', 'ML', 'Attack', 'Wide player who cuts inside onto his stronger foot',
+'{"crossing": 1.0, "dribbling": 1.0, "passing": 1.0, "technique": 1.0, "acceleration": 1.0, "agility": 1.0, "first_touch": 0.5, "long_shots": 0.5, "anticipation": 0.5, "composure": 0.5, "decisions": 0.5, "flair": 0.5, "off_the_ball": 0.5, "vision": 0.5, "balance": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"crossing": "key", "dribbling": "key", "passing": "key", "technique": "key", "acceleration": "key", "agility": "key", "first_touch": "preferred", "long_shots": "preferred", "anticipation": "preferred", "composure": "preferred", "decisions": "preferred", "flair": "preferred", "off_the_ball": "preferred", "vision": "preferred", "balance": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Wide Midfielder', 'MR', 'Defend', 'Hard-working wide midfielder',
+'{"passing": 1.0, "tackling": 1.0, "concentration": 1.0, "decisions": 1.0, "positioning": 1.0, "teamwork": 1.0, "work_rate": 1.0, "crossing": 0.5, "first_touch": 0.5, "marking": 0.5, "technique": 0.5, "anticipation": 0.5, "composure": 0.5, "stamina": 0.5}',
+'{"passing": "key", "tackling": "key", "concentration": "key", "decisions": "key", "positioning": "key", "teamwork": "key", "work_rate": "key", "crossing": "preferred", "first_touch": "preferred", "marking": "preferred", "technique": "preferred", "anticipation": "preferred", "composure": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Wide Midfielder', 'MR', 'Support', 'Hard-working wide midfielder',
+'{"passing": 1.0, "tackling": 1.0, "decisions": 1.0, "teamwork": 1.0, "work_rate": 1.0, "stamina": 1.0, "crossing": 0.5, "first_touch": 0.5, "technique": 0.5, "anticipation": 0.5, "composure": 0.5, "concentration": 0.5, "off_the_ball": 0.5, "positioning": 0.5, "vision": 0.5}',
+'{"passing": "key", "tackling": "key", "decisions": "key", "teamwork": "key", "work_rate": "key", "stamina": "key", "crossing": "preferred", "first_touch": "preferred", "technique": "preferred", "anticipation": "preferred", "composure": "preferred", "concentration": "preferred", "off_the_ball": "preferred", "positioning": "preferred", "vision": "preferred"}',
+'tiers', 0),
+
+('Wide Midfielder', 'MR', 'Attack', 'Hard-working wide midfielder',
+'{"crossing": 1.0, "first_touch": 1.0, "passing": 1.0, "decisions": 1.0, "teamwork": 1.0, "work_rate": 1.0, "stamina": 1.0, "tackling": 0.5, "technique": 0.5, "anticipation": 0.5, "composure": 0.5, "off_the_ball": 0.5, "vision": 0.5}',
+'{"crossing": "key", "first_touch": "key", "passing": "key", "decisions": "key", "teamwork": "key", "work_rate": "key", "stamina": "key", "tackling": "preferred", "technique": "preferred", "anticipation": "preferred", "composure": "preferred", "off_the_ball": "preferred", "vision": "preferred"}',
+'tiers', 0),
+
+('Wide Midfielder', 'ML', 'Defend', 'Hard-working wide midfielder',
+'{"passing": 1.0, "tackling": 1.0, "concentration": 1.0, "decisions": 1.0, "positioning": 1.0, "teamwork": 1.0, "work_rate": 1.0, "crossing": 0.5, "first_touch": 0.5, "marking": 0.5, "technique": 0.5, "anticipation": 0.5, "composure": 0.5, "stamina": 0.5}',
+'{"passing": "key", "tackling": "key", "concentration": "key", "decisions": "key", "positioning": "key", "teamwork": "key", "work_rate": "key", "crossing": "preferred", "first_touch": "preferred", "marking": "preferred", "technique": "preferred", "anticipation": "preferred", "composure": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Wide Midfielder', 'ML', 'Support', 'Hard-working wide midfielder',
+'{"passing": 1.0, "tackling": 1.0, "decisions": 1.0, "teamwork": 1.0, "work_rate": 1.0, "stamina": 1.0, "crossing": 0.5, "first_touch": 0.5, "technique": 0.5, "anticipation": 0.5, "composure": 0.5, "concentration": 0.5, "off_the_ball": 0.5, "positioning": 0.5, "vision": 0.5}',
+'{"passing": "key", "tackling": "key", "decisions": "key", "teamwork": "key", "work_rate": "key", "stamina": "key", "crossing": "preferred", "first_touch": "preferred", "technique": "preferred", "anticipation": "preferred", "composure": "preferred", "concentration": "preferred", "off_the_ball": "preferred", "positioning": "preferred", "vision": "preferred"}',
+'tiers', 0),
+
+('Wide Midfielder', 'ML', 'Attack', 'Hard-working wide midfielder',
+'{"crossing": 1.0, "first_touch": 1.0, "passing": 1.0, "decisions": 1.0, "teamwork": 1.0, "work_rate": 1.0, "stamina": 1.0, "tackling": 0.5, "technique": 0.5, "anticipation": 0.5, "composure": 0.5, "off_the_ball": 0.5, "vision": 0.5}',
+'{"crossing": "key", "first_touch": "key", "passing": "key", "decisions": "key", "teamwork": "key", "work_rate": "key", "stamina": "key", "tackling": "preferred", "technique": "preferred", "anticipation": "preferred", "composure": "preferred", "off_the_ball": "preferred", "vision": "preferred"}',
+'tiers', 0),
+
+('Wide Playmaker', 'MR', 'Support', 'Playmaker who drifts in from the flank',
+'{"first_touch": 1.0, "passing": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "teamwork": 1.0, "vision": 1.0, "dribbling": 0.5, "off_the_ball": 0.5, "agility": 0.5}',
+'{"first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "teamwork": "key", "vision": "key", "dribbling": "preferred", "off_the_ball": "preferred", "agility": "preferred"}',
+'tiers', 0),
+
+('Wide Playmaker', 'MR', 'Attack', 'Playmaker who drifts in from the flank',
+'{"dribbling": 1.0, "first_touch": 1.0, "passing": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "vision": 1.0, "anticipation": 0.5, "flair": 0.5, "acceleration": 0.5, "agility": 0.5}',
+'{"dribbling": "key", "first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "off_the_ball": "key", "teamwork": "key", "vision": "key", "anticipation": "preferred", "flair": "preferred", "acceleration": "preferred", "agility": "preferred"}',
+'tiers', 0),
+
+('Wide Playmaker', 'ML', 'Support', 'Playmaker who drifts in from the flank',
+'{"first_touch": 1.0, "passing": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "teamwork": 1.0, "vision": 1.0, "dribbling": 0.5, "off_the_ball": 0.5, "agility": 0.5}',
+'{"first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "teamwork": "key", "vision": "key", "dribbling": "preferred", "off_the_ball": "preferred", "agility": "preferred"}',
+'tiers', 0),
+
+('Wide Playmaker', 'ML', 'Attack', 'Playmaker who drifts in from the flank',
+'{"dribbling": 1.0, "first_touch": 1.0, "passing": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "vision": 1.0, "anticipation": 0.5, "flair": 0.5, "acceleration": 0.5, "agility": 0.5}',
+'{"dribbling": "key", "first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "off_the_ball": "key", "teamwork": "key", "vision": "key", "anticipation": "preferred", "flair": "preferred", "acceleration": "preferred", "agility": "preferred"}',
+'tiers', 0),
+
+('Defensive Winger', 'MR', 'Defend', 'Winger who presses and tracks back',
+'{"technique": 1.0, "anticipation": 1.0, "off_the_ball": 1.0, "positioning": 1.0, "teamwork": 1.0, "work_rate": 1.0, "stamina": 1.0, "crossing": 0.5, "dribbling": 0.5, "first_touch": 0.5, "marking": 0.5, "tackling": 0.5, "aggression": 0.5, "concentration": 0.5, "decisions": 0.5, "acceleration": 0.5}',
+'{"technique": "key", "anticipation": "key", "off_the_ball": "key", "positioning": "key", "teamwork": "key", "work_rate": "key", "stamina": "key", "crossing": "preferred", "dribbling": "preferred", "first_touch": "preferred", "marking": "preferred", "tackling": "preferred", "aggression": "preferred", "concentration": "preferred", "decisions": "preferred", "acceleration": "preferred"}',
+'tiers', 0),
+
+('Defensive Winger', 'MR', 'Support', 'Winger who presses and tracks back',
+'{"crossing": 1.0, "technique": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "work_rate": 1.0, "stamina": 1.0, "dribbling": 0.5, "first_touch": 0.5, "marking": 0.5, "passing": 0.5, "tackling": 0.5, "aggression": 0.5, "anticipation": 0.5, "composure": 0.5, "concentration": 0.5, "decisions": 0.5, "positioning": 0.5, "acceleration": 0.5}',
+'{"crossing": "key", "technique": "key", "off_the_ball": "key", "teamwork": "key", "work_rate": "key", "stamina": "key", "dribbling": "preferred", "first_touch": "preferred", "marking": "preferred", "passing": "preferred", "tackling": "preferred", "aggression": "preferred", "anticipation": "preferred", "composure": "preferred", "concentration": "preferred", "decisions": "preferred", "positioning": "preferred", "acceleration": "preferred"}',
+'tiers', 0),
+
+('Defensive Winger', 'ML', 'Defend', 'Winger who presses and tracks back',
+'{"technique": 1.0, "anticipation": 1.0, "off_the_ball": 1.0, "positioning": 1.0, "teamwork": 1.0, "work_rate": 1.0, "stamina": 1.0, "crossing": 0.5, "dribbling": 0.5, "first_touch": 0.5, "marking": 0.5, "tackling": 0.5, "aggression": 0.5, "concentration": 0.5, "decisions": 0.5, "acceleration": 0.5}',
+'{"technique": "key", "anticipation": "key", "off_the_ball": "key", "positioning": "key", "teamwork": "key", "work_rate": "key", "stamina": "key", "crossing": "preferred", "dribbling": "preferred", "first_touch": "preferred", "marking": "preferred", "tackling": "preferred", "aggression": "preferred", "concentration": "preferred", "decisions": "preferred", "acceleration": "preferred"}',
+'tiers', 0),
+
+('Defensive Winger', 'ML', 'Support', 'Winger who presses and tracks back',
+'{"crossing": 1.0, "technique": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "work_rate": 1.0, "stamina": 1.0, "dribbling": 0.5, "first_touch": 0.5, "marking": 0.5, "passing": 0.5, "tackling": 0.5, "aggression": 0.5, "anticipation": 0.5, "composure": 0.5, "concentration": 0.5, "decisions": 0.5, "positioning": 0.5, "acceleration": 0.5}',
+'{"crossing": "key", "technique": "key", "off_the_ball": "key", "teamwork": "key", "work_rate": "key", "stamina": "key", "dribbling": "preferred", "first_touch": "preferred", "marking": "preferred", "passing": "preferred", "tackling": "preferred", "aggression": "preferred", "anticipation": "preferred", "composure": "preferred", "concentration": "preferred", "decisions": "preferred", "positioning": "preferred", "acceleration": "preferred"}',
+'tiers', 0);
+
+-- Attacking midfielders
+INSERT INTO roles (name, position, duty, description, attribute_weights, attribute_tiers, weighting, is_custom) VALUES
+('Winger', 'AMR', 'Support', 'Wide player who beats his man and crosses',
+'{"crossing": 1.0, "dribbling": 1.0, "technique": 1.0, "acceleration": 1.0, "agility": 1.0, "first_touch": 0.5, "passing": 0.5, "off_the_ball": 0.5, "work_rate": 0.5, "balance": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"crossing": "key", "dribbling": "key", "technique": "key", "acceleration": "key", "agility": "key", "first_touch": "preferred", "passing": "preferred", "off_the_ball": "preferred", "work_rate": "preferred", "balance": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Winger', 'AMR', 'Attack', 'Wide player who beats his man and crosses',
+'{"crossing": 1.0, "dribbling": 1.0, "technique": 1.0, "acceleration": 1.0, "agility": 1.0, "first_touch": 0.5, "passing": 0.5, "flair": 0.5, "off_the_ball": 0.5, "balance": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"crossing": "key", "dribbling": "key", "technique": "key", "acceleration": "key", "agility": "key", "first_touch": "preferred", "passing": "preferred", "flair": "preferred", "off_the_ball": "preferred", "balance": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Winger', 'AML', 'Support', 'Wide player who beats his man and crosses',
+'{"crossing": 1.0, "dribbling": 1.0, "technique": 1.0, "acceleration": 1.0, "agility": 1.0, "first_touch": 0.5, "passing": 0.5, "off_the_ball": 0.5, "work_rate": 0.5, "balance": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"crossing": "key", "dribbling": "key", "technique": "key", "acceleration": "key", "agility": "key", "first_touch": "preferred", "passing": "preferred", "off_the_ball": "preferred", "work_rate": "preferred", "balance": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Winger', 'AML', 'Attack', 'Wide player who beats his man and crosses',
+'{"crossing": 1.0, "dribbling": 1.0, "technique": 1.0, "acceleration": 1.0, "agility": 1.0, "first_touch": 0.5, "passing": 0.5, "flair": 0.5, "off_the_ball": 0.5, "balance": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"crossing": "key", "dribbling": "key", "technique": "key", "acceleration": "key", "agility": "key", "first_touch": "preferred", "passing": "preferred", "flair": "preferred", "off_the_ball": "preferred", "balance": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Inverted Winger', 'AMR', 'Support', 'Wide player who cuts inside onto his stronger foot',
+'{"crossing": 1.0, "dribbling": 1.0, "passing": 1.0, "technique": 1.0, "acceleration": 1.0, "agility": 1.0, "first_touch": 0.5, "long_shots": 0.5, "composure": 0.5, "decisions": 0.5, "off_the_ball": 0.5, "vision": 0.5, "work_rate": 0.5, "balance": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"crossing": "key", "dribbling": "key", "passing": "key", "technique": "key", "acceleration": "key", "agility": "key", "first_touch": "preferred", "long_shots": "preferred", "composure": "preferred", "decisions": "preferred", "off_the_ball": "preferred", "vision": "preferred", "work_rate": "preferred", "balance": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Inverted Winger', 'AMR', 'Attack', 'Wide player who cuts inside onto his stronger foot',
+'{"crossing": 1.0, "dribbling": 1.0, "passing": 1.0, "technique": 1.0, "acceleration": 1.0, "agility": 1.0, "first_touch": 0.5, "long_shots": 0.5, "anticipation": 0.5, "composure": 0.5, "decisions": 0.5, "flair": 0.5, "off_the_ball": 0.5, "vision": 0.5, "balance": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"crossing": "key", "dribbling": "key", "passing": "key", "technique": "key", "acceleration": "key", "agility": "key", "first_touch": "preferred", "long_shots": "preferred", "anticipation": "preferred", "composure": "preferred", "decisions": "preferred", "flair": "preferred", "off_the_ball": "preferred", "vision": "preferred", "balance": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Inverted Winger', 'AML', 'Support', 'Wide player who cuts inside onto his stronger foot',
+'{"crossing": 1.0, "dribbling": 1.0, "passing": 1.0, "technique": 1.0, "acceleration": 1.0, "agility": 1.0, "first_touch": 0.5, "long_shots": 0.5, "composure": 0.5, "decisions": 0.5, "off_the_ball": 0.5, "vision": 0.5, "work_rate": 0.5, "balance": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"crossing": "key", "dribbling": "key", "passing": "key", "technique": "key", "acceleration": "key", "agility": "key", "first_touch": "preferred", "long_shots": "preferred", "composure": "preferred", "decisions": "preferred", "off_the_ball": "preferred", "vision": "preferred", "work_rate": "preferred", "balance": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Inverted Winger', 'AML', 'Attack', 'Wide player who cuts inside onto his stronger foot',
+'{"crossing": 1.0, "dribbling": 1.0, "passing": 1.0, "technique": 1.0, "acceleration": 1.0, "agility": 1.0, "first_touch": 0.5, "long_shots": 0.5, "anticipation": 0.5, "composure": 0.5, "decisions": 0.5, "flair": 0.5, "off_the_ball": 0.5, "vision": 0.5, "balance": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"crossing": "key", "dribbling": "key", "passing": "key", "technique": "key", "acceleration": "key", "agility": "key", "first_touch": "preferred", "long_shots": "preferred", "anticipation": "preferred", "composure": "preferred", "decisions": "preferred", "flair": "preferred", "off_the_ball": "preferred", "vision": "preferred", "balance": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Wide Playmaker', 'AMR', 'Support', 'Playmaker who drifts in from the flank',
+'{"first_touch": 1.0, "passing": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "teamwork": 1.0, "vision": 1.0, "dribbling": 0.5, "off_the_ball": 0.5, "agility": 0.5}',
+'{"first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "teamwork": "key", "vision": "key", "dribbling": "preferred", "off_the_ball": "preferred", "agility": "preferred"}',
+'tiers', 0),
+
+('Wide Playmaker', 'AMR', 'Attack', 'Playmaker who drifts in from the flank',
+'{"dribbling": 1.0, "first_touch": 1.0, "passing": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "vision": 1.0, "anticipation": 0.5, "flair": 0.5, "acceleration": 0.5, "agility": 0.5}',
+'{"dribbling": "key", "first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "off_the_ball": "key", "teamwork": "key", "vision": "key", "anticipation": "preferred", "flair": "preferred", "acceleration": "preferred", "agility": "preferred"}',
+'tiers', 0),
+
+('Wide Playmaker', 'AML', 'Support', 'Playmaker who drifts in from the flank',
+'{"first_touch": 1.0, "passing": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "teamwork": 1.0, "vision": 1.0, "dribbling": 0.5, "off_the_ball": 0.5, "agility": 0.5}',
+'{"first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "teamwork": "key", "vision": "key", "dribbling": "preferred", "off_the_ball": "preferred", "agility": "preferred"}',
+'tiers', 0),
+
+('Wide Playmaker', 'AML', 'Attack', 'Playmaker who drifts in from the flank',
+'{"dribbling": 1.0, "first_touch": 1.0, "passing": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "vision": 1.0, "anticipation": 0.5, "flair": 0.5, "acceleration": 0.5, "agility": 0.5}',
+'{"dribbling": "key", "first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "off_the_ball": "key", "teamwork": "key", "vision": "key", "anticipation": "preferred", "flair": "preferred", "acceleration": "preferred", "agility": "preferred"}',
+'tiers', 0),
+
+('Inside Forward', 'AMR', 'Support', 'Wide forward who cuts inside to score',
+'{"dribbling": 1.0, "finishing": 1.0, "first_touch": 1.0, "technique": 1.0, "off_the_ball": 1.0, "acceleration": 1.0, "agility": 1.0, "long_shots": 0.5, "passing": 0.5, "anticipation": 0.5, "composure": 0.5, "flair": 0.5, "vision": 0.5, "work_rate": 0.5, "balance": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"dribbling": "key", "finishing": "key", "first_touch": "key", "technique": "key", "off_the_ball": "key", "acceleration": "key", "agility": "key", "long_shots": "preferred", "passing": "preferred", "anticipation": "preferred", "composure": "preferred", "flair": "preferred", "vision": "preferred", "work_rate": "preferred", "balance": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Inside Forward', 'AMR', 'Attack', 'Wide forward who cuts inside to score',
+'{"dribbling": 1.0, "finishing": 1.0, "first_touch": 1.0, "technique": 1.0, "anticipation": 1.0, "off_the_ball": 1.0, "acceleration": 1.0, "agility": 1.0, "long_shots": 0.5, "passing": 0.5, "composure": 0.5, "flair": 0.5, "balance": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"dribbling": "key", "finishing": "key", "first_touch": "key", "technique": "key", "anticipation": "key", "off_the_ball": "key", "acceleration": "key", "agility": "key", "long_shots": "preferred", "passing": "preferred", "composure": "preferred", "flair": "preferred", "balance": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Inside Forward', 'AML', 'Support', 'Wide forward who cuts inside to score',
+'{"dribbling": 1.0, "finishing": 1.0, "first_touch": 1.0, "technique": 1.0, "off_the_ball": 1.0, "acceleration": 1.0, "agility": 1.0, "long_shots": 0.5, "passing": 0.5, "anticipation": 0.5, "composure": 0.5, "flair": 0.5, "vision": 0.5, "work_rate": 0.5, "balance": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"dribbling": "key", "finishing": "key", "first_touch": "key", "technique": "key", "off_the_ball": "key", "acceleration": "key", "agility": "key", "long_shots": "preferred", "passing": "preferred", "anticipation": "preferred", "composure": "preferred", "flair": "preferred", "vision": "preferred", "work_rate": "preferred", "balance": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Inside Forward', 'AML', 'Attack', 'Wide forward who cuts inside to score',
+'{"dribbling": 1.0, "finishing": 1.0, "first_touch": 1.0, "technique": 1.0, "anticipation": 1.0, "off_the_ball": 1.0, "acceleration": 1.0, "agility": 1.0, "long_shots": 0.5, "passing": 0.5, "composure": 0.5, "flair": 0.5, "balance": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"dribbling": "key", "finishing": "key", "first_touch": "key", "technique": "key", "anticipation": "key", "off_the_ball": "key", "acceleration": "key", "agility": "key", "long_shots": "preferred", "passing": "preferred", "composure": "preferred", "flair": "preferred", "balance": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Raumdeuter', 'AMR', 'Attack', 'Wide forward who finds space in the box',
+'{"finishing": 1.0, "anticipation": 1.0, "composure": 1.0, "concentration": 1.0, "decisions": 1.0, "off_the_ball": 1.0, "balance": 1.0, "first_touch": 0.5, "technique": 0.5, "acceleration": 0.5, "stamina": 0.5}',
+'{"finishing": "key", "anticipation": "key", "composure": "key", "concentration": "key", "decisions": "key", "off_the_ball": "key", "balance": "key", "first_touch": "preferred", "technique": "preferred", "acceleration": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Raumdeuter', 'AML', 'Attack', 'Wide forward who finds space in the box',
+'{"finishing": 1.0, "anticipation": 1.0, "composure": 1.0, "concentration": 1.0, "decisions": 1.0, "off_the_ball": 1.0, "balance": 1.0, "first_touch": 0.5, "technique": 0.5, "acceleration": 0.5, "stamina": 0.5}',
+'{"finishing": "key", "anticipation": "key", "composure": "key", "concentration": "key", "decisions": "key", "off_the_ball": "key", "balance": "key", "first_touch": "preferred", "technique": "preferred", "acceleration": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Advanced Playmaker', 'AMC', 'Support', 'Creative playmaker in advanced positions',
+'{"first_touch": 1.0, "passing": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "vision": 1.0, "dribbling": 0.5, "anticipation": 0.5, "flair": 0.5, "agility": 0.5}',
+'{"first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "off_the_ball": "key", "teamwork": "key", "vision": "key", "dribbling": "preferred", "anticipation": "preferred", "flair": "preferred", "agility": "preferred"}',
+'tiers', 0),
+
+('Advanced Playmaker', 'AMC', 'Attack', 'Creative playmaker in advanced positions',
+'{"first_touch": 1.0, "passing": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "vision": 1.0, "dribbling": 0.5, "anticipation": 0.5, "flair": 0.5, "acceleration": 0.5, "agility": 0.5}',
+'{"first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "off_the_ball": "key", "teamwork": "key", "vision": "key", "dribbling": "preferred", "anticipation": "preferred", "flair": "preferred", "acceleration": "preferred", "agility": "preferred"}',
+'tiers', 0),
+
+('Attacking Midfielder', 'AMC', 'Support', 'Midfielder who creates and scores from behind the striker',
+'{"first_touch": 1.0, "long_shots": 1.0, "passing": 1.0, "technique": 1.0, "anticipation": 1.0, "decisions": 1.0, "flair": 1.0, "off_the_ball": 1.0, "dribbling": 0.5, "composure": 0.5, "vision": 0.5, "agility": 0.5}',
+'{"first_touch": "key", "long_shots": "key", "passing": "key", "technique": "key", "anticipation": "key", "decisions": "key", "flair": "key", "off_the_ball": "key", "dribbling": "preferred", "composure": "preferred", "vision": "preferred", "agility": "preferred"}',
+'tiers', 0),
+
+('Attacking Midfielder', 'AMC', 'Attack', 'Midfielder who creates and scores from behind the striker',
+'{"dribbling": 1.0, "first_touch": 1.0, "long_shots": 1.0, "passing": 1.0, "technique": 1.0, "anticipation": 1.0, "decisions": 1.0, "flair": 1.0, "off_the_ball": 1.0, "finishing": 0.5, "composure": 0.5, "vision": 0.5, "agility": 0.5}',
+'{"dribbling": "key", "first_touch": "key", "long_shots": "key", "passing": "key", "technique": "key", "anticipation": "key", "decisions": "key", "flair": "key", "off_the_ball": "key", "finishing": "preferred", "composure": "preferred", "vision": "preferred", "agility": "preferred"}',
+'tiers', 0),
+
+('Enganche', 'AMC', 'Support', 'Static playmaker the attack is built around',
+'{"first_touch": 1.0, "passing": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "vision": 1.0, "dribbling": 0.5, "anticipation": 0.5, "flair": 0.5, "off_the_ball": 0.5, "teamwork": 0.5, "agility": 0.5}',
+'{"first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "vision": "key", "dribbling": "preferred", "anticipation": "preferred", "flair": "preferred", "off_the_ball": "preferred", "teamwork": "preferred", "agility": "preferred"}',
+'tiers', 0),
+
+('Shadow Striker', 'AMC', 'Attack', 'Midfielder who breaks beyond the striker',
+'{"dribbling": 1.0, "finishing": 1.0, "first_touch": 1.0, "anticipation": 1.0, "composure": 1.0, "off_the_ball": 1.0, "acceleration": 1.0, "passing": 0.5, "technique": 0.5, "concentration": 0.5, "decisions": 0.5, "work_rate": 0.5, "balance": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"dribbling": "key", "finishing": "key", "first_touch": "key", "anticipation": "key", "composure": "key", "off_the_ball": "key", "acceleration": "key", "passing": "preferred", "technique": "preferred", "concentration": "preferred", "decisions": "preferred", "work_rate": "preferred", "balance": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Trequartista', 'AMC', 'Support', 'Free-roaming creative attacker',
+'{"dribbling": 1.0, "first_touch": 1.0, "passing": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "flair": 1.0, "off_the_ball": 1.0, "vision": 1.0, "acceleration": 1.0, "finishing": 0.5, "anticipation": 0.5, "agility": 0.5, "balance": 0.5}',
+'{"dribbling": "key", "first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "flair": "key", "off_the_ball": "key", "vision": "key", "acceleration": "key", "finishing": "preferred", "anticipation": "preferred", "agility": "preferred", "balance": "preferred"}',
+'tiers', 0);
+
+-- Strikers
+INSERT INTO roles (name, position, duty, description, attribute_weights, attribute_tiers, weighting, is_custom) VALUES
+('Advanced Forward', 'STC', 'Attack', 'Forward who runs in behind the defence',
+'{"dribbling": 1.0, "finishing": 1.0, "first_touch": 1.0, "technique": 1.0, "composure": 1.0, "off_the_ball": 1.0, "acceleration": 1.0, "pace": 1.0, "passing": 0.5, "anticipation": 0.5, "decisions": 0.5, "work_rate": 0.5, "agility": 0.5, "balance": 0.5, "stamina": 0.5}',
+'{"dribbling": "key", "finishing": "key", "first_touch": "key", "technique": "key", "composure": "key", "off_the_ball": "key", "acceleration": "key", "pace": "key", "passing": "preferred", "anticipation": "preferred", "decisions": "preferred", "work_rate": "preferred", "agility": "preferred", "balance": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Complete Forward', 'STC', 'Support', 'Forward capable of everything',
+'{"dribbling": 1.0, "first_touch": 1.0, "heading": 1.0, "long_shots": 1.0, "passing": 1.0, "technique": 1.0, "anticipation": 1.0, "composure": 1.0, "decisions": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "vision": 1.0, "acceleration": 1.0, "agility": 1.0, "strength": 1.0, "finishing": 0.5, "work_rate": 0.5, "balance": 0.5, "jumping_reach": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"dribbling": "key", "first_touch": "key", "heading": "key", "long_shots": "key", "passing": "key", "technique": "key", "anticipation": "key", "composure": "key", "decisions": "key", "off_the_ball": "key", "teamwork": "key", "vision": "key", "acceleration": "key", "agility": "key", "strength": "key", "finishing": "preferred", "work_rate": "preferred", "balance": "preferred", "jumping_reach": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Complete Forward', 'STC', 'Attack', 'Forward capable of everything',
+'{"dribbling": 1.0, "finishing": 1.0, "first_touch": 1.0, "heading": 1.0, "long_shots": 1.0, "passing": 1.0, "technique": 1.0, "anticipation": 1.0, "composure": 1.0, "off_the_ball": 1.0, "acceleration": 1.0, "agility": 1.0, "strength": 1.0, "decisions": 0.5, "teamwork": 0.5, "vision": 0.5, "work_rate": 0.5, "balance": 0.5, "jumping_reach": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"dribbling": "key", "finishing": "key", "first_touch": "key", "heading": "key", "long_shots": "key", "passing": "key", "technique": "key", "anticipation": "key", "composure": "key", "off_the_ball": "key", "acceleration": "key", "agility": "key", "strength": "key", "decisions": "preferred", "teamwork": "preferred", "vision": "preferred", "work_rate": "preferred", "balance": "preferred", "jumping_reach": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Deep-Lying Forward', 'STC', 'Support', 'Forward who drops deep to link play',
+'{"first_touch": 1.0, "passing": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "finishing": 0.5, "anticipation": 0.5, "flair": 0.5, "vision": 0.5, "balance": 0.5, "strength": 0.5}',
+'{"first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "off_the_ball": "key", "teamwork": "key", "finishing": "preferred", "anticipation": "preferred", "flair": "preferred", "vision": "preferred", "balance": "preferred", "strength": "preferred"}',
+'tiers', 0),
+
+('Deep-Lying Forward', 'STC', 'Attack', 'Forward who drops deep to link play',
+'{"first_touch": 1.0, "passing": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "dribbling": 0.5, "finishing": 0.5, "anticipation": 0.5, "flair": 0.5, "vision": 0.5, "balance": 0.5, "strength": 0.5}',
+'{"first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "off_the_ball": "key", "teamwork": "key", "dribbling": "preferred", "finishing": "preferred", "anticipation": "preferred", "flair": "preferred", "vision": "preferred", "balance": "preferred", "strength": "preferred"}',
+'tiers', 0),
+
+('False Nine', 'STC', 'Support', 'Striker who drops into midfield to create space',
+'{"dribbling": 1.0, "first_touch": 1.0, "passing": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "off_the_ball": 1.0, "vision": 1.0, "acceleration": 1.0, "agility": 1.0, "finishing": 0.5, "anticipation": 0.5, "flair": 0.5, "teamwork": 0.5, "balance": 0.5}',
+'{"dribbling": "key", "first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "off_the_ball": "key", "vision": "key", "acceleration": "key", "agility": "key", "finishing": "preferred", "anticipation": "preferred", "flair": "preferred", "teamwork": "preferred", "balance": "preferred"}',
+'tiers', 0),
+
+('Poacher', 'STC', 'Attack', 'Striker who lives in the box',
+'{"finishing": 1.0, "anticipation": 1.0, "composure": 1.0, "off_the_ball": 1.0, "first_touch": 0.5, "heading": 0.5, "technique": 0.5, "decisions": 0.5, "acceleration": 0.5}',
+'{"finishing": "key", "anticipation": "key", "composure": "key", "off_the_ball": "key", "first_touch": "preferred", "heading": "preferred", "technique": "preferred", "decisions": "preferred", "acceleration": "preferred"}',
+'tiers', 0),
+
+('Pressing Forward', 'STC', 'Defend', 'Forward who harries the defence',
+'{"aggression": 1.0, "anticipation": 1.0, "bravery": 1.0, "decisions": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "pace": 1.0, "stamina": 1.0, "first_touch": 0.5, "composure": 0.5, "concentration": 0.5, "agility": 0.5, "balance": 0.5, "strength": 0.5}',
+'{"aggression": "key", "anticipation": "key", "bravery": "key", "decisions": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "pace": "key", "stamina": "key", "first_touch": "preferred", "composure": "preferred", "concentration": "preferred", "agility": "preferred", "balance": "preferred", "strength": "preferred"}',
+'tiers', 0),
+
+('Pressing Forward', 'STC', 'Support', 'Forward who harries the defence',
+'{"aggression": 1.0, "anticipation": 1.0, "bravery": 1.0, "decisions": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "pace": 1.0, "stamina": 1.0, "first_touch": 0.5, "passing": 0.5, "composure": 0.5, "concentration": 0.5, "off_the_ball": 0.5, "agility": 0.5, "balance": 0.5, "strength": 0.5}',
+'{"aggression": "key", "anticipation": "key", "bravery": "key", "decisions": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "pace": "key", "stamina": "key", "first_touch": "preferred", "passing": "preferred", "composure": "preferred", "concentration": "preferred", "off_the_ball": "preferred", "agility": "preferred", "balance": "preferred", "strength": "preferred"}',
+'tiers', 0),
+
+('Pressing Forward', 'STC', 'Attack', 'Forward who harries the defence',
+'{"finishing": 1.0, "aggression": 1.0, "anticipation": 1.0, "bravery": 1.0, "off_the_ball": 1.0, "teamwork": 1.0, "work_rate": 1.0, "acceleration": 1.0, "pace": 1.0, "stamina": 1.0, "first_touch": 0.5, "composure": 0.5, "concentration": 0.5, "decisions": 0.5, "agility": 0.5, "balance": 0.5, "strength": 0.5}',
+'{"finishing": "key", "aggression": "key", "anticipation": "key", "bravery": "key", "off_the_ball": "key", "teamwork": "key", "work_rate": "key", "acceleration": "key", "pace": "key", "stamina": "key", "first_touch": "preferred", "composure": "preferred", "concentration": "preferred", "decisions": "preferred", "agility": "preferred", "balance": "preferred", "strength": "preferred"}',
+'tiers', 0),
+
+('Target Man', 'STC', 'Support', 'Physical forward who holds up play',
+'{"heading": 1.0, "bravery": 1.0, "teamwork": 1.0, "balance": 1.0, "jumping_reach": 1.0, "strength": 1.0, "first_touch": 0.5, "aggression": 0.5, "anticipation": 0.5, "composure": 0.5, "decisions": 0.5, "off_the_ball": 0.5}',
+'{"heading": "key", "bravery": "key", "teamwork": "key", "balance": "key", "jumping_reach": "key", "strength": "key", "first_touch": "preferred", "aggression": "preferred", "anticipation": "preferred", "composure": "preferred", "decisions": "preferred", "off_the_ball": "preferred"}',
+'tiers', 0),
+
+('Target Man', 'STC', 'Attack', 'Physical forward who holds up play',
+'{"finishing": 1.0, "heading": 1.0, "bravery": 1.0, "composure": 1.0, "off_the_ball": 1.0, "balance": 1.0, "jumping_reach": 1.0, "strength": 1.0, "first_touch": 0.5, "aggression": 0.5, "anticipation": 0.5, "decisions": 0.5, "teamwork": 0.5}',
+'{"finishing": "key", "heading": "key", "bravery": "key", "composure": "key", "off_the_ball": "key", "balance": "key", "jumping_reach": "key", "strength": "key", "first_touch": "preferred", "aggression": "preferred", "anticipation": "preferred", "decisions": "preferred", "teamwork": "preferred"}',
+'tiers', 0),
+
+('Trequartista Forward', 'STC', 'Support', 'Free-roaming creative forward',
+'{"dribbling": 1.0, "finishing": 1.0, "first_touch": 1.0, "passing": 1.0, "technique": 1.0, "composure": 1.0, "decisions": 1.0, "flair": 1.0, "off_the_ball": 1.0, "vision": 1.0, "acceleration": 1.0, "anticipation": 0.5, "agility": 0.5, "balance": 0.5}',
+'{"dribbling": "key", "finishing": "key", "first_touch": "key", "passing": "key", "technique": "key", "composure": "key", "decisions": "key", "flair": "key", "off_the_ball": "key", "vision": "key", "acceleration": "key", "anticipation": "preferred", "agility": "preferred", "balance": "preferred"}',
+'tiers', 0);
@@ -0,0 +1,76 @@
+-- Which FM role (RoleType) each role is; custom roles name the role they specialise
+ALTER TABLE roles ADD COLUMN role_type TEXT; -- RoleType variant, e.g. BallPlayingDefender
+
+UPDATE roles SET role_type = CASE name
+    WHEN 'Goalkeeper' THEN 'Goalkeeper'
+    WHEN 'Sweeper Keeper' THEN 'SweeperKeeper'
+    WHEN 'Full Back' THEN 'FullBack'
+    WHEN 'Wing Back' THEN 'WingBack'
+    WHEN 'Complete Wing Back' THEN 'CompleteWingBack'
+    WHEN 'Inverted Wing Back' THEN 'InvertedWingBack'
+    WHEN 'Central Defender' THEN 'CentralDefender'
+    WHEN 'Ball-Playing Defender' THEN 'BallPlayingDefender'
+    WHEN 'Libero' THEN 'Libero'
+    WHEN 'No-Challenge Defender' THEN 'NoChallengeDefender'
+    WHEN 'Anchor' THEN 'Anchor'
+    WHEN 'Ball-Winning Midfielder' THEN 'BallWinningMidfielder'
+    WHEN 'Deep-Lying Playmaker' THEN 'DeepLyingPlaymaker'
+    WHEN 'Half Back' THEN 'HalfBack'
+    WHEN 'Regista' THEN 'Regista'
+    WHEN 'Segundo Volante' THEN 'SegundoVolante'
+    WHEN 'Box-to-Box Midfielder' THEN 'BoxToBoxMidfielder'
+    WHEN 'Central Midfielder' THEN 'CentralMidfielder'
+    WHEN 'Carrilero' THEN 'Carrilero'
+    WHEN 'Deep-Lying Midfielder' THEN 'DeepLyingMidfielderSupport'
+    WHEN 'Mezzala' THEN 'Mezzala'
+    WHEN 'Roaming Playmaker' THEN 'RoamingPlaymaker'
+    WHEN 'Winger' THEN 'Winger'
+    WHEN 'Inverted Winger' THEN 'InvertedWinger'
+    WHEN 'Wide Midfielder' THEN 'WideMidfielder'
+    WHEN 'Wide Playmaker' THEN 'WidePlaymaker'
+    WHEN 'Defensive Winger' THEN 'DefensiveWinger'
+    WHEN 'Advanced Playmaker' THEN 'AdvancedPlaymaker'
+    WHEN 'Attacking Midfielder' THEN 'AttackingMidfielder'
+    WHEN 'Enganche' THEN 'Enganche'
+    WHEN 'Shadow Striker' THEN 'ShadowStriker'
+    WHEN 'Trequartista' THEN 'Trequartista'
+    WHEN 'Advanced Forward' THEN 'AdvancedForward'
+    WHEN 'Complete Forward' THEN 'CompleteForward'
+    WHEN 'Deep-Lying Forward' THEN 'DeepLyingForward'
+    WHEN 'False Nine' THEN 'FalseNine'
+    WHEN 'Inside Forward' THEN 'InsideForward'
+    WHEN 'Poacher' THEN 'Poacher'
+    WHEN 'Pressing Forward' THEN 'PressingForward'
+    WHEN 'Raumdeuter' THEN 'Raumdeuter'
+    WHEN 'Target Man' THEN 'TargetMan'
+    WHEN 'Trequartista Forward' THEN 'TrequartistaForward'
+END
+WHERE workspace_id IS NULL;
+
+-- Existing custom roles: a predefined role with the same name, else the first
+-- predefined role for the same position and duty, else for the same position
+UPDATE roles SET role_type = (
+    SELECT p.role_type FROM roles p
+    WHERE p.workspace_id IS NULL AND p.name = roles.name
+    ORDER BY p.id
+    LIMIT 1
+)
+WHERE workspace_id IS NOT NULL;
+
+UPDATE roles SET role_type = (
+    SELECT p.role_type FROM roles p
+    WHERE p.workspace_id IS NULL AND p.position = roles.position AND p.duty = roles.duty
+    ORDER BY p.id
+    LIMIT 1
+)
+WHERE workspace_id IS NOT NULL AND role_type IS NULL;
+
+UPDATE roles SET role_type = (
+    SELECT p.role_type FROM roles p
+    WHERE p.workspace_id IS NULL AND p.position = roles.position
+    ORDER BY p.id
+    LIMIT 1
+)
+WHERE workspace_id IS NOT NULL AND role_type IS NULL;
+
+CREATE INDEX idx_roles_tactical ON roles(position, role_type, duty);
@@ -0,0 +1,36 @@
+-- Which FM release each workspace's save comes from; it decides which roles are on offer
+-- (GameVersion in the shared crate). Existing saves move to FM24, which keeps every role.
+ALTER TABLE workspaces ADD COLUMN game_version TEXT NOT NULL DEFAULT 'FM24'; -- FM23, FM24 or FM26
+
+-- Roles FM24 added. The catalogue holds every version's roles; lists only show the ones
+-- the workspace's version has.
+INSERT INTO roles (name, role_type, position, duty, description, attribute_weights, attribute_tiers, weighting, is_custom) VALUES
+('Inverted Full Back', 'InvertedFullBack', 'DR', 'Defend', 'Full-back who tucks inside to form a back three in possession',
+'{"heading": 1.0, "marking": 1.0, "passing": 1.0, "tackling": 1.0, "composure": 1.0, "positioning": 1.0, "strength": 1.0, "first_touch": 0.5, "technique": 0.5, "aggression": 0.5, "anticipation": 0.5, "bravery": 0.5, "concentration": 0.5, "decisions": 0.5, "jumping_reach": 0.5, "pace": 0.5}',
+'{"heading": "key", "marking": "key", "passing": "key", "tackling": "key", "composure": "key", "positioning": "key", "strength": "key", "first_touch": "preferred", "technique": "preferred", "aggression": "preferred", "anticipation": "preferred", "bravery": "preferred", "concentration": "preferred", "decisions": "preferred", "jumping_reach": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Inverted Full Back', 'InvertedFullBack', 'DL', 'Defend', 'Full-back who tucks inside to form a back three in possession',
+'{"heading": 1.0, "marking": 1.0, "passing": 1.0, "tackling": 1.0, "composure": 1.0, "positioning": 1.0, "strength": 1.0, "first_touch": 0.5, "technique": 0.5, "aggression": 0.5, "anticipation": 0.5, "bravery": 0.5, "concentration": 0.5, "decisions": 0.5, "jumping_reach": 0.5, "pace": 0.5}',
+'{"heading": "key", "marking": "key", "passing": "key", "tackling": "key", "composure": "key", "positioning": "key", "strength": "key", "first_touch": "preferred", "technique": "preferred", "aggression": "preferred", "anticipation": "preferred", "bravery": "preferred", "concentration": "preferred", "decisions": "preferred", "jumping_reach": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Wide Centre-Back', 'WideCentreBack', 'DC', 'Defend', 'Centre-back in a back three who covers and carries the ball into the channels',
+'{"heading": 1.0, "marking": 1.0, "tackling": 1.0, "positioning": 1.0, "jumping_reach": 1.0, "strength": 1.0, "dribbling": 0.5, "passing": 0.5, "technique": 0.5, "aggression": 0.5, "anticipation": 0.5, "bravery": 0.5, "composure": 0.5, "concentration": 0.5, "decisions": 0.5, "pace": 0.5}',
+'{"heading": "key", "marking": "key", "tackling": "key", "positioning": "key", "jumping_reach": "key", "strength": "key", "dribbling": "preferred", "passing": "preferred", "technique": "preferred", "aggression": "preferred", "anticipation": "preferred", "bravery": "preferred", "composure": "preferred", "concentration": "preferred", "decisions": "preferred", "pace": "preferred"}',
+'tiers', 0),
+
+('Wide Centre-Back', 'WideCentreBack', 'DC', 'Support', 'Centre-back in a back three who covers and carries the ball into the channels',
+'{"dribbling": 1.0, "heading": 1.0, "marking": 1.0, "tackling": 1.0, "positioning": 1.0, "jumping_reach": 1.0, "strength": 1.0, "crossing": 0.5, "passing": 0.5, "technique": 0.5, "aggression": 0.5, "anticipation": 0.5, "bravery": 0.5, "composure": 0.5, "concentration": 0.5, "decisions": 0.5, "off_the_ball": 0.5, "pace": 0.5, "stamina": 0.5}',
+'{"dribbling": "key", "heading": "key", "marking": "key", "tackling": "key", "positioning": "key", "jumping_reach": "key", "strength": "key", "crossing": "preferred", "passing": "preferred", "technique": "preferred", "aggression": "preferred", "anticipation": "preferred", "bravery": "preferred", "composure": "preferred", "concentration": "preferred", "decisions": "preferred", "off_the_ball": "preferred", "pace": "preferred", "stamina": "preferred"}',
+'tiers', 0),
+
+('Wide Centre-Back', 'WideCentreBack', 'DC', 'Attack', 'Centre-back in a back three who covers and carries the ball into the channels',
+'{"crossing": 1.0, "dribbling": 1.0, "heading": 1.0, "marking": 1.0, "tackling": 1.0, "off_the_ball": 1.0, "positioning": 1.0, "jumping_reach": 1.0, "pace": 1.0, "stamina": 1.0, "strength": 1.0, "passing": 0.5, "technique": 0.5, "aggression": 0.5, "anticipation": 0.5, "bravery": 0.5, "composure": 0.5, "concentration": 0.5, "decisions": 0.5, "work_rate": 0.5}',
+'{"crossing": "key", "dribbling": "key", "heading": "key", "marking": "key", "tackling": "key", "off_the_ball": "key", "positioning": "key", "jumping_reach": "key", "pace": "key", "stamina": "key", "strength": "key", "passing": "preferred", "technique": "preferred", "aggression": "preferred", "anticipation": "preferred", "bravery": "preferred", "composure": "preferred", "concentration": "preferred", "decisions": "preferred", "work_rate": "preferred"}',
+'tiers', 0),
+
+('Channel Midfielder', 'ChannelMidfielder', 'MC', 'Support', 'Midfielder who runs into the channel between full-back and centre-back',
+'{"first_touch": 1.0, "passing": 1.0, "decisions": 1.0, "off_the_ball": 1.0, "work_rate": 1.0, "acceleration": 1.0, "stamina": 1.0, "dribbling": 0.5, "technique": 0.5, "anticipation": 0.5, "composure": 0.5, "teamwork": 0.5, "vision": 0.5, "pace": 0.5}',
+'{"first_touch": "key", "passing": "key", "decisions": "key", "off_the_ball": "key", "work_rate": "key", "acceleration": "key", "stamina": "key", "dribbling": "preferred", "technique": "preferred", "anticipation": "preferred", "composure": "preferred", "teamwork": "preferred", "vision": "preferred", "pace": "preferred"}',
+'tiers', 0);
@@ -0,0 +1,4 @@
+-- How well each player knows positions other than their main one, as a JSON object keyed
+-- by position: {"DMC": "accomplished", "DR": "unconvincing"}. The main position is natural
+-- unless listed; positions left out are awkward.
+ALTER TABLE players ADD COLUMN position_familiarity TEXT NOT NULL DEFAULT '{}';
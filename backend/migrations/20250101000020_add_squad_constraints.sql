@@ -0,0 +1,4 @@
+-- The lineup constraints a squad was picked under, as a JSON object:
+-- {"locked": [{"player_id": 4, "slot": 0}], "excluded": [{"player_id": 9, "reason": "injured"}],
+--  "minutes_caps": [{"player_id": 12, "max_minutes": 45}]}
+ALTER TABLE squads ADD COLUMN constraints TEXT NOT NULL DEFAULT '{}';
@@ -0,0 +1,83 @@
+-- Give each predefined role's duty its own description; the catalogue reused one
+-- description for every duty of a role.
+UPDATE roles SET description = CASE name || '/' || duty
+    WHEN 'Advanced Forward/Attack' THEN 'Forward who runs in behind the defence and stretches it'
+    WHEN 'Advanced Playmaker/Attack' THEN 'Playmaker between the lines who also gets into the box to score'
+    WHEN 'Advanced Playmaker/Support' THEN 'Playmaker who finds space between the lines and feeds the forwards'
+    WHEN 'Anchor/Defend' THEN 'Holding midfielder who sits in front of the defence and screens it'
+    WHEN 'Attacking Midfielder/Attack' THEN 'Midfielder who gets into the box from behind the striker to score'
+    WHEN 'Attacking Midfielder/Support' THEN 'Midfielder who links with the striker and creates chances from the hole'
+    WHEN 'Ball-Playing Defender/Defend' THEN 'Centre-back who stays back but starts moves with passes from deep'
+    WHEN 'Ball-Playing Defender/Support' THEN 'Centre-back who steps up with the ball and plays through the lines'
+    WHEN 'Ball-Winning Midfielder/Defend' THEN 'Midfielder who hunts down the ball and gives it to a teammate'
+    WHEN 'Ball-Winning Midfielder/Support' THEN 'Midfielder who presses high to win the ball and joins the attack'
+    WHEN 'Box-to-Box Midfielder/Support' THEN 'All-action midfielder who defends in one box and arrives late in the other'
+    WHEN 'Carrilero/Support' THEN 'Shuttler who covers the channel beside the holding midfielder'
+    WHEN 'Central Defender/Defend' THEN 'Centre-back who steps out to close down attackers and win the ball'
+    WHEN 'Central Defender/Support' THEN 'Centre-back who holds the line and keeps the shape of the defence'
+    WHEN 'Central Midfielder/Attack' THEN 'Central midfielder who makes runs forward to join the attack'
+    WHEN 'Central Midfielder/Defend' THEN 'Central midfielder who sits back and protects the defence'
+    WHEN 'Central Midfielder/Support' THEN 'Balanced central midfielder who helps both defence and attack'
+    WHEN 'Channel Midfielder/Support' THEN 'Midfielder who runs into the channel between full-back and centre-back'
+    WHEN 'Complete Forward/Attack' THEN 'Forward who can do everything and gets into the box to finish moves'
+    WHEN 'Complete Forward/Support' THEN 'Forward who can do everything and drops deep to bring others into play'
+    WHEN 'Complete Wing Back/Attack' THEN 'Wing-back who attacks relentlessly and is the team''s main source of width'
+    WHEN 'Complete Wing Back/Support' THEN 'Wing-back who supplies width and creativity while still tracking back'
+    WHEN 'Deep-Lying Forward/Attack' THEN 'Forward who drops deep to link play and then breaks into the box'
+    WHEN 'Deep-Lying Forward/Support' THEN 'Forward who drops deep to hold up the ball and bring runners into play'
+    WHEN 'Deep-Lying Midfielder/Support' THEN 'Midfielder who links defence and attack from deep'
+    WHEN 'Deep-Lying Playmaker/Defend' THEN 'Playmaker who stays in front of the defence and dictates play from there'
+    WHEN 'Deep-Lying Playmaker/Support' THEN 'Playmaker who dictates play from deep and moves forward to keep it going'
+    WHEN 'Defensive Winger/Defend' THEN 'Winger whose main job is to press and track back down the flank'
+    WHEN 'Defensive Winger/Support' THEN 'Winger who presses the full-back and still crosses when the chance comes'
+    WHEN 'Enganche/Support' THEN 'Static playmaker the attack is built around'
+    WHEN 'False Nine/Support' THEN 'Striker who drops into midfield to create space'
+    WHEN 'Full Back/Attack' THEN 'Full-back who overlaps to cross but still defends the flank'
+    WHEN 'Full Back/Defend' THEN 'Full-back who stays back and marks the opposing winger'
+    WHEN 'Full Back/Support' THEN 'Full-back who defends first and supports the attack down the flank'
+    WHEN 'Goalkeeper/Automatic' THEN 'Traditional goalkeeper focused on shot-stopping'
+    WHEN 'Half Back/Defend' THEN 'Midfielder who drops between the centre-backs'
+    WHEN 'Inside Forward/Attack' THEN 'Wide forward who cuts inside to shoot and attacks the box'
+    WHEN 'Inside Forward/Support' THEN 'Wide forward who cuts inside to link up and create chances'
+    WHEN 'Inverted Full Back/Defend' THEN 'Full-back who tucks inside to form a back three in possession'
+    WHEN 'Inverted Wing Back/Attack' THEN 'Wing-back who moves inside in possession and drives forward into the final third'
+    WHEN 'Inverted Wing Back/Defend' THEN 'Wing-back who moves inside to help in midfield and stays in front of the ball'
+    WHEN 'Inverted Wing Back/Support' THEN 'Wing-back who moves inside into midfield in possession to build play'
+    WHEN 'Inverted Winger/Attack' THEN 'Wide player who cuts inside onto the stronger foot and shoots or plays through'
+    WHEN 'Inverted Winger/Support' THEN 'Wide player who cuts inside onto the stronger foot to pass and cross'
+    WHEN 'Libero/Defend' THEN 'Defender who steps out of the line with the ball and drops back into it'
+    WHEN 'Libero/Support' THEN 'Defender who steps out of the line and joins midfield in possession'
+    WHEN 'Mezzala/Attack' THEN 'Midfielder who drifts into the half-spaces and gets into the box'
+    WHEN 'Mezzala/Support' THEN 'Midfielder who drifts into the half-spaces to link with the flanks'
+    WHEN 'No-Challenge Defender/Defend' THEN 'No-nonsense defender who clears the danger'
+    WHEN 'Poacher/Attack' THEN 'Striker who lives in the box'
+    WHEN 'Pressing Forward/Attack' THEN 'Forward who harries the defence and attacks the space behind it'
+    WHEN 'Pressing Forward/Defend' THEN 'Forward who presses from the front and drops to defend the midfield'
+    WHEN 'Pressing Forward/Support' THEN 'Forward who harries the defence and links play when the ball is won'
+    WHEN 'Raumdeuter/Attack' THEN 'Wide forward who finds space in the box'
+    WHEN 'Regista/Support' THEN 'Free-roaming deep playmaker'
+    WHEN 'Roaming Playmaker/Support' THEN 'Playmaker who roams to find space'
+    WHEN 'Segundo Volante/Attack' THEN 'Defensive midfielder who makes runs from deep to score'
+    WHEN 'Segundo Volante/Support' THEN 'Defensive midfielder who arrives late in the box'
+    WHEN 'Shadow Striker/Attack' THEN 'Midfielder who breaks beyond the striker'
+    WHEN 'Sweeper Keeper/Automatic' THEN 'Keeper who leaves the line to sweep up behind the defence'
+    WHEN 'Target Man/Attack' THEN 'Physical forward who wins headers and finishes in the box'
+    WHEN 'Target Man/Support' THEN 'Physical forward who holds up play and lays it off to runners'
+    WHEN 'Trequartista/Support' THEN 'Free-roaming creative attacker'
+    WHEN 'Trequartista Forward/Support' THEN 'Free-roaming creative forward'
+    WHEN 'Wide Centre-Back/Attack' THEN 'Centre-back in a back three who overlaps down the flank to cross'
+    WHEN 'Wide Centre-Back/Defend' THEN 'Centre-back in a back three who covers the channel behind the wing-back'
+    WHEN 'Wide Centre-Back/Support' THEN 'Centre-back in a back three who carries the ball into the channels'
+    WHEN 'Wide Midfielder/Attack' THEN 'Wide midfielder who gets forward to cross and support the strikers'
+    WHEN 'Wide Midfielder/Defend' THEN 'Wide midfielder who tracks back and doubles up with the full-back'
+    WHEN 'Wide Midfielder/Support' THEN 'Hard-working wide midfielder who helps both ends of the flank'
+    WHEN 'Wide Playmaker/Attack' THEN 'Playmaker who drifts in from the flank and makes runs into the box'
+    WHEN 'Wide Playmaker/Support' THEN 'Playmaker who drifts in from the flank to dictate play'
+    WHEN 'Wing Back/Attack' THEN 'Wing-back who bombs forward to provide width and crosses'
+    WHEN 'Wing Back/Defend' THEN 'Wing-back who covers the touchline but stays back when the team attacks'
+    WHEN 'Wing Back/Support' THEN 'Flank player covering the whole touchline'
+    WHEN 'Winger/Attack' THEN 'Wide player who beats the full-back and crosses or cuts in to shoot'
+    WHEN 'Winger/Support' THEN 'Wide player who takes on the full-back and crosses from the byline'
+    ELSE description
+END
+WHERE workspace_id IS NULL;
@@ -6,6 +6,7 @@ pub enum AppError {
     DatabaseError(sqlx::Error),
     NotFound(String),
     ValidationError(String),
+    Conflict(String),
     InternalError(String),
 }
 
@@ -15,6 +16,7 @@ impl fmt::Display for AppError {
             AppError::DatabaseError(err) => write!(f, "Database error: {}", err),
             AppError::NotFound(msg) => write!(f, "Not found: {}", msg),
             AppError::ValidationError(msg) => write!(f, "Validation error: {}", msg),
+            AppError::Conflict(msg) => write!(f, "Conflict: {}", msg),
             AppError::InternalError(msg) => write!(f, "Internal error: {}", msg),
         }
     }
@@ -40,6 +42,11 @@ impl ResponseError for AppError {
                     "error": msg
                 }))
             }
+            AppError::Conflict(msg) => {
+                HttpResponse::Conflict().json(serde_json::json!({
+                    "error": msg
+                }))
+            }
             AppError::InternalError(msg) => {
                 HttpResponse::InternalServerError().json(serde_json::json!({
                     "error": msg
@@ -53,6 +60,7 @@ impl ResponseError for AppError {
             AppError::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
             AppError::NotFound(_) => StatusCode::NOT_FOUND,
             AppError::ValidationError(_) => StatusCode::BAD_REQUEST,
+            AppError::Conflict(_) => StatusCode::CONFLICT,
             AppError::InternalError(_) => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -60,7 +68,18 @@ impl ResponseError for AppError {
 
 impl From<sqlx::Error> for AppError {
     fn from(err: sqlx::Error) -> Self {
-        AppError::DatabaseError(err)
+        match &err {
+            sqlx::Error::RowNotFound => AppError::NotFound("Record not found".to_string()),
+            sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
+                AppError::Conflict(db_err.message().to_string())
+            }
+            sqlx::Error::Database(db_err)
+                if db_err.is_check_violation() || db_err.is_foreign_key_violation() =>
+            {
+                AppError::ValidationError(db_err.message().to_string())
+            }
+            _ => AppError::DatabaseError(err),
+        }
     }
 }
 
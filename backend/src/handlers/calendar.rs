@@ -0,0 +1,34 @@
+use actix_web::{web, HttpResponse};
+use sqlx::SqlitePool;
+use shared::models::GameCalendar;
+use crate::error::AppResult;
+use crate::repository::CalendarRepository;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/calendar")
+            .route("", web::get().to(get_calendar))
+            .route("", web::put().to(set_calendar)),
+    );
+}
+
+async fn get_calendar(
+    pool: web::Data<SqlitePool>,
+    workspace_id: web::Path<i64>,
+) -> AppResult<HttpResponse> {
+    let game_date = CalendarRepository::new(pool.get_ref().clone(), workspace_id.into_inner())
+        .get_game_date()
+        .await?;
+    Ok(HttpResponse::Ok().json(GameCalendar { game_date }))
+}
+
+async fn set_calendar(
+    pool: web::Data<SqlitePool>,
+    workspace_id: web::Path<i64>,
+    calendar: web::Json<GameCalendar>,
+) -> AppResult<HttpResponse> {
+    CalendarRepository::new(pool.get_ref().clone(), workspace_id.into_inner())
+        .set_game_date(calendar.game_date)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
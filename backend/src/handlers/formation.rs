@@ -0,0 +1,182 @@
+use actix_web::{web, HttpResponse};
+use serde::Deserialize;
+use sqlx::SqlitePool;
+use shared::models::Formation;
+use shared::recruitment;
+use shared::selection::{self, FormationRanking};
+use crate::error::{AppError, AppResult};
+use crate::handlers::created;
+use crate::handlers::player::SuitabilityQuery;
+use crate::repository::{FormationRepository, PlayerRepository, RoleRepository};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/formations")
+            .route("", web::get().to(list_formations))
+            .route("", web::post().to(create_formation))
+            .route("/custom", web::get().to(get_custom_formations))
+            .route("/compare", web::get().to(compare_formations))
+            .route("/{id}", web::get().to(get_formation))
+            .route("/{id}/gaps", web::get().to(get_gap_report))
+            .route("/{id}", web::put().to(update_formation))
+            .route("/{id}", web::delete().to(delete_formation)),
+    );
+}
+
+/// How formations are compared
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct CompareQuery {
+    pub ranking: FormationRanking,
+    /// Score every slot with its position's generic role instead of the one chosen for it
+    pub default_roles: bool,
+}
+
+/// The suitability every slot should reach, and limits for the recruitment briefs
+#[derive(Debug, Deserialize)]
+pub struct GapQuery {
+    pub target: Option<f32>,
+    pub max_age: Option<i32>,
+    /// Weekly wage ceiling
+    pub max_wage: Option<i64>,
+}
+
+fn validate(formation: &Formation) -> AppResult<()> {
+    if !formation.is_valid() {
+        return Err(AppError::ValidationError(format!(
+            "Formation must have 11 positions, got {}",
+            formation.player_count()
+        )));
+    }
+    Ok(())
+}
+
+async fn list_formations(
+    pool: web::Data<SqlitePool>,
+    workspace_id: web::Path<i64>,
+) -> AppResult<HttpResponse> {
+    let formations = FormationRepository::new(pool.get_ref().clone(), workspace_id.into_inner())
+        .get_all()
+        .await?;
+    Ok(HttpResponse::Ok().json(formations))
+}
+
+async fn create_formation(
+    pool: web::Data<SqlitePool>,
+    workspace_id: web::Path<i64>,
+    formation: web::Json<Formation>,
+) -> AppResult<HttpResponse> {
+    validate(&formation)?;
+    let id = FormationRepository::new(pool.get_ref().clone(), workspace_id.into_inner())
+        .create(&formation)
+        .await?;
+    Ok(created(id))
+}
+
+async fn get_formation(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    let formation = FormationRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_by_id(id)
+        .await?;
+    Ok(HttpResponse::Ok().json(formation))
+}
+
+async fn update_formation(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+    formation: web::Json<Formation>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    validate(&formation)?;
+    FormationRepository::new(pool.get_ref().clone(), workspace_id)
+        .update(id, &formation)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+async fn delete_formation(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    FormationRepository::new(pool.get_ref().clone(), workspace_id)
+        .delete(id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+async fn get_custom_formations(
+    pool: web::Data<SqlitePool>,
+    workspace_id: web::Path<i64>,
+) -> AppResult<HttpResponse> {
+    let formations = FormationRepository::new(pool.get_ref().clone(), workspace_id.into_inner())
+        .get_custom_formations()
+        .await?;
+    Ok(HttpResponse::Ok().json(formations))
+}
+
+/// Every formation ranked by how well its best XI suits the players, scored with the same
+/// options as `generate_squad`. Formations no XI can be picked for are listed with the reason.
+async fn compare_formations(
+    pool: web::Data<SqlitePool>,
+    workspace_id: web::Path<i64>,
+    query: web::Query<SuitabilityQuery>,
+    compare: web::Query<CompareQuery>,
+) -> AppResult<HttpResponse> {
+    let workspace_id = workspace_id.into_inner();
+    let formations = FormationRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_all()
+        .await?;
+    let roles = RoleRepository::new(pool.get_ref().clone(), workspace_id);
+    let players = PlayerRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_all()
+        .await?;
+
+    let formations = formations
+        .into_iter()
+        .map(|formation| if compare.default_roles { formation.with_default_roles() } else { formation })
+        .collect();
+    // A chosen role the workspace's game version doesn't have rules the formation out
+    let (candidates, unfit) = roles.get_for_formations(formations).await?;
+
+    let options = query.options(&players, true)?;
+    let mut comparison = selection::compare_formations(&candidates, &players, &options, compare.ranking);
+    comparison.unfit.extend(unfit);
+    Ok(HttpResponse::Ok().json(comparison))
+}
+
+/// The formation's slots no player reaches the target suitability in, what holds the best
+/// candidate back, and a recruitment brief for each. Scored with the same options as
+/// `generate_squad`.
+async fn get_gap_report(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+    query: web::Query<SuitabilityQuery>,
+    gaps: web::Query<GapQuery>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    let target = gaps.target.unwrap_or(recruitment::DEFAULT_TARGET);
+    if !(0.0..=100.0).contains(&target) {
+        return Err(AppError::ValidationError(
+            "target must be a percentage between 0 and 100".to_string(),
+        ));
+    }
+
+    let formation = FormationRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_by_id(id)
+        .await?;
+    let roles = RoleRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_for_formation(&formation)
+        .await?;
+    let players = PlayerRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_all()
+        .await?;
+
+    let options = query.options(&players, true)?;
+    let report = recruitment::gap_report(&formation, &roles, &players, &options, target, gaps.max_age, gaps.max_wage)
+        .map_err(AppError::ValidationError)?;
+    Ok(HttpResponse::Ok().json(report))
+}
@@ -0,0 +1,55 @@
+use actix_multipart::form::{bytes::Bytes, MultipartForm};
+use actix_web::{web, HttpResponse};
+use serde::Deserialize;
+use sqlx::SqlitePool;
+use crate::error::AppResult;
+use crate::import::{self, ImportFormat};
+use crate::repository::MappingProfileRepository;
+
+/// Multipart body for player imports: a single file field named `file`
+#[derive(MultipartForm)]
+pub struct ImportUpload {
+    #[multipart(limit = "10MB")]
+    pub file: Bytes,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ImportQuery {
+    /// Validate and report without writing anything
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Upload format; detected from the file contents when omitted
+    pub format: Option<ImportFormat>,
+    /// Mapping profile to match column headers with
+    pub profile_id: Option<i64>,
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/players/import", web::post().to(import_players));
+}
+
+async fn import_players(
+    pool: web::Data<SqlitePool>,
+    workspace_id: web::Path<i64>,
+    query: web::Query<ImportQuery>,
+    MultipartForm(upload): MultipartForm<ImportUpload>,
+) -> AppResult<HttpResponse> {
+    let workspace_id = workspace_id.into_inner();
+    let data = &upload.file.data;
+    let format = query.format.unwrap_or_else(|| ImportFormat::detect(data));
+    let table = format.parse(data)?;
+    let profile = match query.profile_id {
+        Some(id) => Some(
+            MappingProfileRepository::new(pool.get_ref().clone(), workspace_id)
+                .get_by_id(id)
+                .await?,
+        ),
+        None => None,
+    };
+    let result = if query.dry_run {
+        import::preview(&table, profile.as_ref())
+    } else {
+        import::import_players(pool.get_ref(), workspace_id, &table, profile.as_ref()).await?
+    };
+    Ok(HttpResponse::Ok().json(result))
+}
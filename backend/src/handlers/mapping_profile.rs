@@ -0,0 +1,75 @@
+use actix_web::{web, HttpResponse};
+use sqlx::SqlitePool;
+use shared::models::MappingProfile;
+use crate::error::AppResult;
+use crate::handlers::created;
+use crate::import::validate_profile;
+use crate::repository::MappingProfileRepository;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/mapping-profiles")
+            .route("", web::get().to(list_profiles))
+            .route("", web::post().to(create_profile))
+            .route("/{id}", web::get().to(get_profile))
+            .route("/{id}", web::put().to(update_profile))
+            .route("/{id}", web::delete().to(delete_profile)),
+    );
+}
+
+async fn list_profiles(
+    pool: web::Data<SqlitePool>,
+    workspace_id: web::Path<i64>,
+) -> AppResult<HttpResponse> {
+    let profiles = MappingProfileRepository::new(pool.get_ref().clone(), workspace_id.into_inner())
+        .get_all()
+        .await?;
+    Ok(HttpResponse::Ok().json(profiles))
+}
+
+async fn create_profile(
+    pool: web::Data<SqlitePool>,
+    workspace_id: web::Path<i64>,
+    profile: web::Json<MappingProfile>,
+) -> AppResult<HttpResponse> {
+    validate_profile(&profile)?;
+    let id = MappingProfileRepository::new(pool.get_ref().clone(), workspace_id.into_inner())
+        .create(&profile)
+        .await?;
+    Ok(created(id))
+}
+
+async fn get_profile(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    let profile = MappingProfileRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_by_id(id)
+        .await?;
+    Ok(HttpResponse::Ok().json(profile))
+}
+
+async fn update_profile(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+    profile: web::Json<MappingProfile>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    validate_profile(&profile)?;
+    MappingProfileRepository::new(pool.get_ref().clone(), workspace_id)
+        .update(id, &profile)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+async fn delete_profile(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    MappingProfileRepository::new(pool.get_ref().clone(), workspace_id)
+        .delete(id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
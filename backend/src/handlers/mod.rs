@@ -0,0 +1,84 @@
+pub mod import;
+pub mod player;
+pub mod formation;
+pub mod role;
+pub mod squad;
+pub mod tactics;
+pub mod mapping_profile;
+pub mod calendar;
+pub mod workspace;
+
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::middleware::{self, Next};
+use actix_web::{web, HttpResponse};
+use sqlx::SqlitePool;
+use shared::models::Position;
+use crate::error::{AppError, AppResult};
+use crate::repository::WorkspaceRepository;
+
+/// Mount every versioned API route under `/api/v1`.
+/// Save data lives under `/workspaces/{workspace_id}`.
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/v1")
+            // Malformed bodies, path segments and query strings are reported through AppError
+            // so clients always get the same error shape
+            .app_data(web::JsonConfig::default().error_handler(|err, _req| {
+                AppError::ValidationError(err.to_string()).into()
+            }))
+            .app_data(web::PathConfig::default().error_handler(|err, _req| {
+                AppError::ValidationError(err.to_string()).into()
+            }))
+            .app_data(web::QueryConfig::default().error_handler(|err, _req| {
+                AppError::ValidationError(err.to_string()).into()
+            }))
+            .service(
+                web::scope("/workspaces")
+                    .configure(workspace::configure)
+                    .service(
+                        web::scope("/{workspace_id}")
+                            .wrap(middleware::from_fn(require_workspace))
+                            // Registered ahead of the players scope so `/players/import` is not shadowed
+                            .configure(import::configure)
+                            .configure(player::configure)
+                            .configure(formation::configure)
+                            .configure(role::configure)
+                            .configure(squad::configure)
+                            .configure(tactics::configure)
+                            .configure(mapping_profile::configure)
+                            .configure(calendar::configure),
+                    ),
+            ),
+    );
+}
+
+/// Reject requests for a workspace that doesn't exist before they reach a handler
+async fn require_workspace(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let workspace_id: i64 = req
+        .match_info()
+        .get("workspace_id")
+        .and_then(|id| id.parse().ok())
+        .ok_or_else(|| AppError::ValidationError("Invalid workspace id".to_string()))?;
+    let pool = req
+        .app_data::<web::Data<SqlitePool>>()
+        .ok_or_else(|| AppError::InternalError("Database pool not configured".to_string()))?
+        .get_ref()
+        .clone();
+
+    WorkspaceRepository::new(pool).get_by_id(workspace_id).await?;
+    next.call(req).await
+}
+
+/// Response for create endpoints: 201 with the new row id
+pub fn created(id: i64) -> HttpResponse {
+    HttpResponse::Created().json(serde_json::json!({ "id": id }))
+}
+
+/// Parse a position path segment, rejecting anything the Position enum doesn't know
+pub fn parse_position(position: &str) -> AppResult<Position> {
+    position.parse().map_err(AppError::ValidationError)
+}
@@ -0,0 +1,272 @@
+use actix_web::{web, HttpResponse};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use sqlx::SqlitePool;
+use shared::models::{AttributeSnapshot, Familiarity, Player, ProgressionReport, RecruitmentBrief};
+use shared::scoring::{self, FamiliarityMultipliers, MissingAttributePolicy, ScoringOptions};
+use crate::error::{AppError, AppResult};
+use crate::handlers::{created, parse_position};
+use crate::repository::{PlayerRepository, RoleRepository};
+
+#[derive(Debug, Deserialize)]
+pub struct DeltaQuery {
+    /// Earlier snapshot id; defaults to the player's first snapshot
+    pub from: Option<i64>,
+    /// Later snapshot id; defaults to the player's latest snapshot
+    pub to: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ProgressionQuery {
+    /// Only consider snapshots recorded on or after this date
+    pub since: Option<NaiveDate>,
+    /// Players listed in each direction
+    #[serde(default = "default_progression_limit")]
+    pub limit: usize,
+}
+
+fn default_progression_limit() -> usize {
+    10
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SuitabilityQuery {
+    /// How attributes the player has no value for are scored
+    pub missing: Option<MissingAttributePolicy>,
+    /// Leave out roles scored with less confidence than this (0-1)
+    #[serde(default)]
+    pub min_confidence: f32,
+    /// Weight of a key attribute for roles weighted by tiers
+    pub key_multiplier: Option<f32>,
+    /// Weight of a preferred attribute for roles weighted by tiers
+    pub preferred_multiplier: Option<f32>,
+    /// Score players down in roles outside their natural position
+    pub familiarity_penalty: Option<bool>,
+}
+
+impl SuitabilityQuery {
+    /// The scoring options asked for. `population` is only used to impute missing attributes.
+    /// Unless set explicitly, picking players for a slot (`for_selection`) applies the familiarity
+    /// penalty and scores missing attributes as the minimum, so a barely scouted player can't
+    /// outrank a known one on the few attributes they have.
+    pub fn options(&self, population: &[Player], for_selection: bool) -> AppResult<ScoringOptions> {
+        if !(0.0..=1.0).contains(&self.min_confidence) {
+            return Err(AppError::ValidationError("min_confidence must be between 0 and 1".to_string()));
+        }
+        for (name, multiplier) in [("key_multiplier", self.key_multiplier), ("preferred_multiplier", self.preferred_multiplier)] {
+            if multiplier.is_some_and(|multiplier| !multiplier.is_finite() || multiplier <= 0.0) {
+                return Err(AppError::ValidationError(format!("{} must be a positive number", name)));
+            }
+        }
+
+        let missing = self.missing.unwrap_or(if for_selection {
+            MissingAttributePolicy::Minimum
+        } else {
+            MissingAttributePolicy::Ignore
+        });
+        let mut options = ScoringOptions::new(missing, self.min_confidence, population);
+        if let Some(key) = self.key_multiplier {
+            options.tier_multipliers.key = key;
+        }
+        if let Some(preferred) = self.preferred_multiplier {
+            options.tier_multipliers.preferred = preferred;
+        }
+        if self.familiarity_penalty.unwrap_or(for_selection) {
+            options.familiarity = Some(FamiliarityMultipliers::default());
+        }
+        Ok(options)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PositionQuery {
+    /// Match every player who knows the position at least this well, not just those whose
+    /// main position it is
+    pub min_familiarity: Option<Familiarity>,
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/players")
+            .route("", web::get().to(list_players))
+            .route("", web::post().to(create_player))
+            .route("/position/{position}", web::get().to(search_by_position))
+            .route("/search", web::post().to(search_by_brief))
+            .route("/progression", web::get().to(progression_report))
+            .route("/{id}/history", web::get().to(get_history))
+            .route("/{id}/history/delta", web::get().to(get_history_delta))
+            .route("/{id}/suitability", web::get().to(get_suitability))
+            .route("/{id}", web::get().to(get_player))
+            .route("/{id}", web::put().to(update_player))
+            .route("/{id}", web::delete().to(delete_player)),
+    );
+}
+
+async fn list_players(
+    pool: web::Data<SqlitePool>,
+    workspace_id: web::Path<i64>,
+) -> AppResult<HttpResponse> {
+    let players = PlayerRepository::new(pool.get_ref().clone(), workspace_id.into_inner())
+        .get_all()
+        .await?;
+    Ok(HttpResponse::Ok().json(players))
+}
+
+async fn create_player(
+    pool: web::Data<SqlitePool>,
+    workspace_id: web::Path<i64>,
+    player: web::Json<Player>,
+) -> AppResult<HttpResponse> {
+    let id = PlayerRepository::new(pool.get_ref().clone(), workspace_id.into_inner())
+        .create(&player)
+        .await?;
+    Ok(created(id))
+}
+
+async fn get_player(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    let player = PlayerRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_by_id(id)
+        .await?;
+    Ok(HttpResponse::Ok().json(player))
+}
+
+async fn update_player(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+    player: web::Json<Player>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    PlayerRepository::new(pool.get_ref().clone(), workspace_id)
+        .update(id, &player)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+async fn delete_player(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    PlayerRepository::new(pool.get_ref().clone(), workspace_id)
+        .delete(id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+async fn search_by_position(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, String)>,
+    query: web::Query<PositionQuery>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, position) = path.into_inner();
+    let position = parse_position(&position)?;
+    let players = PlayerRepository::new(pool.get_ref().clone(), workspace_id)
+        .search_by_position(position, query.min_familiarity)
+        .await?;
+    Ok(HttpResponse::Ok().json(players))
+}
+
+/// Players who fit a recruitment brief, such as one from a formation's gap report
+async fn search_by_brief(
+    pool: web::Data<SqlitePool>,
+    workspace_id: web::Path<i64>,
+    brief: web::Json<RecruitmentBrief>,
+) -> AppResult<HttpResponse> {
+    let players = PlayerRepository::new(pool.get_ref().clone(), workspace_id.into_inner())
+        .search_by_brief(&brief)
+        .await?;
+    Ok(HttpResponse::Ok().json(players))
+}
+
+/// The player's suitability for every role in the workspace, best suited first
+async fn get_suitability(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+    query: web::Query<SuitabilityQuery>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    let repo = PlayerRepository::new(pool.get_ref().clone(), workspace_id);
+    let player = repo.get_by_id(id).await?;
+    let roles = RoleRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_all()
+        .await?;
+
+    // Only imputation needs the rest of the squad
+    let population = match query.missing {
+        Some(MissingAttributePolicy::Impute) => repo.get_all().await?,
+        _ => Vec::new(),
+    };
+    let options = query.options(&population, false)?;
+    Ok(HttpResponse::Ok().json(scoring::score_roles(&player, &roles, &options)))
+}
+
+async fn get_history(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    let repo = PlayerRepository::new(pool.get_ref().clone(), workspace_id);
+    repo.get_by_id(id).await?;
+    let history = repo.get_history(id).await?;
+    Ok(HttpResponse::Ok().json(history))
+}
+
+async fn get_history_delta(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+    query: web::Query<DeltaQuery>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    let repo = PlayerRepository::new(pool.get_ref().clone(), workspace_id);
+    repo.get_by_id(id).await?;
+    let history = repo.get_history(id).await?;
+
+    let from = match query.from {
+        Some(snapshot_id) => player_snapshot(&repo, id, snapshot_id).await?,
+        None => history.first().cloned().ok_or_else(|| no_history(id))?,
+    };
+    let to = match query.to {
+        Some(snapshot_id) => player_snapshot(&repo, id, snapshot_id).await?,
+        None => history.last().cloned().ok_or_else(|| no_history(id))?,
+    };
+
+    Ok(HttpResponse::Ok().json(from.deltas_to(&to)))
+}
+
+/// Fetch a snapshot, rejecting one that belongs to another player
+async fn player_snapshot(
+    repo: &PlayerRepository,
+    player_id: i64,
+    snapshot_id: i64,
+) -> AppResult<AttributeSnapshot> {
+    let snapshot = repo.get_snapshot(snapshot_id).await?;
+    if snapshot.player_id != player_id {
+        return Err(AppError::NotFound(format!(
+            "Snapshot with id {} not found for player {}",
+            snapshot_id, player_id
+        )));
+    }
+    Ok(snapshot)
+}
+
+fn no_history(player_id: i64) -> AppError {
+    AppError::NotFound(format!("Player {} has no attribute history", player_id))
+}
+
+async fn progression_report(
+    pool: web::Data<SqlitePool>,
+    workspace_id: web::Path<i64>,
+    query: web::Query<ProgressionQuery>,
+) -> AppResult<HttpResponse> {
+    let repo = PlayerRepository::new(pool.get_ref().clone(), workspace_id.into_inner());
+    let players = repo.get_all().await?;
+    let snapshots = repo.get_all_snapshots().await?;
+    let since = query.since.and_then(|date| date.and_hms_opt(0, 0, 0));
+
+    let report = ProgressionReport::build(&players, &snapshots, since, query.limit);
+    Ok(HttpResponse::Ok().json(report))
+}
@@ -0,0 +1,122 @@
+use actix_web::{web, HttpResponse};
+use serde::Deserialize;
+use sqlx::SqlitePool;
+use shared::models::{Duty, GameVersion, Position, Role, RoleType, TacticalRole};
+use crate::error::{AppError, AppResult};
+use crate::handlers::{created, parse_position};
+use crate::repository::RoleRepository;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/roles")
+            .route("", web::get().to(list_roles))
+            .route("", web::post().to(create_role))
+            .route("/position/{position}", web::get().to(get_by_position))
+            .route("/tactical", web::get().to(get_by_tactical_role))
+            .route("/{id}", web::get().to(get_role))
+            .route("/{id}", web::put().to(update_role))
+            .route("/{id}", web::delete().to(delete_role)),
+    );
+}
+
+/// Lists show the workspace's game version's roles unless `?game_version=` picks another
+#[derive(Deserialize)]
+struct GameVersionQuery {
+    #[serde(default)]
+    game_version: Option<GameVersion>,
+}
+
+async fn list_roles(
+    pool: web::Data<SqlitePool>,
+    workspace_id: web::Path<i64>,
+    query: web::Query<GameVersionQuery>,
+) -> AppResult<HttpResponse> {
+    let roles = RoleRepository::new(pool.get_ref().clone(), workspace_id.into_inner())
+        .with_game_version(query.game_version)
+        .get_all()
+        .await?;
+    Ok(HttpResponse::Ok().json(roles))
+}
+
+async fn create_role(
+    pool: web::Data<SqlitePool>,
+    workspace_id: web::Path<i64>,
+    role: web::Json<Role>,
+) -> AppResult<HttpResponse> {
+    let id = RoleRepository::new(pool.get_ref().clone(), workspace_id.into_inner())
+        .create(&role)
+        .await?;
+    Ok(created(id))
+}
+
+async fn get_role(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    let role = RoleRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_by_id(id)
+        .await?;
+    Ok(HttpResponse::Ok().json(role))
+}
+
+async fn update_role(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+    role: web::Json<Role>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    RoleRepository::new(pool.get_ref().clone(), workspace_id)
+        .update(id, &role)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+async fn delete_role(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    RoleRepository::new(pool.get_ref().clone(), workspace_id)
+        .delete(id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+async fn get_by_position(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, String)>,
+    query: web::Query<GameVersionQuery>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, position) = path.into_inner();
+    let position = parse_position(&position)?;
+    let roles = RoleRepository::new(pool.get_ref().clone(), workspace_id)
+        .with_game_version(query.game_version)
+        .get_by_position(position.as_str())
+        .await?;
+    Ok(HttpResponse::Ok().json(roles))
+}
+
+#[derive(Deserialize)]
+struct TacticalRoleQuery {
+    position: Position,
+    role: RoleType,
+    duty: Duty,
+    #[serde(default)]
+    game_version: Option<GameVersion>,
+}
+
+async fn get_by_tactical_role(
+    pool: web::Data<SqlitePool>,
+    workspace_id: web::Path<i64>,
+    query: web::Query<TacticalRoleQuery>,
+) -> AppResult<HttpResponse> {
+    let repo = RoleRepository::new(pool.get_ref().clone(), workspace_id.into_inner())
+        .with_game_version(query.game_version);
+    let tactical_role = TacticalRole::new(query.position, query.role, query.duty, repo.game_version().await?)
+        .map_err(AppError::ValidationError)?;
+    let role = repo
+        .get_by_tactical_role(&tactical_role)
+        .await?;
+    Ok(HttpResponse::Ok().json(role))
+}
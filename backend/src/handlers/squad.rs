@@ -0,0 +1,260 @@
+use actix_web::{web, HttpResponse};
+use serde::Deserialize;
+use sqlx::SqlitePool;
+use shared::models::{LineupConstraints, Squad, SquadWithPlayers};
+use shared::{bench, selection};
+use crate::error::{AppError, AppResult};
+use crate::handlers::created;
+use crate::handlers::player::SuitabilityQuery;
+use crate::repository::{FormationRepository, PlayerRepository, RoleRepository, SquadRepository};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/squads")
+            .route("", web::get().to(list_squads))
+            .route("", web::post().to(create_squad))
+            .route("/formation/{formation_id}", web::get().to(get_by_formation))
+            .route("/generate/{formation_id}", web::get().to(generate_squad))
+            .route("/generate/{formation_id}", web::post().to(generate_constrained_squad))
+            .route("/depth-chart/{formation_id}", web::get().to(get_depth_chart))
+            .route("/{id}", web::get().to(get_squad))
+            .route("/{id}/details", web::get().to(get_squad_details))
+            .route("/{id}/bench", web::get().to(get_bench))
+            .route("/{id}", web::put().to(update_squad))
+            .route("/{id}", web::delete().to(delete_squad)),
+    );
+}
+
+/// How deep a depth chart goes and when a slot's cover counts as thin
+#[derive(Debug, Deserialize)]
+pub struct DepthChartQuery {
+    pub depth: Option<usize>,
+    pub threshold: Option<f32>,
+}
+
+impl DepthChartQuery {
+    fn depth(&self) -> AppResult<usize> {
+        match self.depth {
+            Some(0) => Err(AppError::ValidationError("depth must be at least 1".to_string())),
+            depth => Ok(depth.unwrap_or(selection::DEFAULT_DEPTH)),
+        }
+    }
+
+    fn threshold(&self) -> AppResult<f32> {
+        cover_threshold(self.threshold)
+    }
+}
+
+/// How many substitutes to pick and when a slot's cover counts as adequate
+#[derive(Debug, Deserialize)]
+pub struct BenchQuery {
+    pub size: Option<usize>,
+    pub threshold: Option<f32>,
+}
+
+/// The suitability percentage cover has to reach, `DEFAULT_COVER_THRESHOLD` unless given
+fn cover_threshold(threshold: Option<f32>) -> AppResult<f32> {
+    match threshold {
+        Some(threshold) if !(0.0..=100.0).contains(&threshold) => Err(AppError::ValidationError(
+            "threshold must be a percentage between 0 and 100".to_string(),
+        )),
+        threshold => Ok(threshold.unwrap_or(selection::DEFAULT_COVER_THRESHOLD)),
+    }
+}
+
+async fn list_squads(
+    pool: web::Data<SqlitePool>,
+    workspace_id: web::Path<i64>,
+) -> AppResult<HttpResponse> {
+    let squads = SquadRepository::new(pool.get_ref().clone(), workspace_id.into_inner())
+        .get_all()
+        .await?;
+    Ok(HttpResponse::Ok().json(squads))
+}
+
+async fn create_squad(
+    pool: web::Data<SqlitePool>,
+    workspace_id: web::Path<i64>,
+    squad: web::Json<Squad>,
+) -> AppResult<HttpResponse> {
+    let id = SquadRepository::new(pool.get_ref().clone(), workspace_id.into_inner())
+        .create(&squad)
+        .await?;
+    Ok(created(id))
+}
+
+async fn get_squad(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    let squad = SquadRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_by_id(id)
+        .await?;
+    Ok(HttpResponse::Ok().json(squad))
+}
+
+async fn update_squad(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+    squad: web::Json<Squad>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    SquadRepository::new(pool.get_ref().clone(), workspace_id)
+        .update(id, &squad)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+async fn delete_squad(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    SquadRepository::new(pool.get_ref().clone(), workspace_id)
+        .delete(id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+async fn get_by_formation(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, formation_id) = path.into_inner();
+    let squads = SquadRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_by_formation(formation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(squads))
+}
+
+/// The starting XI with the highest total suitability for a formation, scored with the same
+/// options as player suitability. Players are scored down outside their natural position
+/// unless `familiarity_penalty=false`. The squad is not saved.
+async fn generate_squad(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+    query: web::Query<SuitabilityQuery>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, formation_id) = path.into_inner();
+    let squad = generate(pool.get_ref(), workspace_id, formation_id, &query, &LineupConstraints::default()).await?;
+    Ok(HttpResponse::Ok().json(squad))
+}
+
+/// `generate_squad` with locked, excluded and minutes-capped players given in the body.
+/// The constraints are kept on the squad, and any that conflict are listed in the error.
+async fn generate_constrained_squad(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+    query: web::Query<SuitabilityQuery>,
+    constraints: web::Json<LineupConstraints>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, formation_id) = path.into_inner();
+    let squad = generate(pool.get_ref(), workspace_id, formation_id, &query, &constraints).await?;
+    Ok(HttpResponse::Ok().json(squad))
+}
+
+async fn generate(
+    pool: &SqlitePool,
+    workspace_id: i64,
+    formation_id: i64,
+    query: &SuitabilityQuery,
+    constraints: &LineupConstraints,
+) -> AppResult<Squad> {
+    let formation = FormationRepository::new(pool.clone(), workspace_id)
+        .get_by_id(formation_id)
+        .await?;
+    let roles = RoleRepository::new(pool.clone(), workspace_id)
+        .get_for_formation(&formation)
+        .await?;
+    let players = PlayerRepository::new(pool.clone(), workspace_id)
+        .get_all()
+        .await?;
+
+    let options = query.options(&players, true)?;
+    selection::select_starting_xi(&formation, &roles, &players, &options, constraints)
+        .map_err(AppError::ValidationError)
+}
+
+/// The best suited players for every slot of a formation, with slots whose second choice
+/// falls below `threshold` flagged. Takes the same scoring options as `generate_squad`.
+async fn get_depth_chart(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+    query: web::Query<SuitabilityQuery>,
+    depth: web::Query<DepthChartQuery>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, formation_id) = path.into_inner();
+    let formation = FormationRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_by_id(formation_id)
+        .await?;
+    let roles = RoleRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_for_formation(&formation)
+        .await?;
+    let players = PlayerRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_all()
+        .await?;
+
+    let options = query.options(&players, true)?;
+    let chart = selection::depth_chart(&formation, &roles, &players, &options, depth.depth()?, depth.threshold()?);
+    Ok(HttpResponse::Ok().json(chart))
+}
+
+/// A squad with its players' data and the depth chart of its formation
+async fn get_squad_details(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+    query: web::Query<SuitabilityQuery>,
+    depth: web::Query<DepthChartQuery>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    let squad = SquadRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_by_id(id)
+        .await?;
+    let formation = FormationRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_by_id(squad.formation_id)
+        .await?;
+    // Rank the roles the starters play; an XI that doesn't fill the formation gets its defaults
+    let picked = squad.formation_as_picked(&formation).unwrap_or_else(|_| formation.clone());
+    let roles = RoleRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_for_formation(&picked)
+        .await?;
+    let players = PlayerRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_all()
+        .await?;
+
+    let options = query.options(&players, true)?;
+    let chart = selection::depth_chart(&picked, &roles, &players, &options, depth.depth()?, depth.threshold()?);
+    Ok(HttpResponse::Ok().json(SquadWithPlayers::new(&squad, &formation, &players, chart)))
+}
+
+/// Substitutes for a squad's starting XI that cover its slots as well as possible, with a
+/// backup goalkeeper, and which starters are left without adequate cover. Nothing is saved.
+async fn get_bench(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+    query: web::Query<SuitabilityQuery>,
+    bench_query: web::Query<BenchQuery>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    let squad = SquadRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_by_id(id)
+        .await?;
+    let formation = FormationRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_by_id(squad.formation_id)
+        .await?;
+    let formation = squad.formation_as_picked(&formation).map_err(AppError::ValidationError)?;
+    let roles = RoleRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_for_formation(&formation)
+        .await?;
+    let players = PlayerRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_all()
+        .await?;
+
+    let options = query.options(&players, true)?;
+    let size = bench_query.size.unwrap_or(bench::DEFAULT_BENCH_SIZE);
+    let threshold = cover_threshold(bench_query.threshold)?;
+    let bench = bench::select_bench(&squad, &formation, &roles, &players, &options, size, threshold)
+        .map_err(AppError::ValidationError)?;
+    Ok(HttpResponse::Ok().json(bench))
+}
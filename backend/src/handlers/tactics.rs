@@ -0,0 +1,84 @@
+use actix_web::{web, HttpResponse};
+use sqlx::SqlitePool;
+use shared::models::Tactics;
+use crate::error::AppResult;
+use crate::handlers::created;
+use crate::repository::TacticsRepository;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/tactics")
+            .route("", web::get().to(list_tactics))
+            .route("", web::post().to(create_tactics))
+            .route("/squad/{squad_id}", web::get().to(get_by_squad))
+            .route("/{id}", web::get().to(get_tactics))
+            .route("/{id}", web::put().to(update_tactics))
+            .route("/{id}", web::delete().to(delete_tactics)),
+    );
+}
+
+async fn list_tactics(
+    pool: web::Data<SqlitePool>,
+    workspace_id: web::Path<i64>,
+) -> AppResult<HttpResponse> {
+    let tactics = TacticsRepository::new(pool.get_ref().clone(), workspace_id.into_inner())
+        .get_all()
+        .await?;
+    Ok(HttpResponse::Ok().json(tactics))
+}
+
+async fn create_tactics(
+    pool: web::Data<SqlitePool>,
+    workspace_id: web::Path<i64>,
+    tactics: web::Json<Tactics>,
+) -> AppResult<HttpResponse> {
+    let id = TacticsRepository::new(pool.get_ref().clone(), workspace_id.into_inner())
+        .create(&tactics)
+        .await?;
+    Ok(created(id))
+}
+
+async fn get_tactics(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    let tactics = TacticsRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_by_id(id)
+        .await?;
+    Ok(HttpResponse::Ok().json(tactics))
+}
+
+async fn update_tactics(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+    tactics: web::Json<Tactics>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    TacticsRepository::new(pool.get_ref().clone(), workspace_id)
+        .update(id, &tactics)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+async fn delete_tactics(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, id) = path.into_inner();
+    TacticsRepository::new(pool.get_ref().clone(), workspace_id)
+        .delete(id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+async fn get_by_squad(
+    pool: web::Data<SqlitePool>,
+    path: web::Path<(i64, i64)>,
+) -> AppResult<HttpResponse> {
+    let (workspace_id, squad_id) = path.into_inner();
+    let tactics = TacticsRepository::new(pool.get_ref().clone(), workspace_id)
+        .get_by_squad(squad_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(tactics))
+}
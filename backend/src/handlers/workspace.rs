@@ -0,0 +1,92 @@
+use actix_web::{web, HttpResponse};
+use serde::Deserialize;
+use sqlx::SqlitePool;
+use shared::models::{GameVersion, Workspace};
+use crate::error::{AppError, AppResult};
+use crate::handlers::created;
+use crate::repository::WorkspaceRepository;
+
+/// Body for cloning a workspace
+#[derive(Debug, Deserialize)]
+pub struct WorkspaceName {
+    pub name: String,
+}
+
+/// Body for updating a workspace; the game version is left alone when omitted
+#[derive(Debug, Deserialize)]
+pub struct WorkspaceUpdate {
+    pub name: String,
+    #[serde(default)]
+    pub game_version: Option<GameVersion>,
+}
+
+/// Workspace routes; mounted on the `/workspaces` scope alongside the per-workspace resources
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("", web::get().to(list_workspaces))
+        .route("", web::post().to(create_workspace))
+        .route("/{workspace_id}", web::get().to(get_workspace))
+        .route("/{workspace_id}", web::put().to(update_workspace))
+        .route("/{workspace_id}", web::delete().to(delete_workspace))
+        .route("/{workspace_id}/clone", web::post().to(clone_workspace));
+}
+
+fn validate_name(name: &str) -> AppResult<()> {
+    if name.trim().is_empty() {
+        return Err(AppError::ValidationError("Workspace name must not be empty".to_string()));
+    }
+    Ok(())
+}
+
+async fn list_workspaces(pool: web::Data<SqlitePool>) -> AppResult<HttpResponse> {
+    let workspaces = WorkspaceRepository::new(pool.get_ref().clone()).get_all().await?;
+    Ok(HttpResponse::Ok().json(workspaces))
+}
+
+async fn create_workspace(
+    pool: web::Data<SqlitePool>,
+    workspace: web::Json<Workspace>,
+) -> AppResult<HttpResponse> {
+    validate_name(&workspace.name)?;
+    let id = WorkspaceRepository::new(pool.get_ref().clone())
+        .create(&workspace)
+        .await?;
+    Ok(created(id))
+}
+
+async fn get_workspace(pool: web::Data<SqlitePool>, id: web::Path<i64>) -> AppResult<HttpResponse> {
+    let workspace = WorkspaceRepository::new(pool.get_ref().clone())
+        .get_by_id(id.into_inner())
+        .await?;
+    Ok(HttpResponse::Ok().json(workspace))
+}
+
+async fn update_workspace(
+    pool: web::Data<SqlitePool>,
+    id: web::Path<i64>,
+    body: web::Json<WorkspaceUpdate>,
+) -> AppResult<HttpResponse> {
+    validate_name(&body.name)?;
+    WorkspaceRepository::new(pool.get_ref().clone())
+        .update(id.into_inner(), &body.name, body.game_version)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+async fn delete_workspace(pool: web::Data<SqlitePool>, id: web::Path<i64>) -> AppResult<HttpResponse> {
+    WorkspaceRepository::new(pool.get_ref().clone())
+        .delete(id.into_inner())
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+async fn clone_workspace(
+    pool: web::Data<SqlitePool>,
+    id: web::Path<i64>,
+    body: web::Json<WorkspaceName>,
+) -> AppResult<HttpResponse> {
+    validate_name(&body.name)?;
+    let id = WorkspaceRepository::new(pool.get_ref().clone())
+        .clone_workspace(id.into_inner(), &body.name)
+        .await?;
+    Ok(created(id))
+}
@@ -0,0 +1,56 @@
+use std::io::Cursor;
+use polars::prelude::*;
+use crate::error::{AppError, AppResult};
+use super::RawTable;
+
+/// Read a CSV file into a raw table.
+/// Schema inference is disabled so every column comes back as text and
+/// values like "1,500,000" or "30/06/2027" reach the mapper untouched.
+pub fn parse(bytes: &[u8]) -> AppResult<RawTable> {
+    let df = CsvReadOptions::default()
+        .with_has_header(true)
+        .with_infer_schema_length(Some(0))
+        .into_reader_with_file_handle(Cursor::new(bytes))
+        .finish()
+        .map_err(|e| AppError::ValidationError(format!("Failed to parse CSV: {}", e)))?;
+
+    let headers = df
+        .get_column_names()
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(|column| column.str())
+        .collect::<PolarsResult<Vec<_>>>()
+        .map_err(|e| AppError::InternalError(format!("Failed to read CSV columns: {}", e)))?;
+
+    let rows = (0..df.height())
+        .map(|i| {
+            columns
+                .iter()
+                .map(|column| column.get(i).unwrap_or_default().trim().to_string())
+                .collect()
+        })
+        .collect();
+
+    Ok(RawTable { headers, rows })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv() {
+        let csv = "Name,Age,Position,Value\nJohn Smith,24,STC,\"1,500,000\"\nJan Novak,30,DC,\n";
+        let table = parse(csv.as_bytes()).unwrap();
+
+        assert_eq!(table.headers, vec!["Name", "Age", "Position", "Value"]);
+        assert_eq!(table.rows.len(), 2);
+        assert_eq!(table.rows[0], vec!["John Smith", "24", "STC", "1,500,000"]);
+        assert_eq!(table.rows[1][3], "");
+    }
+}
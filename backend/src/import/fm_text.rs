@@ -0,0 +1,106 @@
+use crate::error::{AppError, AppResult};
+use super::RawTable;
+
+/// Parse FM's "Print Screen -> Text file" export.
+///
+/// The export is a pipe-delimited table: a header row of FM column labels,
+/// a dashed divider, then one row per player, e.g.
+///
+/// ```text
+/// | Name          | Position | Age | Acc   | Cmp |
+/// | ------------- | -------- | --- | ----- | --- |
+/// | John Smith    | ST (C)   | 24  | 11-15 | 13  |
+/// ```
+///
+/// Lines that are not table rows (titles, blank lines) and divider lines are skipped.
+pub fn parse(bytes: &[u8]) -> AppResult<RawTable> {
+    let text = String::from_utf8_lossy(bytes);
+
+    let mut headers: Option<Vec<String>> = None;
+    let mut rows = Vec::new();
+    for line in text.lines().map(str::trim) {
+        if !line.starts_with('|') || is_divider(line) {
+            continue;
+        }
+
+        let cells = split_cells(line);
+        match &headers {
+            None => headers = Some(cells),
+            // FM repeats the header row on long exports
+            Some(existing) if *existing == cells => {}
+            Some(_) => rows.push(cells),
+        }
+    }
+
+    let headers = headers.ok_or_else(|| {
+        AppError::ValidationError("No pipe-delimited table found in FM text export".to_string())
+    })?;
+
+    Ok(RawTable { headers, rows })
+}
+
+fn is_divider(line: &str) -> bool {
+    line.contains('-') && line.chars().all(|c| matches!(c, '|' | '-' | '+' | ' '))
+}
+
+fn split_cells(line: &str) -> Vec<String> {
+    let inner = line.strip_prefix('|').unwrap_or(line);
+    let inner = inner.strip_suffix('|').unwrap_or(inner);
+    inner.split('|').map(|cell| cell.trim().to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import::{validate_rows, ImportFormat};
+    use shared::models::Position;
+
+    const EXPORT: &str = "\
+Squad View
+
+| Name          | Position        | Age | Nat | Acc   | Cmp | Wor | 1v1 |
+| ------------- | --------------- | --- | --- | ----- | --- | --- | --- |
+| John Smith    | AM (R), ST (C)  | 24  | ENG | 11-15 | 13  | 16  | -   |
+| Jan Novak     | GK              | 30  | CZE | 8     | 14  | 10  | 15  |
+";
+
+    #[test]
+    fn test_parse_fm_text() {
+        let table = parse(EXPORT.as_bytes()).unwrap();
+
+        assert_eq!(table.headers[0], "Name");
+        assert_eq!(table.headers.len(), 8);
+        assert_eq!(table.rows.len(), 2);
+        assert_eq!(table.rows[0][1], "AM (R), ST (C)");
+        assert_eq!(table.rows[0][4], "11-15");
+    }
+
+    #[test]
+    fn test_detect_after_title() {
+        assert_eq!(ImportFormat::detect(EXPORT.as_bytes()), ImportFormat::FmText);
+    }
+
+    #[test]
+    fn test_fm_text_feeds_import_pipeline() {
+        let table = parse(EXPORT.as_bytes()).unwrap();
+        let (players, issues) = validate_rows(&table, None);
+
+        assert!(issues.is_empty(), "{:?}", issues);
+        let (_, smith) = &players[0];
+        assert_eq!(smith.position, Position::AMR);
+        assert_eq!(smith.nationality.as_deref(), Some("ENG"));
+        assert_eq!(smith.acceleration, Some(13));
+        assert_eq!(smith.composure, Some(13));
+        assert_eq!(smith.work_rate, Some(16));
+        assert_eq!(smith.one_on_ones, None);
+
+        let (_, novak) = &players[1];
+        assert_eq!(novak.position, Position::GK);
+        assert_eq!(novak.one_on_ones, Some(15));
+    }
+
+    #[test]
+    fn test_parse_without_table() {
+        assert!(parse(b"just some text").is_err());
+    }
+}
@@ -0,0 +1,115 @@
+use scraper::{ElementRef, Html, Selector};
+use crate::error::{AppError, AppResult};
+use super::RawTable;
+
+/// Parse an FM HTML view export.
+///
+/// FM writes the view as a `<table>` whose first row holds the column labels in
+/// `<th>` cells. The first table with a header row is used; if no table has `<th>`
+/// cells, the first row of the first table is taken as the header.
+/// Cell text is whitespace-normalised, including the non-breaking spaces FM pads cells with.
+pub fn parse(bytes: &[u8]) -> AppResult<RawTable> {
+    let document = Html::parse_document(&String::from_utf8_lossy(bytes));
+    let table_selector = Selector::parse("table").expect("valid selector");
+    let row_selector = Selector::parse("tr").expect("valid selector");
+    let header_selector = Selector::parse("th").expect("valid selector");
+
+    let tables: Vec<ElementRef> = document.select(&table_selector).collect();
+    let table = tables
+        .iter()
+        .find(|table| table.select(&header_selector).next().is_some())
+        .or_else(|| tables.first())
+        .ok_or_else(|| AppError::ValidationError("No table found in HTML export".to_string()))?;
+
+    let mut headers: Option<Vec<String>> = None;
+    let mut rows = Vec::new();
+    for row in table.select(&row_selector) {
+        let cells = row_cells(row);
+        if cells.iter().all(|cell| cell.is_empty()) {
+            continue;
+        }
+
+        let is_header_row = row.select(&header_selector).next().is_some();
+        match &headers {
+            None => headers = Some(cells),
+            // Long exports repeat the header row
+            Some(_) if is_header_row => {}
+            Some(_) => rows.push(cells),
+        }
+    }
+
+    let headers = headers
+        .ok_or_else(|| AppError::ValidationError("HTML table has no rows".to_string()))?;
+
+    Ok(RawTable { headers, rows })
+}
+
+/// Text of every `th`/`td` cell in a row
+fn row_cells(row: ElementRef) -> Vec<String> {
+    row.children()
+        .filter_map(ElementRef::wrap)
+        .filter(|cell| matches!(cell.value().name(), "th" | "td"))
+        .map(|cell| cell_text(&cell))
+        .collect()
+}
+
+fn cell_text(cell: &ElementRef) -> String {
+    let text: String = cell.text().collect();
+    text.replace('\u{a0}', " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import::validate_rows;
+    use shared::models::Position;
+
+    const EXPORT: &str = r#"<!DOCTYPE html>
+<html>
+<head><meta charset="utf-8"><title>Squad</title></head>
+<body>
+<table>
+<tr><th>Inf</th><th>Name</th><th>Position</th><th>Age</th><th>Transfer Value</th><th>Wage</th><th>Acc</th><th>Fin</th></tr>
+<tr><td></td><td>John&nbsp;Smith</td><td>ST (C)</td><td>24</td><td>£12.5M</td><td>£45K&nbsp;p/w</td><td>15</td><td>16</td></tr>
+<tr><td>Inj</td><td>Jan Novak</td><td>D (C)</td><td>30</td><td>£1.5M - £3M</td><td>£12,000 p/w</td><td>9</td><td>-</td></tr>
+</table>
+</body>
+</html>"#;
+
+    #[test]
+    fn test_parse_html() {
+        let table = parse(EXPORT.as_bytes()).unwrap();
+
+        assert_eq!(table.headers[1], "Name");
+        assert_eq!(table.rows.len(), 2);
+        assert_eq!(table.rows[0][1], "John Smith");
+        assert_eq!(table.rows[0][5], "£45K p/w");
+    }
+
+    #[test]
+    fn test_html_feeds_import_pipeline() {
+        let table = parse(EXPORT.as_bytes()).unwrap();
+        let (players, issues) = validate_rows(&table, None);
+
+        assert!(issues.is_empty(), "{:?}", issues);
+        let (_, smith) = &players[0];
+        assert_eq!(smith.name, "John Smith");
+        assert_eq!(smith.position, Position::STC);
+        assert_eq!(smith.value, Some(12_500_000));
+        assert_eq!(smith.wage, Some(45_000));
+        assert_eq!(smith.finishing, Some(16));
+
+        let (_, novak) = &players[1];
+        assert_eq!(novak.value, Some(2_250_000));
+        assert_eq!(novak.wage, Some(12_000));
+        assert_eq!(novak.finishing, None);
+    }
+
+    #[test]
+    fn test_parse_without_table() {
+        assert!(parse(b"<html><body><p>Nothing here</p></body></html>").is_err());
+    }
+}
@@ -0,0 +1,796 @@
+pub mod csv;
+pub mod fm_text;
+pub mod html;
+pub mod validation;
+
+use chrono::{NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+use sqlx::{SqliteConnection, SqlitePool};
+use std::collections::HashMap;
+use shared::models::{Attribute, AttributeSnapshot, Familiarity, MappingProfile, Player, Position};
+use crate::error::{AppError, AppResult};
+use crate::repository::{CalendarRepository, PlayerRepository};
+use validation::{parse_attribute, parse_familiarity, parse_money, parse_number, parse_wage, validate_positions, RowIssue};
+
+/// Tabular data extracted from an uploaded file, before it is mapped onto players.
+/// Every cell is kept as text so each source format goes through the same mapping.
+#[derive(Debug, Clone, Default)]
+pub struct RawTable {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Supported upload formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportFormat {
+    Csv,
+    /// FM "Print Screen -> Text file" export: a pipe-delimited table
+    FmText,
+    /// FM HTML view export
+    Html,
+}
+
+impl ImportFormat {
+    /// Guess the format from the file contents: HTML exports start with a tag,
+    /// FM text exports have `|` table rows, possibly after a title line
+    pub fn detect(bytes: &[u8]) -> Self {
+        let text = String::from_utf8_lossy(bytes);
+        let mut lines = text
+            .lines()
+            .map(|line| line.trim().trim_start_matches('\u{feff}'))
+            .filter(|line| !line.is_empty())
+            .peekable();
+        if lines.peek().is_some_and(|line| line.starts_with('<')) {
+            ImportFormat::Html
+        } else if lines.any(|line| line.starts_with('|')) {
+            ImportFormat::FmText
+        } else {
+            ImportFormat::Csv
+        }
+    }
+
+    /// Parse an uploaded file into a raw table
+    pub fn parse(&self, bytes: &[u8]) -> AppResult<RawTable> {
+        match self {
+            ImportFormat::Csv => csv::parse(bytes),
+            ImportFormat::FmText => fm_text::parse(bytes),
+            ImportFormat::Html => html::parse(bytes),
+        }
+    }
+}
+
+/// `Player` field a source column maps onto
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerField {
+    /// FM's unique player ID
+    FmUid,
+    Name,
+    Age,
+    DateOfBirth,
+    Nationality,
+    Position,
+    /// One of the 1-20 attributes
+    Attribute(Attribute),
+    /// Familiarity with a position, e.g. `familiarity_dmc`
+    Familiarity(Position),
+    Value,
+    Wage,
+    ContractExpiry,
+}
+
+impl PlayerField {
+    /// Look up a field by its exact `Player` field name, e.g. "contract_expiry" or "jumping_reach"
+    pub fn from_name(name: &str) -> Option<Self> {
+        let field = match name {
+            "fm_uid" => PlayerField::FmUid,
+            "name" => PlayerField::Name,
+            "age" => PlayerField::Age,
+            "date_of_birth" => PlayerField::DateOfBirth,
+            "nationality" => PlayerField::Nationality,
+            "position" => PlayerField::Position,
+            "value" => PlayerField::Value,
+            "wage" => PlayerField::Wage,
+            "contract_expiry" => PlayerField::ContractExpiry,
+            other => match other.strip_prefix("familiarity_") {
+                Some(position) => PlayerField::Familiarity(position.to_uppercase().parse().ok()?),
+                None => PlayerField::Attribute(other.parse().ok()?),
+            },
+        };
+        Some(field)
+    }
+}
+
+/// Outcome of a player import or dry run
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportResult {
+    /// True when nothing was written to the database
+    pub dry_run: bool,
+    pub total_rows: usize,
+    /// Rows that passed validation
+    pub valid: usize,
+    /// New players added
+    pub created: usize,
+    /// Existing players whose data changed
+    pub updated: usize,
+    /// Existing players that already matched the file
+    pub unchanged: usize,
+    /// Rows that were not (or, in a dry run, would not be) imported
+    pub rejected: usize,
+    pub issues: Vec<RowIssue>,
+    /// Source columns that matched no `Player` field and were not imported
+    pub unmapped_columns: Vec<String>,
+}
+
+/// Lowercase a header and collapse spaces and punctuation into underscores
+fn normalize_header(header: &str) -> String {
+    let mut normalized = String::new();
+    for c in header.trim().chars() {
+        if c.is_alphanumeric() {
+            normalized.extend(c.to_lowercase());
+        } else if !normalized.is_empty() && !normalized.ends_with('_') {
+            normalized.push('_');
+        }
+    }
+    normalized.trim_end_matches('_').to_string()
+}
+
+/// Map a source column header onto a `Player` field.
+/// Accepts field names in any case or spacing as well as FM's abbreviations.
+pub fn map_header(header: &str) -> Option<PlayerField> {
+    let normalized = normalize_header(header);
+    let field = match normalized.as_str() {
+        "uid" | "unique_id" => PlayerField::FmUid,
+        "player" => PlayerField::Name,
+        "dob" | "born" | "birth_date" => PlayerField::DateOfBirth,
+        "nation" => PlayerField::Nationality,
+        "best_pos" => PlayerField::Position,
+        "transfer_value" => PlayerField::Value,
+        "expires" => PlayerField::ContractExpiry,
+        // Position rating columns, as exported by scouting tools
+        "dm" => PlayerField::Familiarity(Position::DMC),
+        "st" => PlayerField::Familiarity(Position::STC),
+        other => {
+            if let Ok(position) = other.to_uppercase().parse() {
+                return Some(PlayerField::Familiarity(position));
+            }
+            let abbreviated = Attribute::ALL
+                .iter()
+                .find(|attribute| normalize_header(attribute.abbreviation()) == other);
+            return match abbreviated {
+                Some(attribute) => Some(PlayerField::Attribute(*attribute)),
+                None => PlayerField::from_name(other),
+            };
+        }
+    };
+    Some(field)
+}
+
+/// Check that every column of a profile targets a real `Player` field
+pub fn validate_profile(profile: &MappingProfile) -> AppResult<()> {
+    let mut headers: Vec<&String> = profile.columns.keys().collect();
+    headers.sort();
+    let mut normalized: HashMap<String, &String> = HashMap::new();
+    for header in headers {
+        if let Some(other) = normalized.insert(normalize_header(header), header) {
+            return Err(AppError::ValidationError(format!(
+                "Columns '{}' and '{}' are the same header and can't be mapped separately",
+                other, header
+            )));
+        }
+    }
+
+    for (header, field) in &profile.columns {
+        if let Some(field) = field {
+            if PlayerField::from_name(field).is_none() {
+                return Err(AppError::ValidationError(format!(
+                    "Column '{}' maps to unknown player field '{}'",
+                    header, field
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The profile's entry for a header, if it has one. An exact match wins; otherwise the first
+/// matching source header in sorted order, so a profile saved before duplicates were
+/// rejected still resolves the same way every time.
+fn profile_entry<'a>(profile: Option<&'a MappingProfile>, header: &str) -> Option<&'a Option<String>> {
+    let normalized = normalize_header(header);
+    profile?
+        .columns
+        .iter()
+        .filter(|(source, _)| normalize_header(source) == normalized)
+        .min_by_key(|(source, _)| (source.as_str() != header, source.as_str()))
+        .map(|(_, field)| field)
+}
+
+/// Map every header of a table, preferring the profile's entry for a header
+/// over the built-in names and abbreviations.
+/// FM labels both nationality and natural fitness "Nat", so that column is
+/// resolved from its contents: text means nationality.
+fn map_headers(table: &RawTable, profile: Option<&MappingProfile>) -> Vec<Option<PlayerField>> {
+    table
+        .headers
+        .iter()
+        .enumerate()
+        .map(|(index, header)| {
+            if let Some(field) = profile_entry(profile, header) {
+                return field.as_deref().and_then(PlayerField::from_name);
+            }
+
+            let field = map_header(header);
+            if normalize_header(header) != "nat" {
+                return field;
+            }
+
+            let has_text = table
+                .rows
+                .iter()
+                .filter_map(|row| row.get(index))
+                .any(|cell| cell.chars().any(char::is_alphabetic));
+            if has_text {
+                Some(PlayerField::Nationality)
+            } else {
+                field
+            }
+        })
+        .collect()
+}
+
+/// Headers that neither the profile nor the built-in names account for.
+/// Columns the profile deliberately skips are not reported.
+pub fn unmapped_columns(table: &RawTable, profile: Option<&MappingProfile>) -> Vec<String> {
+    table
+        .headers
+        .iter()
+        .filter(|header| !header.trim().is_empty())
+        .filter(|header| profile_entry(profile, header).is_none() && map_header(header).is_none())
+        .cloned()
+        .collect()
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(value, "%d/%m/%Y"))
+        .map_err(|_| "is not a date (expected YYYY-MM-DD or DD/MM/YYYY)".to_string())
+}
+
+/// Build a player from one row, given the mapped field for each column.
+/// Every problem in the row is reported, not just the first one.
+fn build_player(
+    row_number: usize,
+    headers: &[String],
+    fields: &[Option<PlayerField>],
+    row: &[String],
+) -> Result<Player, Vec<RowIssue>> {
+    let mut issues = Vec::new();
+    let mut provided = Vec::new();
+    let mut name = None;
+    let mut age = None;
+    let mut positions = Vec::new();
+    let mut familiarity = HashMap::new();
+    let mut player = Player::new(String::new(), 0, Position::GK);
+
+    for ((header, field), value) in headers.iter().zip(fields).zip(row) {
+        let value = value.trim();
+        let Some(field) = field else { continue };
+        // FM shows "-" for attributes that are not known at all
+        if value.is_empty() || value == "-" {
+            continue;
+        }
+        provided.push(*field);
+
+        let result = match field {
+            PlayerField::FmUid => parse_number(value).map(|v| player.fm_uid = Some(v)),
+            PlayerField::Name => {
+                name = Some(value.to_string());
+                Ok(())
+            }
+            PlayerField::Age => parse_number(value).map(|v| age = Some(v)),
+            // FM appends the age: "12/3/2001 (23 years old)"
+            PlayerField::DateOfBirth => parse_date(value.split('(').next().unwrap_or(value).trim())
+                .map(|v| player.date_of_birth = Some(v)),
+            PlayerField::Nationality => {
+                player.nationality = Some(value.to_string());
+                Ok(())
+            }
+            PlayerField::Position => validate_positions(value).map(|v| positions = v),
+            PlayerField::Familiarity(position) => parse_familiarity(value).map(|v| {
+                familiarity.insert(*position, v);
+            }),
+            PlayerField::Attribute(attribute) => parse_attribute(value)
+                .map(|range| player.set_attribute_range(*attribute, range)),
+            PlayerField::Value => parse_money(value).map(|v| player.value = Some(v)),
+            PlayerField::Wage => parse_wage(value).map(|v| player.wage = Some(v)),
+            PlayerField::ContractExpiry => parse_date(value).map(|v| player.contract_expiry = Some(v)),
+        };
+
+        if let Err(reason) = result {
+            issues.push(RowIssue::new(row_number, header, value, reason));
+        }
+    }
+
+    let required = [
+        (PlayerField::Name, "name"),
+        (PlayerField::Age, "age"),
+        (PlayerField::Position, "position"),
+    ];
+    for (field, field_name) in required {
+        // The age can be derived from a date of birth instead
+        let derivable = field == PlayerField::Age && provided.contains(&PlayerField::DateOfBirth);
+        if !provided.contains(&field) && !derivable {
+            issues.push(RowIssue::new(row_number, field_name, "", "is required"));
+        }
+    }
+
+    match (name, positions.first()) {
+        (Some(name), Some(&position)) if issues.is_empty() => {
+            player.name = name;
+            // Without an age column the age is derived from the date of birth when stored
+            player.age = age.unwrap_or_default();
+            player.position = position;
+            // FM lists the positions a player is at least accomplished in, best first;
+            // position rating columns are more precise and win
+            for other in &positions[1..] {
+                player.position_familiarity.insert(*other, Familiarity::Accomplished);
+            }
+            player.position_familiarity.extend(familiarity);
+            Ok(player)
+        }
+        _ => Err(issues),
+    }
+}
+
+/// Map and validate every row of a table.
+/// Returns the valid players with their row numbers, and every issue found.
+pub fn validate_rows(
+    table: &RawTable,
+    profile: Option<&MappingProfile>,
+) -> (Vec<(usize, Player)>, Vec<RowIssue>) {
+    let fields = map_headers(table, profile);
+
+    let mut players = Vec::new();
+    let mut issues = Vec::new();
+    for (index, row) in table.rows.iter().enumerate() {
+        let row_number = index + 1;
+        match build_player(row_number, &table.headers, &fields, row) {
+            Ok(player) => players.push((row_number, player)),
+            Err(row_issues) => issues.extend(row_issues),
+        }
+    }
+
+    (players, issues)
+}
+
+/// Validate a table and report what an import would do, without touching the database
+pub fn preview(table: &RawTable, profile: Option<&MappingProfile>) -> ImportResult {
+    let (players, issues) = validate_rows(table, profile);
+    ImportResult {
+        dry_run: true,
+        total_rows: table.rows.len(),
+        valid: players.len(),
+        created: 0,
+        updated: 0,
+        unchanged: 0,
+        rejected: table.rows.len() - players.len(),
+        issues,
+        unmapped_columns: unmapped_columns(table, profile),
+    }
+}
+
+/// Outcome of writing a single imported player
+enum Upsert {
+    Created,
+    Updated,
+    Unchanged,
+}
+
+/// Keep what a stored player has and the file doesn't, so a partial export doesn't wipe it.
+/// Attributes the file has replace the stored value and range; familiarity the file records
+/// replaces the stored level for that position.
+fn merge_existing(player: &mut Player, existing: &Player) {
+    player.id = existing.id;
+    player.fm_uid = player.fm_uid.or(existing.fm_uid);
+    player.date_of_birth = player.date_of_birth.or(existing.date_of_birth);
+    player.nationality = player.nationality.take().or_else(|| existing.nationality.clone());
+    player.value = player.value.or(existing.value);
+    player.wage = player.wage.or(existing.wage);
+    player.contract_expiry = player.contract_expiry.or(existing.contract_expiry);
+
+    for attribute in Attribute::ALL {
+        if attribute.value(player).is_some() {
+            continue;
+        }
+        *attribute.value_mut(player) = attribute.value(existing);
+        if let Some(range) = existing.attribute_ranges.get(&attribute) {
+            player.attribute_ranges.insert(attribute, *range);
+        }
+    }
+
+    let mut familiarity = existing.position_familiarity.clone();
+    familiarity.extend(player.position_familiarity.drain());
+    player.position_familiarity = familiarity;
+}
+
+/// Update the stored player an imported player refers to, or create it if there is none.
+/// The stored unique ID is kept when the source doesn't provide one.
+/// Returns what happened along with the player's id.
+async fn upsert_player(
+    repo: &PlayerRepository,
+    conn: &mut SqliteConnection,
+    player: &Player,
+) -> AppResult<(Upsert, i64)> {
+    let Some(id) = repo.find_existing_in(conn, player).await? else {
+        let id = repo.create_in(conn, player).await?;
+        return Ok((Upsert::Created, id));
+    };
+
+    let existing = repo.get_by_id_in(conn, id).await?;
+    let mut player = player.clone();
+    merge_existing(&mut player, &existing);
+    if player == existing {
+        return Ok((Upsert::Unchanged, id));
+    }
+
+    repo.update_in(conn, id, &player).await?;
+    Ok((Upsert::Updated, id))
+}
+
+/// Write every valid row into a workspace in a single transaction, updating players that
+/// are already stored (see `PlayerRepository::find_existing_in`) and creating the rest.
+/// Ages are derived from dates of birth at the game date, and every imported player
+/// gets an attribute snapshot dated with the game date.
+/// Rows are checked with the same validators as `preview`, so both paths agree.
+pub async fn import_players(
+    pool: &SqlitePool,
+    workspace_id: i64,
+    table: &RawTable,
+    profile: Option<&MappingProfile>,
+) -> AppResult<ImportResult> {
+    let (players, mut issues) = validate_rows(table, profile);
+    let repo = PlayerRepository::new(pool.clone(), workspace_id);
+
+    let mut tx = pool.begin().await?;
+    let game_date = CalendarRepository::new(pool.clone(), workspace_id).game_date_in(&mut tx).await?;
+    let recorded_at = game_date.and_time(NaiveTime::MIN);
+    let (mut created, mut updated, mut unchanged) = (0, 0, 0);
+    for (row, player) in &players {
+        let mut player = player.clone();
+        player.refresh_derived(game_date);
+        match upsert_player(&repo, &mut tx, &player).await {
+            Ok((outcome, id)) => {
+                let snapshot = AttributeSnapshot::of(id, &player, recorded_at);
+                PlayerRepository::record_snapshot_in(&mut tx, &snapshot).await?;
+                match outcome {
+                    Upsert::Created => created += 1,
+                    Upsert::Updated => updated += 1,
+                    Upsert::Unchanged => unchanged += 1,
+                }
+            }
+            // Constraints the validators don't know about still reject just this row
+            Err(AppError::ValidationError(reason)) => issues.push(RowIssue::new(*row, "", "", reason)),
+            Err(err) => return Err(err),
+        }
+    }
+    tx.commit().await?;
+
+    issues.sort_by_key(|i| i.row);
+    Ok(ImportResult {
+        dry_run: false,
+        total_rows: table.rows.len(),
+        valid: players.len(),
+        created,
+        updated,
+        unchanged,
+        rejected: table.rows.len() - created - updated - unchanged,
+        issues,
+        unmapped_columns: unmapped_columns(table, profile),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::models::AttributeRange;
+
+    /// The default workspace seeded by the migrations
+    const WORKSPACE: i64 = 1;
+
+    async fn setup_test_db() -> SqlitePool {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+        pool
+    }
+
+    fn table(headers: &[&str], rows: &[&[&str]]) -> RawTable {
+        RawTable {
+            headers: headers.iter().map(|h| h.to_string()).collect(),
+            rows: rows
+                .iter()
+                .map(|r| r.iter().map(|c| c.to_string()).collect())
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_map_header() {
+        assert_eq!(map_header("Name"), Some(PlayerField::Name));
+        assert_eq!(map_header("Jumping Reach"), Some(PlayerField::Attribute(Attribute::JumpingReach)));
+        assert_eq!(map_header("free-kick taking"), Some(PlayerField::Attribute(Attribute::FreeKickTaking)));
+        assert_eq!(map_header("Contract Expiry"), Some(PlayerField::ContractExpiry));
+        assert_eq!(map_header("Shoe Size"), None);
+    }
+
+    #[test]
+    fn test_map_position_rating_columns() {
+        assert_eq!(map_header("DC"), Some(PlayerField::Familiarity(Position::DC)));
+        assert_eq!(map_header("DM"), Some(PlayerField::Familiarity(Position::DMC)));
+        assert_eq!(map_header("ST"), Some(PlayerField::Familiarity(Position::STC)));
+        assert_eq!(map_header("Familiarity WBL"), Some(PlayerField::Familiarity(Position::WBL)));
+        assert_eq!(PlayerField::from_name("familiarity_xx"), None);
+    }
+
+    #[test]
+    fn test_map_fm_abbreviations() {
+        assert_eq!(map_header("Acc"), Some(PlayerField::Attribute(Attribute::Acceleration)));
+        assert_eq!(map_header("Cmp"), Some(PlayerField::Attribute(Attribute::Composure)));
+        assert_eq!(map_header("Wor"), Some(PlayerField::Attribute(Attribute::WorkRate)));
+        assert_eq!(map_header("1v1"), Some(PlayerField::Attribute(Attribute::OneOnOnes)));
+        assert_eq!(map_header("L Th"), Some(PlayerField::Attribute(Attribute::LongThrows)));
+        assert_eq!(map_header("Best Pos"), Some(PlayerField::Position));
+        assert_eq!(map_header("UID"), Some(PlayerField::FmUid));
+    }
+
+    #[test]
+    fn test_nat_column_resolved_from_contents() {
+        let nationality = table(&["Name", "Nat"], &[&["A", "ENG"]]);
+        assert_eq!(map_headers(&nationality, None)[1], Some(PlayerField::Nationality));
+
+        let fitness = table(&["Name", "Nat"], &[&["A", "14"]]);
+        assert_eq!(map_headers(&fitness, None)[1], Some(PlayerField::Attribute(Attribute::NaturalFitness)));
+    }
+
+    #[test]
+    fn test_profile_overrides_builtin_mapping() {
+        let mut columns = std::collections::HashMap::new();
+        columns.insert("Jumping".to_string(), Some("jumping_reach".to_string()));
+        columns.insert("Pos".to_string(), Some("position".to_string()));
+        columns.insert("Club".to_string(), None);
+        let profile = MappingProfile::new("Scout".to_string(), columns);
+
+        let table = table(
+            &["Name", "Age", "POS", "jumping", "Club", "Shoe Size"],
+            &[&["Target Man", "27", "STC", "18", "Town", "46"]],
+        );
+
+        let fields = map_headers(&table, Some(&profile));
+        assert_eq!(fields[2], Some(PlayerField::Position));
+        assert_eq!(fields[3], Some(PlayerField::Attribute(Attribute::JumpingReach)));
+        assert_eq!(fields[4], None);
+
+        let report = preview(&table, Some(&profile));
+        assert_eq!(report.valid, 1);
+        assert_eq!(report.unmapped_columns, vec!["Shoe Size".to_string()]);
+
+        // Without the profile "POS" is read as FM's Positioning abbreviation,
+        // and the other columns are reported rather than dropped silently
+        let report = preview(&table, None);
+        assert_eq!(report.valid, 0);
+        assert_eq!(report.unmapped_columns, vec!["jumping", "Club", "Shoe Size"]);
+    }
+
+    #[test]
+    fn test_validate_profile() {
+        let mut columns = std::collections::HashMap::new();
+        columns.insert("Spd".to_string(), Some("pace".to_string()));
+        let mut profile = MappingProfile::new("Scout".to_string(), columns);
+        assert!(validate_profile(&profile).is_ok());
+
+        profile.columns.insert("Shoe".to_string(), Some("shoe_size".to_string()));
+        assert!(validate_profile(&profile).is_err());
+        profile.columns.remove("Shoe");
+
+        // "SPD" and "Spd" would both claim the same column
+        profile.columns.insert("SPD".to_string(), Some("acceleration".to_string()));
+        assert!(matches!(validate_profile(&profile), Err(AppError::ValidationError(_))));
+        assert_eq!(profile_entry(Some(&profile), "Spd"), Some(&Some("pace".to_string())));
+        assert_eq!(profile_entry(Some(&profile), "spd"), Some(&Some("acceleration".to_string())));
+    }
+
+    #[test]
+    fn test_import_position_familiarity() {
+        let table = table(
+            &["Name", "Age", "Position", "DM", "ST"],
+            &[
+                &["Utility", "27", "D (C), DM", "", "Unconvincing"],
+                &["Rated", "22", "D (RC)", "20", "3"],
+                &["Bad Rating", "22", "GK", "Decent", ""],
+            ],
+        );
+
+        let (players, issues) = validate_rows(&table, None);
+        let utility = &players[0].1;
+        assert_eq!(utility.position, Position::DC);
+        assert_eq!(utility.familiarity(Position::DC), Familiarity::Natural);
+        assert_eq!(utility.familiarity(Position::DMC), Familiarity::Accomplished);
+        assert_eq!(utility.familiarity(Position::STC), Familiarity::Unconvincing);
+
+        // Rating columns win over the position list
+        let rated = &players[1].1;
+        assert_eq!(rated.familiarity(Position::DR), Familiarity::Natural);
+        assert_eq!(rated.familiarity(Position::DC), Familiarity::Accomplished);
+        assert_eq!(rated.familiarity(Position::DMC), Familiarity::Natural);
+        assert_eq!(rated.familiarity(Position::STC), Familiarity::Awkward);
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!((issues[0].row, issues[0].column.as_str()), (3, "DM"));
+    }
+
+    #[test]
+    fn test_detect_format() {
+        assert_eq!(ImportFormat::detect(b"Name,Age\nA,20\n"), ImportFormat::Csv);
+        assert_eq!(ImportFormat::detect(b"\n| Name | Age |\n"), ImportFormat::FmText);
+        assert_eq!(ImportFormat::detect(b"<!DOCTYPE html>\n<html>"), ImportFormat::Html);
+    }
+
+    #[test]
+    fn test_validate_rows() {
+        let table = table(
+            &["Name", "Age", "Position", "Finishing", "Value", "Contract Expiry"],
+            &[
+                &["Striker", "24", "STC", "14-18", "1,500,000", "30/06/2027"],
+                &["No Age", "", "MC", "10", "", ""],
+                &["Bad Row", "20", "XX", "25", "", ""],
+            ],
+        );
+
+        let (players, issues) = validate_rows(&table, None);
+        assert_eq!(players.len(), 1);
+        let (row, player) = &players[0];
+        assert_eq!(*row, 1);
+        assert_eq!(player.position, Position::STC);
+        assert_eq!(player.finishing, Some(16));
+        assert_eq!(player.attribute_ranges[&Attribute::Finishing], AttributeRange::new(14, 18));
+        assert_eq!(player.value, Some(1_500_000));
+        assert_eq!(player.contract_expiry, NaiveDate::from_ymd_opt(2027, 6, 30));
+
+        assert_eq!(issues.len(), 3);
+        assert_eq!(issues[0], RowIssue::new(2, "age", "", "is required"));
+        assert_eq!(issues[1].row, 3);
+        assert_eq!(issues[1].column, "Position");
+        assert_eq!(issues[1].value, "XX");
+        assert_eq!(issues[2].column, "Finishing");
+        assert_eq!(issues[2].value, "25");
+    }
+
+    #[tokio::test]
+    async fn test_preview_writes_nothing() {
+        let pool = setup_test_db().await;
+        let table = table(
+            &["Name", "Age", "Position", "Pace"],
+            &[&["Winger", "21", "AMR", "17"], &["Too Fast", "22", "AMR", "25"]],
+        );
+
+        let report = preview(&table, None);
+        assert!(report.dry_run);
+        assert_eq!(report.valid, 1);
+        assert_eq!(report.rejected, 1);
+        assert_eq!(report.issues.len(), 1);
+
+        let players = PlayerRepository::new(pool, WORKSPACE).get_all().await.unwrap();
+        assert!(players.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_import_players() {
+        let pool = setup_test_db().await;
+        let table = table(
+            &["Name", "Age", "Position", "Pace"],
+            &[
+                &["Winger", "21", "AMR", "17"],
+                &["Out Of Range", "22", "AMR", "25"],
+                &["Missing Position", "22", "", "12"],
+            ],
+        );
+
+        let preview = preview(&table, None);
+        let result = import_players(&pool, WORKSPACE, &table, None).await.unwrap();
+        assert_eq!(result.created, 1);
+        assert_eq!(result.rejected, 2);
+        assert_eq!(result.issues, preview.issues);
+
+        let players = PlayerRepository::new(pool, WORKSPACE).get_all().await.unwrap();
+        assert_eq!(players.len(), 1);
+        assert_eq!(players[0].pace, Some(17));
+    }
+
+    #[tokio::test]
+    async fn test_reimport_updates_existing_players() {
+        let pool = setup_test_db().await;
+        let first = table(
+            &["UID", "Name", "Age", "Nationality", "Position", "Pace"],
+            &[
+                &["1001", "Winger", "21", "ENG", "AMR", "15"],
+                &["", "Keeper", "30", "ESP", "GK", "8"],
+            ],
+        );
+        let result = import_players(&pool, WORKSPACE, &first, None).await.unwrap();
+        assert_eq!(result.created, 2);
+
+        // The winger is matched by UID despite the new age, the keeper by name + age + nationality
+        let second = table(
+            &["UID", "Name", "Age", "Nationality", "Position", "Pace"],
+            &[
+                &["1001", "Winger", "22", "ENG", "AMR", "16"],
+                &["", "Keeper", "30", "ESP", "GK", "8"],
+                &["1002", "Newcomer", "19", "BRA", "STC", "17"],
+            ],
+        );
+        let result = import_players(&pool, WORKSPACE, &second, None).await.unwrap();
+        assert_eq!(result.created, 1);
+        assert_eq!(result.updated, 1);
+        assert_eq!(result.unchanged, 1);
+        assert_eq!(result.rejected, 0);
+
+        let repo = PlayerRepository::new(pool, WORKSPACE);
+        let players = repo.get_all().await.unwrap();
+        assert_eq!(players.len(), 3);
+        let winger = players.iter().find(|p| p.fm_uid == Some(1001)).unwrap();
+        assert_eq!(winger.age, 22);
+        assert_eq!(winger.pace, Some(16));
+
+        // Each import left a snapshot behind
+        let history = repo.get_history(winger.id.unwrap()).await.unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].deltas_to(&history[1])[0].change, 1);
+    }
+
+    #[tokio::test]
+    async fn test_partial_reimport_keeps_stored_fields() {
+        let pool = setup_test_db().await;
+        let full = table(
+            &["UID", "Name", "Age", "Nationality", "Position", "Pace", "Finishing", "Wage"],
+            &[&["1001", "Striker", "24", "ENG", "ST (C), AM (L)", "15", "11-15", "£20K p/w"]],
+        );
+        import_players(&pool, WORKSPACE, &full, None).await.unwrap();
+
+        // A later export with only some of the columns
+        let partial = table(&["UID", "Name", "Age", "Position", "Pace"], &[&["1001", "Striker", "24", "STC", "16"]]);
+        let result = import_players(&pool, WORKSPACE, &partial, None).await.unwrap();
+        assert_eq!(result.updated, 1);
+
+        let striker = PlayerRepository::new(pool, WORKSPACE).get_all().await.unwrap().remove(0);
+        assert_eq!(striker.pace, Some(16));
+        assert_eq!(striker.finishing, Some(13));
+        assert_eq!(striker.attribute_ranges.get(&Attribute::Finishing), Some(&AttributeRange { low: 11, high: 15 }));
+        assert_eq!(striker.nationality.as_deref(), Some("ENG"));
+        assert_eq!(striker.wage, Some(20_000));
+        assert_eq!(striker.familiarity(Position::AML), Familiarity::Accomplished);
+    }
+
+    #[tokio::test]
+    async fn test_import_derives_age_from_date_of_birth() {
+        let pool = setup_test_db().await;
+        CalendarRepository::new(pool.clone(), WORKSPACE)
+            .set_game_date(NaiveDate::from_ymd_opt(2025, 7, 1).unwrap())
+            .await
+            .unwrap();
+        let table = table(
+            &["Name", "DoB", "Position"],
+            &[&["Wonderkid", "12/3/2008 (17 years old)", "AMC"]],
+        );
+
+        assert_eq!(preview(&table, None).valid, 1);
+        import_players(&pool, WORKSPACE, &table, None).await.unwrap();
+
+        let repo = PlayerRepository::new(pool, WORKSPACE);
+        let players = repo.get_all().await.unwrap();
+        assert_eq!(players[0].age, 17);
+        assert_eq!(players[0].date_of_birth, NaiveDate::from_ymd_opt(2008, 3, 12));
+
+        let history = repo.get_history(players[0].id.unwrap()).await.unwrap();
+        assert_eq!(history[0].recorded_at.date(), NaiveDate::from_ymd_opt(2025, 7, 1).unwrap());
+    }
+}
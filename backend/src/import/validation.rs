@@ -0,0 +1,247 @@
+use serde::Serialize;
+use shared::models::{AttributeRange, Familiarity, Position};
+
+/// A single problem found while validating an import row
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RowIssue {
+    /// 1-based data row number (the header is not counted)
+    pub row: usize,
+    /// Source column header, or the `Player` field name for missing required values
+    pub column: String,
+    /// Offending value as it appeared in the file
+    pub value: String,
+    pub reason: String,
+}
+
+impl RowIssue {
+    pub fn new(row: usize, column: &str, value: &str, reason: impl Into<String>) -> Self {
+        Self {
+            row,
+            column: column.to_string(),
+            value: value.to_string(),
+            reason: reason.into(),
+        }
+    }
+}
+
+/// Validate a 1-20 attribute value
+pub fn validate_attribute(value: i32) -> Result<i32, String> {
+    AttributeRange::exact(value).validate().map(|()| value)
+}
+
+/// Validate a position using the `Position` `FromStr` rules,
+/// falling back to FM's display notation (e.g. "D (C)", "AM (RL), ST (C)")
+pub fn validate_position(value: &str) -> Result<Position, String> {
+    validate_positions(value).map(|positions| positions[0])
+}
+
+/// Every position a position cell lists, main position first
+pub fn validate_positions(value: &str) -> Result<Vec<Position>, String> {
+    let value = value.trim().to_uppercase();
+    match value.parse() {
+        Ok(position) => Ok(vec![position]),
+        Err(err) => {
+            let positions = parse_fm_positions(&value);
+            if positions.is_empty() {
+                Err(err)
+            } else {
+                Ok(positions)
+            }
+        }
+    }
+}
+
+/// Parse FM's position notation into every position it lists, in the order written.
+/// "D/WB (R), DM" becomes DR, WBR, DMC; combinations FM does not have are skipped.
+fn parse_fm_positions(value: &str) -> Vec<Position> {
+    let mut positions = Vec::new();
+    for part in value.split(',') {
+        let part = part.trim();
+        let (groups, sides) = match part.split_once('(') {
+            Some((groups, sides)) => (groups.trim(), sides.trim_end_matches(')').trim()),
+            None => (part, ""),
+        };
+        let sides: Vec<Option<char>> = match sides {
+            "" => vec![None],
+            sides => sides.chars().map(Some).collect(),
+        };
+
+        for group in groups.split('/') {
+            for side in &sides {
+                if let Some(position) = fm_position(group.trim(), *side) {
+                    if !positions.contains(&position) {
+                        positions.push(position);
+                    }
+                }
+            }
+        }
+    }
+    positions
+}
+
+fn fm_position(group: &str, side: Option<char>) -> Option<Position> {
+    let position = match (group, side) {
+        ("GK", _) => Position::GK,
+        ("D", Some('R')) => Position::DR,
+        ("D", Some('L')) => Position::DL,
+        ("D", _) => Position::DC,
+        ("WB", Some('R')) => Position::WBR,
+        ("WB", Some('L')) => Position::WBL,
+        ("DM", _) => Position::DMC,
+        ("M", Some('R')) => Position::MR,
+        ("M", Some('L')) => Position::ML,
+        ("M", _) => Position::MC,
+        ("AM", Some('R')) => Position::AMR,
+        ("AM", Some('L')) => Position::AML,
+        ("AM", _) => Position::AMC,
+        ("ST" | "F", _) => Position::STC,
+        _ => return None,
+    };
+    Some(position)
+}
+
+/// Parse a position familiarity cell: FM's level ("Accomplished") or a 1-20 position rating
+pub fn parse_familiarity(value: &str) -> Result<Familiarity, String> {
+    if let Ok(familiarity) = value.trim().to_lowercase().parse() {
+        return Ok(familiarity);
+    }
+    parse_number(value)
+        .ok()
+        .and_then(Familiarity::from_rating)
+        .ok_or_else(|| "must be natural, accomplished, competent, unconvincing, awkward or a 1-20 rating".to_string())
+}
+
+/// Parse an attribute cell. Partially scouted players show a range such as "11-15";
+/// a plain value is an exact range.
+pub fn parse_attribute(value: &str) -> Result<AttributeRange, String> {
+    match value.split_once('-') {
+        Some((low, high)) if !low.trim().is_empty() => {
+            let range = AttributeRange::new(parse_number(low.trim())?, parse_number(high.trim())?);
+            range.validate()?;
+            Ok(range)
+        }
+        _ => parse_number(value).and_then(validate_attribute).map(AttributeRange::exact),
+    }
+}
+
+/// Parse a currency amount such as "£12.5M", "€850K", "$1,200,000" or "12500000".
+/// FM shows a range ("£1.5M - £3M") when the value is not fully known; the midpoint is used.
+pub fn parse_money(value: &str) -> Result<i64, String> {
+    let value = value.replace('\u{a0}', " ");
+    if let Some((low, high)) = value.split_once(" - ") {
+        return Ok((parse_money(low)? + parse_money(high)?) / 2);
+    }
+
+    let amount: String = value
+        .trim()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '.')
+        .collect();
+    let (number, multiplier) = match amount.chars().last().map(|c| c.to_ascii_uppercase()) {
+        Some('K') => (&amount[..amount.len() - 1], 1_000.0),
+        Some('M') => (&amount[..amount.len() - 1], 1_000_000.0),
+        Some('B') => (&amount[..amount.len() - 1], 1_000_000_000.0),
+        _ => (amount.as_str(), 1.0),
+    };
+    let number: f64 = number
+        .parse()
+        .map_err(|_| "is not a currency amount".to_string())?;
+    Ok((number * multiplier).round() as i64)
+}
+
+/// Parse a wage into a weekly amount. FM appends the period: "£45K p/w",
+/// "£180K p/m" or "£2.3M p/a"; an amount without a period is taken as weekly.
+pub fn parse_wage(value: &str) -> Result<i64, String> {
+    let value = value.replace('\u{a0}', " ").to_lowercase();
+    let (amount, weeks) = if let Some(amount) = value.strip_suffix("p/a") {
+        (amount, 52.0)
+    } else if let Some(amount) = value.strip_suffix("p/m") {
+        (amount, 52.0 / 12.0)
+    } else {
+        (value.strip_suffix("p/w").unwrap_or(&value), 1.0)
+    };
+    let amount = parse_money(amount)?;
+    Ok((amount as f64 / weeks).round() as i64)
+}
+
+/// Parse a whole number, ignoring thousands separators
+pub fn parse_number<T: std::str::FromStr>(value: &str) -> Result<T, String> {
+    value
+        .replace(',', "")
+        .parse()
+        .map_err(|_| "is not a number".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_attribute() {
+        assert_eq!(validate_attribute(1), Ok(1));
+        assert_eq!(validate_attribute(20), Ok(20));
+        assert!(validate_attribute(0).is_err());
+        assert!(validate_attribute(21).is_err());
+    }
+
+    #[test]
+    fn test_validate_position() {
+        assert_eq!(validate_position("stc"), Ok(Position::STC));
+        assert_eq!(validate_position(" DMC "), Ok(Position::DMC));
+        assert!(validate_position("Striker").is_err());
+    }
+
+    #[test]
+    fn test_validate_fm_position() {
+        assert_eq!(validate_position("D (C)"), Ok(Position::DC));
+        assert_eq!(validate_position("D/WB (R)"), Ok(Position::DR));
+        assert_eq!(validate_position("AM (LC), ST (C)"), Ok(Position::AML));
+        assert_eq!(validate_position("DM"), Ok(Position::DMC));
+        assert_eq!(validate_position("ST (C)"), Ok(Position::STC));
+        assert_eq!(validate_position("GK"), Ok(Position::GK));
+        assert_eq!(
+            validate_positions("D/WB (R), DM, M/AM (RC)"),
+            Ok(vec![Position::DR, Position::WBR, Position::DMC, Position::MR, Position::MC, Position::AMR, Position::AMC])
+        );
+        assert_eq!(validate_positions("WB (C), ST (C)"), Ok(vec![Position::STC]));
+        assert!(validate_positions("WB (C)").is_err());
+    }
+
+    #[test]
+    fn test_parse_familiarity() {
+        assert_eq!(parse_familiarity("Accomplished"), Ok(Familiarity::Accomplished));
+        assert_eq!(parse_familiarity("20"), Ok(Familiarity::Natural));
+        assert_eq!(parse_familiarity("7"), Ok(Familiarity::Unconvincing));
+        assert!(parse_familiarity("21").is_err());
+        assert!(parse_familiarity("Decent").is_err());
+    }
+
+    #[test]
+    fn test_parse_attribute() {
+        assert_eq!(parse_attribute("14"), Ok(AttributeRange::exact(14)));
+        assert_eq!(parse_attribute("11-15"), Ok(AttributeRange::new(11, 15)));
+        assert_eq!(parse_attribute("11 - 14").map(|range| range.midpoint()), Ok(12));
+        assert!(parse_attribute("15-11").is_err());
+        assert!(parse_attribute("18-22").is_err());
+        assert!(parse_attribute("-3").is_err());
+    }
+
+    #[test]
+    fn test_parse_money() {
+        assert_eq!(parse_money("12500000"), Ok(12_500_000));
+        assert_eq!(parse_money("£12.5M"), Ok(12_500_000));
+        assert_eq!(parse_money("€850K"), Ok(850_000));
+        assert_eq!(parse_money("$1,200,000"), Ok(1_200_000));
+        assert_eq!(parse_money("£1.5M - £3M"), Ok(2_250_000));
+        assert!(parse_money("Not for Sale").is_err());
+    }
+
+    #[test]
+    fn test_parse_wage() {
+        assert_eq!(parse_wage("£45K p/w"), Ok(45_000));
+        assert_eq!(parse_wage("£45,000\u{a0}p/w"), Ok(45_000));
+        assert_eq!(parse_wage("£2.6M p/a"), Ok(50_000));
+        assert_eq!(parse_wage("£130K p/m"), Ok(30_000));
+        assert_eq!(parse_wage("12000"), Ok(12_000));
+    }
+}
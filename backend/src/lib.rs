@@ -1,3 +1,5 @@
 pub mod db;
 pub mod error;
+pub mod handlers;
+pub mod import;
 pub mod repository;
@@ -1,5 +1,7 @@
 mod db;
 mod error;
+mod handlers;
+mod import;
 mod repository;
 
 use actix_web::{web, App, HttpResponse, HttpServer, Responder};
@@ -53,6 +55,7 @@ async fn main() -> std::io::Result<()> {
             .app_data(web::Data::new(pool.clone()))
             .route("/health", web::get().to(health_check))
             .route("/api/health", web::get().to(health_check))
+            .configure(handlers::configure)
     })
     .bind((host.as_str(), port))?
     .run()
@@ -0,0 +1,123 @@
+use chrono::NaiveDate;
+use sqlx::{SqliteConnection, SqlitePool};
+use shared::models::age_at;
+use crate::error::{AppError, AppResult};
+
+/// A workspace's in-game date
+pub struct CalendarRepository {
+    pool: SqlitePool,
+    workspace_id: i64,
+}
+
+impl CalendarRepository {
+    pub fn new(pool: SqlitePool, workspace_id: i64) -> Self {
+        Self { pool, workspace_id }
+    }
+
+    pub async fn get_game_date(&self) -> AppResult<NaiveDate> {
+        let mut conn = self.pool.acquire().await?;
+        self.game_date_in(&mut conn).await
+    }
+
+    /// Read the game date on an existing connection
+    pub async fn game_date_in(&self, conn: &mut SqliteConnection) -> AppResult<NaiveDate> {
+        let game_date = sqlx::query_scalar!(
+            r#"
+            SELECT game_date AS "game_date: NaiveDate" FROM workspaces WHERE id = ?1
+            "#,
+            self.workspace_id
+        )
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        game_date.ok_or_else(|| {
+            AppError::NotFound(format!("Workspace with id {} not found", self.workspace_id))
+        })
+    }
+
+    /// Move the game date and recompute the stored age of every player in the workspace
+    /// with a known date of birth
+    pub async fn set_game_date(&self, game_date: NaiveDate) -> AppResult<()> {
+        let mut tx = self.pool.begin().await?;
+
+        let result = sqlx::query!(
+            r#"
+            UPDATE workspaces SET game_date = ?1, updated_at = CURRENT_TIMESTAMP WHERE id = ?2
+            "#,
+            game_date,
+            self.workspace_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Workspace with id {} not found", self.workspace_id)));
+        }
+
+        let rows = sqlx::query!(
+            r#"
+            SELECT id AS "id!", date_of_birth AS "date_of_birth!: NaiveDate"
+            FROM players WHERE workspace_id = ?1 AND date_of_birth IS NOT NULL
+            "#,
+            self.workspace_id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for r in rows {
+            let age = age_at(r.date_of_birth, game_date);
+            sqlx::query!(
+                r#"
+                UPDATE players SET age = ?1 WHERE id = ?2
+                "#,
+                age,
+                r.id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::PlayerRepository;
+    use shared::models::{Player, Position};
+
+    /// The default workspace seeded by the migrations
+    const WORKSPACE: i64 = 1;
+
+    async fn setup_test_db() -> SqlitePool {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+        pool
+    }
+
+    #[tokio::test]
+    async fn test_advancing_game_date_updates_derived_values() {
+        let pool = setup_test_db().await;
+        let calendar = CalendarRepository::new(pool.clone(), WORKSPACE);
+        let players = PlayerRepository::new(pool, WORKSPACE);
+
+        calendar.set_game_date(NaiveDate::from_ymd_opt(2025, 7, 1).unwrap()).await.unwrap();
+
+        let mut player = Player::new("Prospect".to_string(), 0, Position::AMC);
+        player.date_of_birth = NaiveDate::from_ymd_opt(2007, 9, 1);
+        player.contract_expiry = NaiveDate::from_ymd_opt(2027, 6, 30);
+        let id = players.create(&player).await.unwrap();
+
+        let stored = players.get_by_id(id).await.unwrap();
+        assert_eq!(stored.age, 17);
+        assert_eq!(stored.contract_months_left, Some(23));
+
+        calendar.set_game_date(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap()).await.unwrap();
+        let stored = players.get_by_id(id).await.unwrap();
+        assert_eq!(stored.age, 18);
+        assert_eq!(stored.contract_months_left, Some(17));
+        assert_eq!(players.get_all().await.unwrap()[0].age, 18);
+    }
+}
@@ -2,13 +2,16 @@ use sqlx::SqlitePool;
 use shared::models::{Formation, FormationPosition};
 use crate::error::{AppError, AppResult};
 
+/// Formations visible in one workspace: the predefined (global) ones plus the workspace's own.
+/// Formations created through the repository belong to its workspace; predefined ones are read-only.
 pub struct FormationRepository {
     pool: SqlitePool,
+    workspace_id: i64,
 }
 
 impl FormationRepository {
-    pub fn new(pool: SqlitePool) -> Self {
-        Self { pool }
+    pub fn new(pool: SqlitePool, workspace_id: i64) -> Self {
+        Self { pool, workspace_id }
     }
 
     pub async fn create(&self, formation: &Formation) -> AppResult<i64> {
@@ -17,13 +20,14 @@ impl FormationRepository {
 
         let result = sqlx::query!(
             r#"
-            INSERT INTO formations (name, description, positions, is_custom)
-            VALUES (?1, ?2, ?3, ?4)
+            INSERT INTO formations (name, description, positions, is_custom, workspace_id)
+            VALUES (?1, ?2, ?3, ?4, ?5)
             "#,
             formation.name,
             formation.description,
             positions_json,
-            formation.is_custom
+            formation.is_custom,
+            self.workspace_id
         )
         .execute(&self.pool)
         .await?;
@@ -34,9 +38,10 @@ impl FormationRepository {
     pub async fn get_by_id(&self, id: i64) -> AppResult<Formation> {
         let row = sqlx::query!(
             r#"
-            SELECT * FROM formations WHERE id = ?1
+            SELECT * FROM formations WHERE id = ?1 AND (workspace_id IS NULL OR workspace_id = ?2)
             "#,
-            id
+            id,
+            self.workspace_id
         )
         .fetch_optional(&self.pool)
         .await?;
@@ -61,8 +66,9 @@ impl FormationRepository {
     pub async fn get_all(&self) -> AppResult<Vec<Formation>> {
         let rows = sqlx::query!(
             r#"
-            SELECT * FROM formations ORDER BY is_custom, name
-            "#
+            SELECT * FROM formations WHERE workspace_id IS NULL OR workspace_id = ?1 ORDER BY is_custom, name
+            "#,
+            self.workspace_id
         )
         .fetch_all(&self.pool)
         .await?;
@@ -95,13 +101,14 @@ impl FormationRepository {
                 description = ?2,
                 positions = ?3,
                 is_custom = ?4
-            WHERE id = ?5
+            WHERE id = ?5 AND workspace_id = ?6
             "#,
             formation.name,
             formation.description,
             positions_json,
             formation.is_custom,
-            id
+            id,
+            self.workspace_id
         )
         .execute(&self.pool)
         .await?;
@@ -116,9 +123,10 @@ impl FormationRepository {
     pub async fn delete(&self, id: i64) -> AppResult<()> {
         let result = sqlx::query!(
             r#"
-            DELETE FROM formations WHERE id = ?1
+            DELETE FROM formations WHERE id = ?1 AND workspace_id = ?2
             "#,
-            id
+            id,
+            self.workspace_id
         )
         .execute(&self.pool)
         .await?;
@@ -133,8 +141,9 @@ impl FormationRepository {
     pub async fn get_custom_formations(&self) -> AppResult<Vec<Formation>> {
         let rows = sqlx::query!(
             r#"
-            SELECT * FROM formations WHERE is_custom = 1 ORDER BY name
-            "#
+            SELECT * FROM formations WHERE is_custom = 1 AND workspace_id = ?1 ORDER BY name
+            "#,
+            self.workspace_id
         )
         .fetch_all(&self.pool)
         .await?;
@@ -162,6 +171,9 @@ mod tests {
     use super::*;
     use shared::models::{Position, FormationPosition};
 
+    /// The default workspace seeded by the migrations
+    const WORKSPACE: i64 = 1;
+
     async fn setup_test_db() -> SqlitePool {
         let pool = SqlitePool::connect(":memory:").await.unwrap();
         sqlx::migrate!("./migrations").run(&pool).await.unwrap();
@@ -190,7 +202,7 @@ mod tests {
     #[tokio::test]
     async fn test_create_and_get_formation() {
         let pool = setup_test_db().await;
-        let repo = FormationRepository::new(pool);
+        let repo = FormationRepository::new(pool, WORKSPACE);
 
         let formation = create_test_formation();
         let id = repo.create(&formation).await.unwrap();
@@ -205,7 +217,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_all_formations() {
         let pool = setup_test_db().await;
-        let repo = FormationRepository::new(pool);
+        let repo = FormationRepository::new(pool, WORKSPACE);
 
         let formation1 = create_test_formation();
         let mut formation2 = create_test_formation();
@@ -221,7 +233,7 @@ mod tests {
     #[tokio::test]
     async fn test_update_formation() {
         let pool = setup_test_db().await;
-        let repo = FormationRepository::new(pool);
+        let repo = FormationRepository::new(pool, WORKSPACE);
 
         let formation = create_test_formation();
         let id = repo.create(&formation).await.unwrap();
@@ -240,7 +252,7 @@ mod tests {
     #[tokio::test]
     async fn test_delete_formation() {
         let pool = setup_test_db().await;
-        let repo = FormationRepository::new(pool);
+        let repo = FormationRepository::new(pool, WORKSPACE);
 
         let formation = create_test_formation();
         let id = repo.create(&formation).await.unwrap();
@@ -254,7 +266,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_custom_formations() {
         let pool = setup_test_db().await;
-        let repo = FormationRepository::new(pool);
+        let repo = FormationRepository::new(pool, WORKSPACE);
 
         let mut formation1 = create_test_formation();
         let mut formation2 = create_test_formation();
@@ -271,7 +283,7 @@ mod tests {
     #[tokio::test]
     async fn test_formation_validation() {
         let pool = setup_test_db().await;
-        let repo = FormationRepository::new(pool);
+        let repo = FormationRepository::new(pool, WORKSPACE);
 
         let positions = vec![
             FormationPosition {
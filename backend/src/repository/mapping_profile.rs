@@ -0,0 +1,255 @@
+use sqlx::SqlitePool;
+use shared::models::MappingProfile;
+use crate::error::{AppError, AppResult};
+use std::collections::HashMap;
+
+/// Mapping profiles visible in one workspace: the built-in (global) ones plus the workspace's own.
+/// Profiles created through the repository belong to its workspace; built-in ones are read-only.
+pub struct MappingProfileRepository {
+    pool: SqlitePool,
+    workspace_id: i64,
+}
+
+impl MappingProfileRepository {
+    pub fn new(pool: SqlitePool, workspace_id: i64) -> Self {
+        Self { pool, workspace_id }
+    }
+
+    pub async fn create(&self, profile: &MappingProfile) -> AppResult<i64> {
+        let columns_json = serde_json::to_string(&profile.columns)
+            .map_err(|e| AppError::InternalError(format!("Failed to serialize columns: {}", e)))?;
+
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO mapping_profiles (name, description, columns, is_custom, workspace_id)
+            VALUES (?1, ?2, ?3, 1, ?4)
+            "#,
+            profile.name,
+            profile.description,
+            columns_json,
+            self.workspace_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    pub async fn get_by_id(&self, id: i64) -> AppResult<MappingProfile> {
+        let row = sqlx::query!(
+            r#"
+            SELECT id, name, description, columns, is_custom FROM mapping_profiles
+            WHERE id = ?1 AND (workspace_id IS NULL OR workspace_id = ?2)
+            "#,
+            id,
+            self.workspace_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some(r) => {
+                let columns: HashMap<String, Option<String>> = serde_json::from_str(&r.columns)
+                    .map_err(|e| AppError::InternalError(format!("Failed to parse columns: {}", e)))?;
+
+                Ok(MappingProfile {
+                    id: Some(r.id),
+                    name: r.name,
+                    description: r.description,
+                    columns,
+                    is_custom: r.is_custom.unwrap_or(false),
+                })
+            }
+            None => Err(AppError::NotFound(format!("Mapping profile with id {} not found", id))),
+        }
+    }
+
+    pub async fn get_all(&self) -> AppResult<Vec<MappingProfile>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, name, description, columns, is_custom FROM mapping_profiles
+            WHERE workspace_id IS NULL OR workspace_id = ?1
+            ORDER BY is_custom, name
+            "#,
+            self.workspace_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut profiles = Vec::new();
+        for r in rows {
+            let columns: HashMap<String, Option<String>> = serde_json::from_str(&r.columns)
+                .map_err(|e| AppError::InternalError(format!("Failed to parse columns: {}", e)))?;
+
+            profiles.push(MappingProfile {
+                id: Some(r.id),
+                name: r.name,
+                description: r.description,
+                columns,
+                is_custom: r.is_custom.unwrap_or(false),
+            });
+        }
+
+        Ok(profiles)
+    }
+
+    pub async fn update(&self, id: i64, profile: &MappingProfile) -> AppResult<()> {
+        self.ensure_custom(id).await?;
+        let columns_json = serde_json::to_string(&profile.columns)
+            .map_err(|e| AppError::InternalError(format!("Failed to serialize columns: {}", e)))?;
+
+        let result = sqlx::query!(
+            r#"
+            UPDATE mapping_profiles SET
+                name = ?1,
+                description = ?2,
+                columns = ?3,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE id = ?4 AND workspace_id = ?5
+            "#,
+            profile.name,
+            profile.description,
+            columns_json,
+            id,
+            self.workspace_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Mapping profile with id {} not found", id)));
+        }
+
+        Ok(())
+    }
+
+    pub async fn delete(&self, id: i64) -> AppResult<()> {
+        self.ensure_custom(id).await?;
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM mapping_profiles WHERE id = ?1 AND workspace_id = ?2
+            "#,
+            id,
+            self.workspace_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Mapping profile with id {} not found", id)));
+        }
+
+        Ok(())
+    }
+
+    /// Fail unless `id` is one of the workspace's own profiles; built-in ones can't be changed
+    async fn ensure_custom(&self, id: i64) -> AppResult<()> {
+        let profile = self.get_by_id(id).await?;
+        if !profile.is_custom {
+            return Err(AppError::Conflict(format!("Mapping profile '{}' is built in and read-only", profile.name)));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The default workspace seeded by the migrations
+    const WORKSPACE: i64 = 1;
+
+    async fn setup_test_db() -> SqlitePool {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+        pool
+    }
+
+    fn create_test_profile() -> MappingProfile {
+        let mut columns = HashMap::new();
+        columns.insert("Player Name".to_string(), Some("name".to_string()));
+        columns.insert("Spd".to_string(), Some("pace".to_string()));
+        columns.insert("Club".to_string(), None);
+
+        MappingProfile::new("Test Source".to_string(), columns)
+    }
+
+    #[tokio::test]
+    async fn test_builtin_profiles_seeded() {
+        let pool = setup_test_db().await;
+        let repo = MappingProfileRepository::new(pool, WORKSPACE);
+
+        let profiles = repo.get_all().await.unwrap();
+        let names: Vec<&str> = profiles.iter().map(|p| p.name.as_str()).collect();
+        assert!(names.contains(&"Football Manager"));
+        assert!(names.contains(&"FM Genie Scout"));
+        assert!(names.contains(&"FMRTE"));
+        for profile in &profiles {
+            assert!(!profile.is_custom);
+            crate::import::validate_profile(profile).unwrap();
+        }
+
+        let genie = profiles.iter().find(|p| p.name == "FM Genie Scout").unwrap();
+        assert_eq!(genie.columns["Jumping"].as_deref(), Some("jumping_reach"));
+    }
+
+    #[tokio::test]
+    async fn test_create_and_get_profile() {
+        let pool = setup_test_db().await;
+        let repo = MappingProfileRepository::new(pool, WORKSPACE);
+
+        let id = repo.create(&create_test_profile()).await.unwrap();
+        let retrieved = repo.get_by_id(id).await.unwrap();
+        assert_eq!(retrieved.name, "Test Source");
+        assert!(retrieved.is_custom);
+        assert_eq!(retrieved.columns["Spd"].as_deref(), Some("pace"));
+        assert_eq!(retrieved.columns["Club"], None);
+    }
+
+    #[tokio::test]
+    async fn test_update_profile() {
+        let pool = setup_test_db().await;
+        let repo = MappingProfileRepository::new(pool, WORKSPACE);
+
+        let id = repo.create(&create_test_profile()).await.unwrap();
+        let mut updated = create_test_profile();
+        updated.name = "Renamed Source".to_string();
+        updated.columns.insert("Jmp".to_string(), Some("jumping_reach".to_string()));
+        repo.update(id, &updated).await.unwrap();
+
+        let retrieved = repo.get_by_id(id).await.unwrap();
+        assert_eq!(retrieved.name, "Renamed Source");
+        assert_eq!(retrieved.columns.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_builtin_profiles_read_only() {
+        let pool = setup_test_db().await;
+        let repo = MappingProfileRepository::new(pool, WORKSPACE);
+
+        let builtin = repo.get_all().await.unwrap().into_iter().find(|p| !p.is_custom).unwrap();
+        let id = builtin.id.unwrap();
+        assert!(matches!(repo.update(id, &create_test_profile()).await, Err(AppError::Conflict(_))));
+        assert!(matches!(repo.delete(id).await, Err(AppError::Conflict(_))));
+        assert_eq!(repo.get_by_id(id).await.unwrap().columns, builtin.columns);
+
+        // A profile created through the API is always the workspace's own
+        let mut disguised = create_test_profile();
+        disguised.is_custom = false;
+        let id = repo.create(&disguised).await.unwrap();
+        assert!(repo.get_by_id(id).await.unwrap().is_custom);
+        repo.delete(id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_delete_profile() {
+        let pool = setup_test_db().await;
+        let repo = MappingProfileRepository::new(pool, WORKSPACE);
+
+        let id = repo.create(&create_test_profile()).await.unwrap();
+        repo.delete(id).await.unwrap();
+
+        assert!(repo.get_by_id(id).await.is_err());
+        assert!(repo.delete(id).await.is_err());
+    }
+}
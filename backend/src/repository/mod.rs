@@ -3,9 +3,15 @@ pub mod formation;
 pub mod role;
 pub mod squad;
 pub mod tactics;
+pub mod mapping_profile;
+pub mod calendar;
+pub mod workspace;
 
 pub use player::PlayerRepository;
 pub use formation::FormationRepository;
 pub use role::RoleRepository;
 pub use squad::SquadRepository;
 pub use tactics::TacticsRepository;
+pub use mapping_profile::MappingProfileRepository;
+pub use calendar::CalendarRepository;
+pub use workspace::WorkspaceRepository;
@@ -1,21 +1,39 @@
-use sqlx::SqlitePool;
-use shared::models::Player;
+use sqlx::{SqliteConnection, SqlitePool};
+use shared::models::{Attribute, AttributeRange, AttributeSnapshot, Familiarity, Player, Position, RecruitmentBrief};
+use std::collections::HashMap;
 use crate::error::{AppError, AppResult};
+use crate::repository::CalendarRepository;
 
+/// Players of one workspace
 pub struct PlayerRepository {
     pool: SqlitePool,
+    workspace_id: i64,
 }
 
 impl PlayerRepository {
-    pub fn new(pool: SqlitePool) -> Self {
-        Self { pool }
+    pub fn new(pool: SqlitePool, workspace_id: i64) -> Self {
+        Self { pool, workspace_id }
+    }
+
+    /// The workspace's calendar, which drives derived values such as age
+    fn calendar(&self) -> CalendarRepository {
+        CalendarRepository::new(self.pool.clone(), self.workspace_id)
     }
 
     pub async fn create(&self, player: &Player) -> AppResult<i64> {
+        let mut conn = self.pool.acquire().await?;
+        self.create_in(&mut conn, player).await
+    }
+
+    /// Insert a player on an existing connection, e.g. inside an import transaction
+    pub async fn create_in(&self, conn: &mut SqliteConnection, player: &Player) -> AppResult<i64> {
+        let age = player.age_on(self.calendar().game_date_in(conn).await?);
+        let attribute_ranges = ranges_to_json(player)?;
+        let position_familiarity = familiarity_to_json(player)?;
         let result = sqlx::query!(
             r#"
             INSERT INTO players (
-                name, age, nationality, position,
+                workspace_id, fm_uid, date_of_birth, name, age, nationality, position,
                 corners, crossing, dribbling, finishing, first_touch, free_kick_taking,
                 heading, long_shots, long_throws, marking, passing, penalty_taking,
                 tackling, technique,
@@ -26,10 +44,10 @@ impl PlayerRepository {
                 stamina, strength,
                 aerial_reach, command_of_area, communication, eccentricity, handling,
                 kicking, one_on_ones, reflexes, rushing_out, punching, throwing,
-                value, wage, contract_expiry
+                value, wage, contract_expiry, attribute_ranges, position_familiarity
             )
             VALUES (
-                ?1, ?2, ?3, ?4,
+                ?57, ?55, ?56, ?1, ?2, ?3, ?4,
                 ?5, ?6, ?7, ?8, ?9, ?10,
                 ?11, ?12, ?13, ?14, ?15, ?16,
                 ?17, ?18,
@@ -40,10 +58,10 @@ impl PlayerRepository {
                 ?39, ?40,
                 ?41, ?42, ?43, ?44, ?45,
                 ?46, ?47, ?48, ?49, ?50, ?51,
-                ?52, ?53, ?54
+                ?52, ?53, ?54, ?58, ?59
             )
             "#,
-            player.name, player.age, player.nationality, player.position.as_str(),
+            player.name, age, player.nationality, player.position.as_str(),
             player.corners, player.crossing, player.dribbling, player.finishing,
             player.first_touch, player.free_kick_taking, player.heading, player.long_shots,
             player.long_throws, player.marking, player.passing, player.penalty_taking,
@@ -57,22 +75,32 @@ impl PlayerRepository {
             player.aerial_reach, player.command_of_area, player.communication,
             player.eccentricity, player.handling, player.kicking, player.one_on_ones,
             player.reflexes, player.rushing_out, player.punching, player.throwing,
-            player.value, player.wage, player.contract_expiry
+            player.value, player.wage, player.contract_expiry,
+            player.fm_uid, player.date_of_birth, self.workspace_id,
+            attribute_ranges,
+            position_familiarity
         )
-        .execute(&self.pool)
+        .execute(&mut *conn)
         .await?;
 
         Ok(result.last_insert_rowid())
     }
 
     pub async fn get_by_id(&self, id: i64) -> AppResult<Player> {
+        let mut conn = self.pool.acquire().await?;
+        self.get_by_id_in(&mut conn, id).await
+    }
+
+    /// Fetch a player on an existing connection
+    pub async fn get_by_id_in(&self, conn: &mut SqliteConnection, id: i64) -> AppResult<Player> {
         let row = sqlx::query!(
             r#"
-            SELECT * FROM players WHERE id = ?1
+            SELECT * FROM players WHERE id = ?1 AND workspace_id = ?2
             "#,
-            id
+            id,
+            self.workspace_id
         )
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *conn)
         .await?;
 
         match row {
@@ -81,12 +109,15 @@ impl PlayerRepository {
                     AppError::InternalError(format!("Invalid position: {}", r.position))
                 })?;
 
-                Ok(Player {
+                let mut player = Player {
                     id: Some(r.id),
+                    fm_uid: r.fm_uid,
                     name: r.name,
                     age: r.age,
+                    date_of_birth: r.date_of_birth,
                     nationality: r.nationality,
                     position,
+                    position_familiarity: familiarity_from_json(&r.position_familiarity)?,
                     corners: r.corners,
                     crossing: r.crossing,
                     dribbling: r.dribbling,
@@ -134,10 +165,14 @@ impl PlayerRepository {
                     rushing_out: r.rushing_out,
                     punching: r.punching,
                     throwing: r.throwing,
+                    attribute_ranges: ranges_from_json(&r.attribute_ranges)?,
                     value: r.value,
                     wage: r.wage,
                     contract_expiry: r.contract_expiry,
-                })
+                    contract_months_left: None,
+                };
+                player.refresh_derived(self.calendar().game_date_in(conn).await?);
+                Ok(player)
             }
             None => Err(AppError::NotFound(format!("Player with id {} not found", id))),
         }
@@ -146,24 +181,29 @@ impl PlayerRepository {
     pub async fn get_all(&self) -> AppResult<Vec<Player>> {
         let rows = sqlx::query!(
             r#"
-            SELECT * FROM players ORDER BY name
-            "#
+            SELECT * FROM players WHERE workspace_id = ?1 ORDER BY name
+            "#,
+            self.workspace_id
         )
         .fetch_all(&self.pool)
         .await?;
 
+        let game_date = self.calendar().get_game_date().await?;
         let mut players = Vec::new();
         for r in rows {
             let position = r.position.parse().map_err(|_| {
                 AppError::InternalError(format!("Invalid position: {}", r.position))
             })?;
 
-            players.push(Player {
+            let mut player = Player {
                 id: Some(r.id),
+                fm_uid: r.fm_uid,
                 name: r.name,
                 age: r.age,
+                date_of_birth: r.date_of_birth,
                 nationality: r.nationality,
                 position,
+                position_familiarity: familiarity_from_json(&r.position_familiarity)?,
                 corners: r.corners,
                 crossing: r.crossing,
                 dribbling: r.dribbling,
@@ -211,16 +251,29 @@ impl PlayerRepository {
                 rushing_out: r.rushing_out,
                 punching: r.punching,
                 throwing: r.throwing,
+                attribute_ranges: ranges_from_json(&r.attribute_ranges)?,
                 value: r.value,
                 wage: r.wage,
                 contract_expiry: r.contract_expiry,
-            });
+                contract_months_left: None,
+            };
+            player.refresh_derived(game_date);
+            players.push(player);
         }
 
         Ok(players)
     }
 
     pub async fn update(&self, id: i64, player: &Player) -> AppResult<()> {
+        let mut conn = self.pool.acquire().await?;
+        self.update_in(&mut conn, id, player).await
+    }
+
+    /// Update a player on an existing connection, e.g. inside an import transaction
+    pub async fn update_in(&self, conn: &mut SqliteConnection, id: i64, player: &Player) -> AppResult<()> {
+        let age = player.age_on(self.calendar().game_date_in(conn).await?);
+        let attribute_ranges = ranges_to_json(player)?;
+        let position_familiarity = familiarity_to_json(player)?;
         let result = sqlx::query!(
             r#"
             UPDATE players SET
@@ -238,10 +291,13 @@ impl PlayerRepository {
                 aerial_reach = ?41, command_of_area = ?42, communication = ?43,
                 eccentricity = ?44, handling = ?45, kicking = ?46, one_on_ones = ?47,
                 reflexes = ?48, rushing_out = ?49, punching = ?50, throwing = ?51,
-                value = ?52, wage = ?53, contract_expiry = ?54
-            WHERE id = ?55
+                value = ?52, wage = ?53, contract_expiry = ?54,
+                fm_uid = ?56, date_of_birth = ?57, attribute_ranges = ?59,
+                position_familiarity = ?60,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE id = ?55 AND workspace_id = ?58
             "#,
-            player.name, player.age, player.nationality, player.position.as_str(),
+            player.name, age, player.nationality, player.position.as_str(),
             player.corners, player.crossing, player.dribbling, player.finishing,
             player.first_touch, player.free_kick_taking, player.heading, player.long_shots,
             player.long_throws, player.marking, player.passing, player.penalty_taking,
@@ -256,9 +312,12 @@ impl PlayerRepository {
             player.eccentricity, player.handling, player.kicking, player.one_on_ones,
             player.reflexes, player.rushing_out, player.punching, player.throwing,
             player.value, player.wage, player.contract_expiry,
-            id
+            id,
+            player.fm_uid, player.date_of_birth, self.workspace_id,
+            attribute_ranges,
+            position_familiarity
         )
-        .execute(&self.pool)
+        .execute(&mut *conn)
         .await?;
 
         if result.rows_affected() == 0 {
@@ -268,12 +327,161 @@ impl PlayerRepository {
         Ok(())
     }
 
+    /// Find the id of the stored player an imported player refers to.
+    /// Matches on the FM unique ID first, then on name + age + nationality.
+    /// A player with a unique ID only falls back onto rows that have none,
+    /// so two namesakes with different IDs are never merged.
+    pub async fn find_existing_in(&self, conn: &mut SqliteConnection, player: &Player) -> AppResult<Option<i64>> {
+        if let Some(fm_uid) = player.fm_uid {
+            let id = sqlx::query_scalar!(
+                r#"
+                SELECT id AS "id!" FROM players WHERE workspace_id = ?1 AND fm_uid = ?2
+                "#,
+                self.workspace_id,
+                fm_uid
+            )
+            .fetch_optional(&mut *conn)
+            .await?;
+
+            if id.is_some() {
+                return Ok(id);
+            }
+        }
+
+        let id = sqlx::query_scalar!(
+            r#"
+            SELECT id AS "id!" FROM players
+            WHERE workspace_id = ?5
+              AND name = ?1 AND age = ?2 AND nationality IS ?3 AND (?4 IS NULL OR fm_uid IS NULL)
+            ORDER BY id
+            LIMIT 1
+            "#,
+            player.name,
+            player.age,
+            player.nationality,
+            player.fm_uid,
+            self.workspace_id
+        )
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        Ok(id)
+    }
+
+    /// Record a snapshot of a player's attributes on an existing connection
+    pub async fn record_snapshot_in(conn: &mut SqliteConnection, snapshot: &AttributeSnapshot) -> AppResult<i64> {
+        let attributes_json = serde_json::to_string(&snapshot.attributes)
+            .map_err(|e| AppError::InternalError(format!("Failed to serialize attributes: {}", e)))?;
+
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO player_attribute_snapshots (player_id, recorded_at, attributes)
+            VALUES (?1, ?2, ?3)
+            "#,
+            snapshot.player_id,
+            snapshot.recorded_at,
+            attributes_json
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    /// A player's attribute snapshots, oldest first
+    pub async fn get_history(&self, player_id: i64) -> AppResult<Vec<AttributeSnapshot>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, player_id, recorded_at, attributes FROM player_attribute_snapshots
+            WHERE player_id = ?1 AND player_id IN (SELECT id FROM players WHERE workspace_id = ?2)
+            ORDER BY recorded_at, id
+            "#,
+            player_id,
+            self.workspace_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut snapshots = Vec::new();
+        for r in rows {
+            let attributes: HashMap<String, i32> = serde_json::from_str(&r.attributes)
+                .map_err(|e| AppError::InternalError(format!("Failed to parse attributes: {}", e)))?;
+
+            snapshots.push(AttributeSnapshot {
+                id: Some(r.id),
+                player_id: r.player_id,
+                recorded_at: r.recorded_at,
+                attributes,
+            });
+        }
+
+        Ok(snapshots)
+    }
+
+    pub async fn get_snapshot(&self, id: i64) -> AppResult<AttributeSnapshot> {
+        let row = sqlx::query!(
+            r#"
+            SELECT id, player_id, recorded_at, attributes FROM player_attribute_snapshots
+            WHERE id = ?1 AND player_id IN (SELECT id FROM players WHERE workspace_id = ?2)
+            "#,
+            id,
+            self.workspace_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some(r) => {
+                let attributes: HashMap<String, i32> = serde_json::from_str(&r.attributes)
+                    .map_err(|e| AppError::InternalError(format!("Failed to parse attributes: {}", e)))?;
+
+                Ok(AttributeSnapshot {
+                    id: Some(r.id),
+                    player_id: r.player_id,
+                    recorded_at: r.recorded_at,
+                    attributes,
+                })
+            }
+            None => Err(AppError::NotFound(format!("Snapshot with id {} not found", id))),
+        }
+    }
+
+    /// Every player's attribute snapshots, oldest first
+    pub async fn get_all_snapshots(&self) -> AppResult<Vec<AttributeSnapshot>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, player_id, recorded_at, attributes FROM player_attribute_snapshots
+            WHERE player_id IN (SELECT id FROM players WHERE workspace_id = ?1)
+            ORDER BY recorded_at, id
+            "#,
+            self.workspace_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut snapshots = Vec::new();
+        for r in rows {
+            let attributes: HashMap<String, i32> = serde_json::from_str(&r.attributes)
+                .map_err(|e| AppError::InternalError(format!("Failed to parse attributes: {}", e)))?;
+
+            snapshots.push(AttributeSnapshot {
+                id: Some(r.id),
+                player_id: r.player_id,
+                recorded_at: r.recorded_at,
+                attributes,
+            });
+        }
+
+        Ok(snapshots)
+    }
+
     pub async fn delete(&self, id: i64) -> AppResult<()> {
         let result = sqlx::query!(
             r#"
-            DELETE FROM players WHERE id = ?1
+            DELETE FROM players WHERE id = ?1 AND workspace_id = ?2
             "#,
-            id
+            id,
+            self.workspace_id
         )
         .execute(&self.pool)
         .await?;
@@ -285,28 +493,42 @@ impl PlayerRepository {
         Ok(())
     }
 
-    pub async fn search_by_position(&self, position: &str) -> AppResult<Vec<Player>> {
+    /// Players whose main position is `position`, or with `min_familiarity`, every player who
+    /// knows the position at least that well
+    pub async fn search_by_position(&self, position: Position, min_familiarity: Option<Familiarity>) -> AppResult<Vec<Player>> {
+        if let Some(min_familiarity) = min_familiarity {
+            let mut players = self.get_all().await?;
+            players.retain(|player| player.plays(position, min_familiarity));
+            return Ok(players);
+        }
+
+        let position = position.as_str();
         let rows = sqlx::query!(
             r#"
-            SELECT * FROM players WHERE position = ?1 ORDER BY name
+            SELECT * FROM players WHERE position = ?1 AND workspace_id = ?2 ORDER BY name
             "#,
-            position
+            position,
+            self.workspace_id
         )
         .fetch_all(&self.pool)
         .await?;
 
+        let game_date = self.calendar().get_game_date().await?;
         let mut players = Vec::new();
         for r in rows {
             let position = r.position.parse().map_err(|_| {
                 AppError::InternalError(format!("Invalid position: {}", r.position))
             })?;
 
-            players.push(Player {
+            let mut player = Player {
                 id: Some(r.id),
+                fm_uid: r.fm_uid,
                 name: r.name,
                 age: r.age,
+                date_of_birth: r.date_of_birth,
                 nationality: r.nationality,
                 position,
+                position_familiarity: familiarity_from_json(&r.position_familiarity)?,
                 corners: r.corners,
                 crossing: r.crossing,
                 dribbling: r.dribbling,
@@ -354,20 +576,65 @@ impl PlayerRepository {
                 rushing_out: r.rushing_out,
                 punching: r.punching,
                 throwing: r.throwing,
+                attribute_ranges: ranges_from_json(&r.attribute_ranges)?,
                 value: r.value,
                 wage: r.wage,
                 contract_expiry: r.contract_expiry,
-            });
+                contract_months_left: None,
+            };
+            player.refresh_derived(game_date);
+            players.push(player);
         }
 
         Ok(players)
     }
+
+    /// Players who fit a recruitment brief
+    pub async fn search_by_brief(&self, brief: &RecruitmentBrief) -> AppResult<Vec<Player>> {
+        let mut players = self.search_by_position(brief.position, Some(brief.min_familiarity)).await?;
+        players.retain(|player| brief.matches(player));
+        Ok(players)
+    }
+}
+
+/// Serialize a player's scouted ranges, rejecting impossible ones. A range the attribute's
+/// value has since been set outside of is out of date and dropped.
+fn ranges_to_json(player: &Player) -> AppResult<String> {
+    let mut ranges = HashMap::new();
+    for (attribute, range) in &player.attribute_ranges {
+        range.validate().map_err(|reason| {
+            AppError::ValidationError(format!("{} range {}-{} {}", attribute.as_str(), range.low, range.high, reason))
+        })?;
+        if attribute.value(player).is_none_or(|value| (range.low..=range.high).contains(&value)) {
+            ranges.insert(*attribute, *range);
+        }
+    }
+    serde_json::to_string(&ranges)
+        .map_err(|e| AppError::InternalError(format!("Failed to serialize attribute_ranges: {}", e)))
+}
+
+fn ranges_from_json(json: &str) -> AppResult<HashMap<Attribute, AttributeRange>> {
+    serde_json::from_str(json)
+        .map_err(|e| AppError::InternalError(format!("Failed to parse attribute_ranges: {}", e)))
+}
+
+fn familiarity_to_json(player: &Player) -> AppResult<String> {
+    serde_json::to_string(&player.position_familiarity)
+        .map_err(|e| AppError::InternalError(format!("Failed to serialize position_familiarity: {}", e)))
+}
+
+fn familiarity_from_json(json: &str) -> AppResult<HashMap<Position, Familiarity>> {
+    serde_json::from_str(json)
+        .map_err(|e| AppError::InternalError(format!("Failed to parse position_familiarity: {}", e)))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use shared::models::Position;
+    use shared::models::TacticalRole;
+
+    /// The default workspace seeded by the migrations
+    const WORKSPACE: i64 = 1;
 
     async fn setup_test_db() -> SqlitePool {
         let pool = SqlitePool::connect(":memory:").await.unwrap();
@@ -378,7 +645,7 @@ mod tests {
     #[tokio::test]
     async fn test_create_and_get_player() {
         let pool = setup_test_db().await;
-        let repo = PlayerRepository::new(pool);
+        let repo = PlayerRepository::new(pool, WORKSPACE);
 
         let mut player = Player::new("Test Player".to_string(), 25, Position::STC);
         player.finishing = Some(15);
@@ -398,7 +665,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_all_players() {
         let pool = setup_test_db().await;
-        let repo = PlayerRepository::new(pool);
+        let repo = PlayerRepository::new(pool, WORKSPACE);
 
         let player1 = Player::new("Player A".to_string(), 25, Position::STC);
         let player2 = Player::new("Player B".to_string(), 28, Position::MC);
@@ -413,7 +680,7 @@ mod tests {
     #[tokio::test]
     async fn test_update_player() {
         let pool = setup_test_db().await;
-        let repo = PlayerRepository::new(pool);
+        let repo = PlayerRepository::new(pool, WORKSPACE);
 
         let player = Player::new("Test Player".to_string(), 25, Position::STC);
         let id = repo.create(&player).await.unwrap();
@@ -430,10 +697,50 @@ mod tests {
         assert_eq!(retrieved.finishing, Some(20));
     }
 
+    #[tokio::test]
+    async fn test_attribute_ranges_round_trip() {
+        let pool = setup_test_db().await;
+        let repo = PlayerRepository::new(pool, WORKSPACE);
+
+        let mut player = Player::new("Trialist".to_string(), 19, Position::STC);
+        player.set_attribute_range(Attribute::Finishing, AttributeRange::new(11, 15));
+        let id = repo.create(&player).await.unwrap();
+
+        let retrieved = repo.get_by_id(id).await.unwrap();
+        assert_eq!(retrieved.finishing, Some(13));
+        assert_eq!(retrieved.attribute_ranges.get(&Attribute::Finishing), Some(&AttributeRange::new(11, 15)));
+
+        player.set_attribute_range(Attribute::Finishing, AttributeRange::exact(14));
+        repo.update(id, &player).await.unwrap();
+        assert!(repo.get_by_id(id).await.unwrap().attribute_ranges.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_attribute_ranges_validated() {
+        let pool = setup_test_db().await;
+        let repo = PlayerRepository::new(pool, WORKSPACE);
+
+        let mut player = Player::new("Trialist".to_string(), 19, Position::STC);
+        player.attribute_ranges.insert(Attribute::Finishing, AttributeRange::new(15, 11));
+        assert!(matches!(repo.create(&player).await, Err(AppError::ValidationError(_))));
+        player.attribute_ranges.insert(Attribute::Finishing, AttributeRange::new(0, 25));
+        assert!(matches!(repo.create(&player).await, Err(AppError::ValidationError(_))));
+
+        player.set_attribute_range(Attribute::Finishing, AttributeRange::new(11, 15));
+        let id = repo.create(&player).await.unwrap();
+
+        // Setting the value outside the range means the range is out of date
+        player.finishing = Some(18);
+        repo.update(id, &player).await.unwrap();
+        let retrieved = repo.get_by_id(id).await.unwrap();
+        assert_eq!(retrieved.finishing, Some(18));
+        assert!(retrieved.attribute_ranges.is_empty());
+    }
+
     #[tokio::test]
     async fn test_delete_player() {
         let pool = setup_test_db().await;
-        let repo = PlayerRepository::new(pool);
+        let repo = PlayerRepository::new(pool, WORKSPACE);
 
         let player = Player::new("Test Player".to_string(), 25, Position::STC);
         let id = repo.create(&player).await.unwrap();
@@ -447,7 +754,7 @@ mod tests {
     #[tokio::test]
     async fn test_search_by_position() {
         let pool = setup_test_db().await;
-        let repo = PlayerRepository::new(pool);
+        let repo = PlayerRepository::new(pool, WORKSPACE);
 
         let player1 = Player::new("Striker 1".to_string(), 25, Position::STC);
         let player2 = Player::new("Striker 2".to_string(), 28, Position::STC);
@@ -457,15 +764,68 @@ mod tests {
         repo.create(&player2).await.unwrap();
         repo.create(&player3).await.unwrap();
 
-        let strikers = repo.search_by_position("STC").await.unwrap();
+        let strikers = repo.search_by_position(Position::STC, None).await.unwrap();
         assert_eq!(strikers.len(), 2);
         assert!(strikers.iter().all(|p| p.position == Position::STC));
     }
 
+    #[tokio::test]
+    async fn test_search_by_familiarity() {
+        let pool = setup_test_db().await;
+        let repo = PlayerRepository::new(pool, WORKSPACE);
+
+        let mut winger = Player::new("Winger".to_string(), 23, Position::AMR);
+        winger.position_familiarity.insert(Position::STC, Familiarity::Accomplished);
+        let mut midfielder = Player::new("Midfielder".to_string(), 26, Position::MC);
+        midfielder.position_familiarity.insert(Position::STC, Familiarity::Unconvincing);
+        let id = repo.create(&winger).await.unwrap();
+        repo.create(&midfielder).await.unwrap();
+        repo.create(&Player::new("Striker".to_string(), 25, Position::STC)).await.unwrap();
+
+        assert_eq!(repo.get_by_id(id).await.unwrap().position_familiarity, winger.position_familiarity);
+        assert_eq!(repo.search_by_position(Position::STC, None).await.unwrap().len(), 1);
+
+        let names = |players: Vec<Player>| players.into_iter().map(|p| p.name).collect::<Vec<_>>();
+        let accomplished = repo.search_by_position(Position::STC, Some(Familiarity::Accomplished)).await.unwrap();
+        assert_eq!(names(accomplished), vec!["Striker", "Winger"]);
+        let unconvincing = repo.search_by_position(Position::STC, Some(Familiarity::Unconvincing)).await.unwrap();
+        assert_eq!(unconvincing.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_search_by_brief() {
+        let pool = setup_test_db().await;
+        let repo = PlayerRepository::new(pool, WORKSPACE);
+
+        let mut target = Player::new("Target".to_string(), 24, Position::DC);
+        target.tackling = Some(15);
+        target.wage = Some(20_000);
+        let mut veteran = target.clone();
+        veteran.name = "Veteran".to_string();
+        veteran.age = 33;
+        let mut weak = target.clone();
+        weak.name = "Weak".to_string();
+        weak.tackling = Some(9);
+        for player in [&target, &veteran, &weak] {
+            repo.create(player).await.unwrap();
+        }
+
+        let brief = RecruitmentBrief {
+            position: Position::DC,
+            tactical_role: TacticalRole::default_for(Position::DC),
+            min_familiarity: Familiarity::Accomplished,
+            min_attributes: HashMap::from([(Attribute::Tackling, 14)]),
+            max_age: Some(28),
+            max_wage: Some(25_000),
+        };
+        let found = repo.search_by_brief(&brief).await.unwrap();
+        assert_eq!(found.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(), vec!["Target"]);
+    }
+
     #[tokio::test]
     async fn test_get_nonexistent_player() {
         let pool = setup_test_db().await;
-        let repo = PlayerRepository::new(pool);
+        let repo = PlayerRepository::new(pool, WORKSPACE);
 
         let result = repo.get_by_id(999).await;
         assert!(result.is_err());
@@ -478,7 +838,7 @@ mod tests {
     #[tokio::test]
     async fn test_update_nonexistent_player() {
         let pool = setup_test_db().await;
-        let repo = PlayerRepository::new(pool);
+        let repo = PlayerRepository::new(pool, WORKSPACE);
 
         let player = Player::new("Test".to_string(), 25, Position::STC);
         let result = repo.update(999, &player).await;
@@ -492,7 +852,7 @@ mod tests {
     #[tokio::test]
     async fn test_delete_nonexistent_player() {
         let pool = setup_test_db().await;
-        let repo = PlayerRepository::new(pool);
+        let repo = PlayerRepository::new(pool, WORKSPACE);
 
         let result = repo.delete(999).await;
         assert!(result.is_err());
@@ -501,4 +861,59 @@ mod tests {
             _ => panic!("Expected NotFound error"),
         }
     }
+
+    #[tokio::test]
+    async fn test_find_existing_player() {
+        let pool = setup_test_db().await;
+        let repo = PlayerRepository::new(pool.clone(), WORKSPACE);
+
+        let mut player = Player::new("Namesake".to_string(), 20, Position::MC);
+        player.fm_uid = Some(7);
+        let id = repo.create(&player).await.unwrap();
+
+        let mut conn = pool.acquire().await.unwrap();
+        let found = repo.find_existing_in(&mut conn, &player).await.unwrap();
+        assert_eq!(found, Some(id));
+
+        // Same name, age and nationality but a different unique ID is a different player
+        player.fm_uid = Some(8);
+        let found = repo.find_existing_in(&mut conn, &player).await.unwrap();
+        assert_eq!(found, None);
+
+        // Without an ID the fallback key applies
+        player.fm_uid = None;
+        let found = repo.find_existing_in(&mut conn, &player).await.unwrap();
+        assert_eq!(found, Some(id));
+    }
+
+    #[tokio::test]
+    async fn test_attribute_history() {
+        let pool = setup_test_db().await;
+        let repo = PlayerRepository::new(pool.clone(), WORKSPACE);
+
+        let mut player = Player::new("Prospect".to_string(), 18, Position::AMC);
+        player.passing = Some(11);
+        let id = repo.create(&player).await.unwrap();
+
+        let first_day = chrono::NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        let mut conn = pool.acquire().await.unwrap();
+        let first = AttributeSnapshot::of(id, &player, first_day);
+        PlayerRepository::record_snapshot_in(&mut conn, &first).await.unwrap();
+        player.passing = Some(13);
+        let second = AttributeSnapshot::of(id, &player, first_day + chrono::Duration::days(30));
+        let second_id = PlayerRepository::record_snapshot_in(&mut conn, &second).await.unwrap();
+
+        let history = repo.get_history(id).await.unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].attributes["passing"], 11);
+        assert_eq!(history[1].id, Some(second_id));
+
+        let snapshot = repo.get_snapshot(second_id).await.unwrap();
+        assert_eq!(history[0].deltas_to(&snapshot)[0].change, 2);
+
+        // Snapshots go with the player
+        drop(conn);
+        repo.delete(id).await.unwrap();
+        assert!(repo.get_all_snapshots().await.unwrap().is_empty());
+    }
 }
@@ -1,32 +1,75 @@
 use sqlx::SqlitePool;
-use shared::models::Role;
+use shared::models::{Attribute, AttributeTier, Formation, GameVersion, Role, TacticalRole, UnfitFormation};
 use crate::error::{AppError, AppResult};
 use std::collections::HashMap;
 
+/// Roles visible in one workspace: the predefined (global) ones plus the workspace's own.
+/// Roles created through the repository belong to its workspace; predefined ones are read-only.
+/// Lists only include the roles of the workspace's game version, or of the version passed to
+/// `with_game_version`.
 pub struct RoleRepository {
     pool: SqlitePool,
+    workspace_id: i64,
+    game_version: Option<GameVersion>,
 }
 
 impl RoleRepository {
-    pub fn new(pool: SqlitePool) -> Self {
-        Self { pool }
+    pub fn new(pool: SqlitePool, workspace_id: i64) -> Self {
+        Self { pool, workspace_id, game_version: None }
+    }
+
+    /// Use the given game version instead of the workspace's
+    pub fn with_game_version(mut self, game_version: Option<GameVersion>) -> Self {
+        self.game_version = game_version;
+        self
+    }
+
+    /// The game version whose roles are valid: the one passed in, or else the workspace's
+    pub async fn game_version(&self) -> AppResult<GameVersion> {
+        if let Some(game_version) = self.game_version {
+            return Ok(game_version);
+        }
+
+        let game_version = sqlx::query_scalar!(
+            r#"
+            SELECT game_version FROM workspaces WHERE id = ?1
+            "#,
+            self.workspace_id
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Workspace with id {} not found", self.workspace_id)))?;
+
+        game_version.parse().map_err(AppError::InternalError)
     }
 
     pub async fn create(&self, role: &Role) -> AppResult<i64> {
+        role.tactical_role(self.game_version().await?).map_err(AppError::ValidationError)?;
+        let weighting = role.weighting.as_str();
+        let role_type = role.role_type.as_str();
         let attribute_weights_json = serde_json::to_string(&role.attribute_weights)
             .map_err(|e| AppError::InternalError(format!("Failed to serialize attribute_weights: {}", e)))?;
+        let attribute_tiers_json = serde_json::to_string(&role.attribute_tiers)
+            .map_err(|e| AppError::InternalError(format!("Failed to serialize attribute_tiers: {}", e)))?;
 
         let result = sqlx::query!(
             r#"
-            INSERT INTO roles (name, position, duty, description, attribute_weights, is_custom)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            INSERT INTO roles (
+                name, position, duty, description, attribute_weights, is_custom, workspace_id,
+                attribute_tiers, weighting, role_type
+            )
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
             "#,
             role.name,
             role.position.as_str(),
             role.duty.duty_name(),
             role.description,
             attribute_weights_json,
-            role.is_custom
+            role.is_custom,
+            self.workspace_id,
+            attribute_tiers_json,
+            weighting,
+            role_type
         )
         .execute(&self.pool)
         .await?;
@@ -37,9 +80,10 @@ impl RoleRepository {
     pub async fn get_by_id(&self, id: i64) -> AppResult<Role> {
         let row = sqlx::query!(
             r#"
-            SELECT * FROM roles WHERE id = ?1
+            SELECT * FROM roles WHERE id = ?1 AND (workspace_id IS NULL OR workspace_id = ?2)
             "#,
-            id
+            id,
+            self.workspace_id
         )
         .fetch_optional(&self.pool)
         .await?;
@@ -49,6 +93,9 @@ impl RoleRepository {
                 let position = r.position.parse()
                     .map_err(|_| AppError::InternalError(format!("Invalid position: {}", r.position)))?;
 
+                let role_type = r.role_type.as_deref().unwrap_or_default().parse()
+                    .map_err(|_| AppError::InternalError(format!("Invalid role type: {:?}", r.role_type)))?;
+
                 let duty = match r.duty.as_str() {
                     "Defend" => shared::models::Duty::Defend,
                     "Support" => shared::models::Duty::Support,
@@ -57,16 +104,24 @@ impl RoleRepository {
                     _ => return Err(AppError::InternalError(format!("Invalid duty: {}", r.duty))),
                 };
 
-                let attribute_weights: HashMap<String, f32> = serde_json::from_str(&r.attribute_weights)
+                let attribute_weights: HashMap<Attribute, f32> = serde_json::from_str(&r.attribute_weights)
                     .map_err(|e| AppError::InternalError(format!("Failed to parse attribute_weights: {}", e)))?;
 
+                let attribute_tiers: HashMap<Attribute, AttributeTier> = serde_json::from_str(&r.attribute_tiers)
+                    .map_err(|e| AppError::InternalError(format!("Failed to parse attribute_tiers: {}", e)))?;
+
+                let weighting = r.weighting.parse().map_err(AppError::InternalError)?;
+
                 Ok(Role {
                     id: Some(r.id),
                     name: r.name,
+                    role_type,
                     position,
                     duty,
                     description: r.description,
                     attribute_weights,
+                    attribute_tiers,
+                    weighting,
                     is_custom: r.is_custom != 0,
                 })
             }
@@ -77,8 +132,9 @@ impl RoleRepository {
     pub async fn get_all(&self) -> AppResult<Vec<Role>> {
         let rows = sqlx::query!(
             r#"
-            SELECT * FROM roles ORDER BY is_custom, name
-            "#
+            SELECT * FROM roles WHERE workspace_id IS NULL OR workspace_id = ?1 ORDER BY is_custom, name
+            "#,
+            self.workspace_id
         )
         .fetch_all(&self.pool)
         .await?;
@@ -88,6 +144,9 @@ impl RoleRepository {
             let position = r.position.parse()
                 .map_err(|_| AppError::InternalError(format!("Invalid position: {}", r.position)))?;
 
+            let role_type = r.role_type.as_deref().unwrap_or_default().parse()
+                .map_err(|_| AppError::InternalError(format!("Invalid role type: {:?}", r.role_type)))?;
+
             let duty = match r.duty.as_str() {
                 "Defend" => shared::models::Duty::Defend,
                 "Support" => shared::models::Duty::Support,
@@ -96,26 +155,42 @@ impl RoleRepository {
                 _ => return Err(AppError::InternalError(format!("Invalid duty: {}", r.duty))),
             };
 
-            let attribute_weights: HashMap<String, f32> = serde_json::from_str(&r.attribute_weights)
+            let attribute_weights: HashMap<Attribute, f32> = serde_json::from_str(&r.attribute_weights)
                 .map_err(|e| AppError::InternalError(format!("Failed to parse attribute_weights: {}", e)))?;
 
+            let attribute_tiers: HashMap<Attribute, AttributeTier> = serde_json::from_str(&r.attribute_tiers)
+                .map_err(|e| AppError::InternalError(format!("Failed to parse attribute_tiers: {}", e)))?;
+
+            let weighting = r.weighting.parse().map_err(AppError::InternalError)?;
+
             roles.push(Role {
                 id: Some(r.id),
                 name: r.name,
+                role_type,
                 position,
                 duty,
                 description: r.description,
                 attribute_weights,
+                attribute_tiers,
+                weighting,
                 is_custom: r.is_custom != 0,
             });
         }
 
+        let game_version = self.game_version().await?;
+        roles.retain(|role| role.tactical_role(game_version).is_ok());
+
         Ok(roles)
     }
 
     pub async fn update(&self, id: i64, role: &Role) -> AppResult<()> {
+        role.tactical_role(self.game_version().await?).map_err(AppError::ValidationError)?;
+        let weighting = role.weighting.as_str();
+        let role_type = role.role_type.as_str();
         let attribute_weights_json = serde_json::to_string(&role.attribute_weights)
             .map_err(|e| AppError::InternalError(format!("Failed to serialize attribute_weights: {}", e)))?;
+        let attribute_tiers_json = serde_json::to_string(&role.attribute_tiers)
+            .map_err(|e| AppError::InternalError(format!("Failed to serialize attribute_tiers: {}", e)))?;
 
         let result = sqlx::query!(
             r#"
@@ -125,8 +200,11 @@ impl RoleRepository {
                 duty = ?3,
                 description = ?4,
                 attribute_weights = ?5,
-                is_custom = ?6
-            WHERE id = ?7
+                is_custom = ?6,
+                attribute_tiers = ?9,
+                weighting = ?10,
+                role_type = ?11
+            WHERE id = ?7 AND workspace_id = ?8
             "#,
             role.name,
             role.position.as_str(),
@@ -134,7 +212,11 @@ impl RoleRepository {
             role.description,
             attribute_weights_json,
             role.is_custom,
-            id
+            id,
+            self.workspace_id,
+            attribute_tiers_json,
+            weighting,
+            role_type
         )
         .execute(&self.pool)
         .await?;
@@ -149,9 +231,10 @@ impl RoleRepository {
     pub async fn delete(&self, id: i64) -> AppResult<()> {
         let result = sqlx::query!(
             r#"
-            DELETE FROM roles WHERE id = ?1
+            DELETE FROM roles WHERE id = ?1 AND workspace_id = ?2
             "#,
-            id
+            id,
+            self.workspace_id
         )
         .execute(&self.pool)
         .await?;
@@ -163,12 +246,85 @@ impl RoleRepository {
         Ok(())
     }
 
+    /// The role scored for a tactical role: the workspace's own role for it if it has one
+    /// (the first created), otherwise the predefined one. A tactical role the game version
+    /// doesn't have is rejected.
+    pub async fn get_by_tactical_role(&self, tactical_role: &TacticalRole) -> AppResult<Role> {
+        let game_version = self.game_version().await?;
+        TacticalRole::new(tactical_role.position, tactical_role.role, tactical_role.duty, game_version)
+            .map_err(AppError::ValidationError)?;
+
+        let position = tactical_role.position.as_str();
+        let role_type = tactical_role.role.as_str();
+        let duty = tactical_role.duty.duty_name();
+        let id = sqlx::query_scalar!(
+            r#"
+            SELECT id AS "id!" FROM roles
+            WHERE position = ?1 AND role_type = ?2 AND duty = ?3
+                AND (workspace_id IS NULL OR workspace_id = ?4)
+            ORDER BY workspace_id IS NULL, id
+            LIMIT 1
+            "#,
+            position,
+            role_type,
+            duty,
+            self.workspace_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match id {
+            Some(id) => self.get_by_id(id).await,
+            None => Err(AppError::NotFound(format!(
+                "No role for {} ({}, {})",
+                tactical_role.role_name(),
+                tactical_role.position.as_str(),
+                tactical_role.duty_name()
+            ))),
+        }
+    }
+
+    /// The role scored for each of a formation's slots, in order; slots without a tactical
+    /// role use their position's default
+    pub async fn get_for_formation(&self, formation: &Formation) -> AppResult<Vec<Role>> {
+        let mut roles = Vec::with_capacity(formation.positions.len());
+        for slot in &formation.positions {
+            roles.push(self.get_by_tactical_role(&slot.tactical_role_or_default()).await?);
+        }
+        Ok(roles)
+    }
+
+    /// The roles for each formation that has them all, and the formations that don't, with the
+    /// reason
+    pub async fn get_for_formations(
+        &self,
+        formations: Vec<Formation>,
+    ) -> AppResult<(Vec<(Formation, Vec<Role>)>, Vec<UnfitFormation>)> {
+        let mut candidates = Vec::with_capacity(formations.len());
+        let mut unfit = Vec::new();
+        for formation in formations {
+            match self.get_for_formation(&formation).await {
+                Ok(roles) => candidates.push((formation, roles)),
+                Err(AppError::NotFound(reason) | AppError::ValidationError(reason)) => unfit.push(UnfitFormation {
+                    formation_id: formation.id.unwrap_or_default(),
+                    formation_name: formation.name,
+                    reason,
+                }),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok((candidates, unfit))
+    }
+
     pub async fn get_by_position(&self, position: &str) -> AppResult<Vec<Role>> {
         let rows = sqlx::query!(
             r#"
-            SELECT * FROM roles WHERE position = ?1 ORDER BY name
+            SELECT * FROM roles
+            WHERE position = ?1 AND (workspace_id IS NULL OR workspace_id = ?2)
+            ORDER BY name
             "#,
-            position
+            position,
+            self.workspace_id
         )
         .fetch_all(&self.pool)
         .await?;
@@ -178,6 +334,9 @@ impl RoleRepository {
             let position = r.position.parse()
                 .map_err(|_| AppError::InternalError(format!("Invalid position: {}", r.position)))?;
 
+            let role_type = r.role_type.as_deref().unwrap_or_default().parse()
+                .map_err(|_| AppError::InternalError(format!("Invalid role type: {:?}", r.role_type)))?;
+
             let duty = match r.duty.as_str() {
                 "Defend" => shared::models::Duty::Defend,
                 "Support" => shared::models::Duty::Support,
@@ -186,20 +345,31 @@ impl RoleRepository {
                 _ => return Err(AppError::InternalError(format!("Invalid duty: {}", r.duty))),
             };
 
-            let attribute_weights: HashMap<String, f32> = serde_json::from_str(&r.attribute_weights)
+            let attribute_weights: HashMap<Attribute, f32> = serde_json::from_str(&r.attribute_weights)
                 .map_err(|e| AppError::InternalError(format!("Failed to parse attribute_weights: {}", e)))?;
 
+            let attribute_tiers: HashMap<Attribute, AttributeTier> = serde_json::from_str(&r.attribute_tiers)
+                .map_err(|e| AppError::InternalError(format!("Failed to parse attribute_tiers: {}", e)))?;
+
+            let weighting = r.weighting.parse().map_err(AppError::InternalError)?;
+
             roles.push(Role {
                 id: Some(r.id),
                 name: r.name,
+                role_type,
                 position,
                 duty,
                 description: r.description,
                 attribute_weights,
+                attribute_tiers,
+                weighting,
                 is_custom: r.is_custom != 0,
             });
         }
 
+        let game_version = self.game_version().await?;
+        roles.retain(|role| role.tactical_role(game_version).is_ok());
+
         Ok(roles)
     }
 }
@@ -207,7 +377,10 @@ impl RoleRepository {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use shared::models::{Position, Duty};
+    use shared::models::{Position, Duty, RoleType, RoleWeighting};
+
+    /// The default workspace seeded by the migrations
+    const WORKSPACE: i64 = 1;
 
     async fn setup_test_db() -> SqlitePool {
         let pool = SqlitePool::connect(":memory:").await.unwrap();
@@ -217,11 +390,12 @@ mod tests {
 
     fn create_test_role() -> Role {
         let mut attribute_weights = HashMap::new();
-        attribute_weights.insert("finishing".to_string(), 0.9);
-        attribute_weights.insert("pace".to_string(), 0.8);
+        attribute_weights.insert(Attribute::Finishing, 0.9);
+        attribute_weights.insert(Attribute::Pace, 0.8);
 
         Role::new(
             "Test Striker".to_string(),
+            RoleType::AdvancedForward,
             Position::STC,
             Duty::Attack,
             attribute_weights,
@@ -231,7 +405,7 @@ mod tests {
     #[tokio::test]
     async fn test_create_and_get_role() {
         let pool = setup_test_db().await;
-        let repo = RoleRepository::new(pool);
+        let repo = RoleRepository::new(pool, WORKSPACE);
 
         let role = create_test_role();
         let id = repo.create(&role).await.unwrap();
@@ -241,13 +415,34 @@ mod tests {
         assert_eq!(retrieved.name, "Test Striker");
         assert_eq!(retrieved.position, Position::STC);
         assert_eq!(retrieved.duty, Duty::Attack);
-        assert_eq!(retrieved.get_weight("finishing"), 0.9);
+        assert_eq!(retrieved.get_weight(Attribute::Finishing), 0.9);
+    }
+
+    #[tokio::test]
+    async fn test_tiered_roles() {
+        let pool = setup_test_db().await;
+        let repo = RoleRepository::new(pool, WORKSPACE);
+
+        let tiers = HashMap::from([
+            (Attribute::Finishing, AttributeTier::Key),
+            (Attribute::Pace, AttributeTier::Preferred),
+        ]);
+        let id = repo.create(&create_test_role().with_tiers(tiers.clone())).await.unwrap();
+        let retrieved = repo.get_by_id(id).await.unwrap();
+        assert_eq!(retrieved.weighting, RoleWeighting::Tiers);
+        assert_eq!(retrieved.attribute_tiers, tiers);
+
+        // The predefined roles follow FM's key and preferred attributes
+        let roles = repo.get_all().await.unwrap();
+        let goalkeeper = roles.iter().find(|r| r.name == "Goalkeeper").unwrap();
+        assert_eq!(goalkeeper.weighting, RoleWeighting::Tiers);
+        assert_eq!(goalkeeper.attribute_tiers[&Attribute::Reflexes], AttributeTier::Key);
     }
 
     #[tokio::test]
     async fn test_get_all_roles() {
         let pool = setup_test_db().await;
-        let repo = RoleRepository::new(pool);
+        let repo = RoleRepository::new(pool, WORKSPACE);
 
         let role1 = create_test_role();
         let mut role2 = create_test_role();
@@ -263,7 +458,7 @@ mod tests {
     #[tokio::test]
     async fn test_update_role() {
         let pool = setup_test_db().await;
-        let repo = RoleRepository::new(pool);
+        let repo = RoleRepository::new(pool, WORKSPACE);
 
         let role = create_test_role();
         let id = repo.create(&role).await.unwrap();
@@ -282,7 +477,7 @@ mod tests {
     #[tokio::test]
     async fn test_delete_role() {
         let pool = setup_test_db().await;
-        let repo = RoleRepository::new(pool);
+        let repo = RoleRepository::new(pool, WORKSPACE);
 
         let role = create_test_role();
         let id = repo.create(&role).await.unwrap();
@@ -296,16 +491,18 @@ mod tests {
     #[tokio::test]
     async fn test_get_by_position() {
         let pool = setup_test_db().await;
-        let repo = RoleRepository::new(pool);
+        let repo = RoleRepository::new(pool, WORKSPACE);
+        let predefined = repo.get_by_position("STC").await.unwrap().len();
 
         let role1 = create_test_role();
         let mut role2 = create_test_role();
         role2.name = "Another Striker".to_string();
 
         let mut weights = HashMap::new();
-        weights.insert("passing".to_string(), 0.9);
+        weights.insert(Attribute::Passing, 0.9);
         let role3 = Role::new(
             "Midfielder".to_string(),
+            RoleType::CentralMidfielder,
             Position::MC,
             Duty::Support,
             weights,
@@ -316,7 +513,143 @@ mod tests {
         repo.create(&role3).await.unwrap();
 
         let strikers = repo.get_by_position("STC").await.unwrap();
-        assert_eq!(strikers.len(), 2);
+        assert_eq!(strikers.len(), predefined + 2);
         assert!(strikers.iter().all(|r| r.position == Position::STC));
     }
+
+    #[tokio::test]
+    async fn test_role_type_validated() {
+        let pool = setup_test_db().await;
+        let repo = RoleRepository::new(pool, WORKSPACE);
+
+        let mut role = create_test_role();
+        role.role_type = RoleType::Anchor;
+        assert!(matches!(repo.create(&role).await, Err(AppError::ValidationError(_))));
+
+        let id = repo.create(&create_test_role()).await.unwrap();
+        assert!(matches!(repo.update(id, &role).await, Err(AppError::ValidationError(_))));
+        assert_eq!(repo.get_by_id(id).await.unwrap().role_type, RoleType::AdvancedForward);
+    }
+
+    #[tokio::test]
+    async fn test_get_by_tactical_role() {
+        let pool = setup_test_db().await;
+        let repo = RoleRepository::new(pool, WORKSPACE);
+        let advanced_forward = TacticalRole::new(Position::STC, RoleType::AdvancedForward, Duty::Attack, GameVersion::FM24).unwrap();
+
+        let predefined = repo.get_by_tactical_role(&advanced_forward).await.unwrap();
+        assert_eq!(predefined.name, "Advanced Forward");
+        assert!(!predefined.is_custom);
+
+        // A workspace's own version takes precedence
+        let id = repo.create(&create_test_role()).await.unwrap();
+        assert_eq!(repo.get_by_tactical_role(&advanced_forward).await.unwrap().id, Some(id));
+    }
+
+    #[tokio::test]
+    async fn test_get_by_tactical_role_follows_game_version() {
+        let pool = setup_test_db().await;
+        let wide_centre_back = TacticalRole::new(Position::DC, RoleType::WideCentreBack, Duty::Defend, GameVersion::FM24).unwrap();
+
+        let repo = RoleRepository::new(pool.clone(), WORKSPACE);
+        assert_eq!(repo.get_by_tactical_role(&wide_centre_back).await.unwrap().role_type, RoleType::WideCentreBack);
+
+        let fm23_repo = RoleRepository::new(pool, WORKSPACE).with_game_version(Some(GameVersion::FM23));
+        assert!(matches!(fm23_repo.get_by_tactical_role(&wide_centre_back).await, Err(AppError::ValidationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_for_formation() {
+        let pool = setup_test_db().await;
+        let repo = RoleRepository::new(pool.clone(), WORKSPACE);
+        let formation = crate::repository::FormationRepository::new(pool, WORKSPACE)
+            .get_all()
+            .await
+            .unwrap()
+            .remove(0);
+
+        let roles = repo.get_for_formation(&formation).await.unwrap();
+        assert_eq!(roles.len(), formation.positions.len());
+        for (slot, role) in formation.positions.iter().zip(&roles) {
+            assert_eq!(role.position, slot.position);
+            assert_eq!(role.role_type, slot.tactical_role_or_default().role);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_for_formations_lists_unfit() {
+        let pool = setup_test_db().await;
+        let formations = crate::repository::FormationRepository::new(pool.clone(), WORKSPACE)
+            .get_all()
+            .await
+            .unwrap();
+        let mut wide_centre_backs = formations[0].clone();
+        wide_centre_backs.id = Some(0);
+        wide_centre_backs.name = "Wide Centre-Backs".to_string();
+        let slot = wide_centre_backs.positions.iter_mut().find(|slot| slot.position == Position::DC).unwrap();
+        slot.tactical_role = Some(TacticalRole::new(Position::DC, RoleType::WideCentreBack, Duty::Support, GameVersion::FM24).unwrap());
+
+        let fm23_repo = RoleRepository::new(pool.clone(), WORKSPACE).with_game_version(Some(GameVersion::FM23));
+        let (candidates, unfit) = fm23_repo.get_for_formations(vec![formations[0].clone(), wide_centre_backs.clone()]).await.unwrap();
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].0.name, formations[0].name);
+        assert_eq!(unfit.len(), 1);
+        assert_eq!(unfit[0].formation_name, "Wide Centre-Backs");
+
+        let (candidates, unfit) = RoleRepository::new(pool, WORKSPACE).get_for_formations(vec![wide_centre_backs]).await.unwrap();
+        assert_eq!(candidates.len(), 1);
+        assert!(unfit.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_catalogue_matches_tactical_roles() {
+        let pool = setup_test_db().await;
+        let repo = RoleRepository::new(pool, WORKSPACE);
+        let valid = TacticalRole::all_valid(GameVersion::FM24);
+
+        let predefined = repo.get_all().await.unwrap();
+        for role in &predefined {
+            let matches = valid
+                .iter()
+                .filter(|t| t.position == role.position && t.duty == role.duty && t.role_name() == role.name)
+                .count();
+            assert_eq!(matches, 1, "{} ({:?}, {:?}) matches {} tactical roles", role.name, role.position, role.duty, matches);
+            assert_eq!(role.tactical_role(GameVersion::FM24).map(|t| t.role_name()), Ok(role.name.as_str()));
+            assert!(!role.attribute_tiers.is_empty(), "{} has no attribute tiers", role.name);
+        }
+
+        // Combined with the unique index, every valid combination is seeded exactly once
+        assert_eq!(predefined.len(), valid.len());
+
+        // Each duty of a role is described on its own
+        for role in &predefined {
+            let same_description = predefined
+                .iter()
+                .filter(|other| other.name == role.name && other.duty != role.duty && other.description == role.description)
+                .count();
+            assert_eq!(same_description, 0, "{} shares its description across duties", role.name);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_roles_follow_game_version() {
+        let pool = setup_test_db().await;
+        let repo = RoleRepository::new(pool.clone(), WORKSPACE);
+        assert_eq!(repo.game_version().await.unwrap(), GameVersion::FM24);
+        let fm24 = repo.get_by_position("DC").await.unwrap();
+        assert!(fm24.iter().any(|r| r.role_type == RoleType::WideCentreBack));
+
+        let fm23_repo = RoleRepository::new(pool, WORKSPACE).with_game_version(Some(GameVersion::FM23));
+        let fm23 = fm23_repo.get_by_position("DC").await.unwrap();
+        assert!(!fm23.iter().any(|r| r.role_type == RoleType::WideCentreBack));
+        assert_eq!(fm23_repo.get_all().await.unwrap().len(), TacticalRole::all_valid(GameVersion::FM23).len());
+
+        // FM23 has no Inverted Full Back to base a custom role on
+        let mut role = create_test_role();
+        role.position = Position::DR;
+        role.role_type = RoleType::InvertedFullBack;
+        role.duty = Duty::Defend;
+        assert!(matches!(fm23_repo.create(&role).await, Err(AppError::ValidationError(_))));
+        assert!(repo.create(&role).await.is_ok());
+    }
 }
@@ -1,14 +1,15 @@
 use sqlx::SqlitePool;
-use shared::models::{Squad, SquadPlayer};
+use shared::models::{LineupConstraints, Squad, SquadPlayer};
 use crate::error::{AppError, AppResult};
 
 pub struct SquadRepository {
     pool: SqlitePool,
+    workspace_id: i64,
 }
 
 impl SquadRepository {
-    pub fn new(pool: SqlitePool) -> Self {
-        Self { pool }
+    pub fn new(pool: SqlitePool, workspace_id: i64) -> Self {
+        Self { pool, workspace_id }
     }
 
     pub async fn create(&self, squad: &Squad) -> AppResult<i64> {
@@ -18,10 +19,13 @@ impl SquadRepository {
         let substitutes_json = serde_json::to_string(&squad.substitutes)
             .map_err(|e| AppError::InternalError(format!("Failed to serialize substitutes: {}", e)))?;
 
+        let constraints_json = serde_json::to_string(&squad.constraints)
+            .map_err(|e| AppError::InternalError(format!("Failed to serialize constraints: {}", e)))?;
+
         let result = sqlx::query!(
             r#"
-            INSERT INTO squads (name, formation_id, starting_xi, substitutes, average_rating, total_value, total_wage)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            INSERT INTO squads (name, formation_id, starting_xi, substitutes, average_rating, total_value, total_wage, workspace_id, constraints)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
             "#,
             squad.name,
             squad.formation_id,
@@ -29,7 +33,9 @@ impl SquadRepository {
             substitutes_json,
             squad.average_rating,
             squad.total_value,
-            squad.total_wage
+            squad.total_wage,
+            self.workspace_id,
+            constraints_json
         )
         .execute(&self.pool)
         .await?;
@@ -40,9 +46,10 @@ impl SquadRepository {
     pub async fn get_by_id(&self, id: i64) -> AppResult<Squad> {
         let row = sqlx::query!(
             r#"
-            SELECT * FROM squads WHERE id = ?1
+            SELECT * FROM squads WHERE id = ?1 AND workspace_id = ?2
             "#,
-            id
+            id,
+            self.workspace_id
         )
         .fetch_optional(&self.pool)
         .await?;
@@ -55,6 +62,9 @@ impl SquadRepository {
                 let substitutes: Vec<i64> = serde_json::from_str(&r.substitutes)
                     .map_err(|e| AppError::InternalError(format!("Failed to parse substitutes: {}", e)))?;
 
+                let constraints: LineupConstraints = serde_json::from_str(&r.constraints)
+                    .map_err(|e| AppError::InternalError(format!("Failed to parse constraints: {}", e)))?;
+
                 Ok(Squad {
                     id: Some(r.id),
                     name: r.name,
@@ -64,6 +74,7 @@ impl SquadRepository {
                     average_rating: r.average_rating,
                     total_value: r.total_value,
                     total_wage: r.total_wage,
+                    constraints,
                 })
             }
             None => Err(AppError::NotFound(format!("Squad with id {} not found", id))),
@@ -73,8 +84,9 @@ impl SquadRepository {
     pub async fn get_all(&self) -> AppResult<Vec<Squad>> {
         let rows = sqlx::query!(
             r#"
-            SELECT * FROM squads ORDER BY name
-            "#
+            SELECT * FROM squads WHERE workspace_id = ?1 ORDER BY name
+            "#,
+            self.workspace_id
         )
         .fetch_all(&self.pool)
         .await?;
@@ -87,6 +99,9 @@ impl SquadRepository {
             let substitutes: Vec<i64> = serde_json::from_str(&r.substitutes)
                 .map_err(|e| AppError::InternalError(format!("Failed to parse substitutes: {}", e)))?;
 
+            let constraints: LineupConstraints = serde_json::from_str(&r.constraints)
+                .map_err(|e| AppError::InternalError(format!("Failed to parse constraints: {}", e)))?;
+
             squads.push(Squad {
                 id: Some(r.id),
                 name: r.name,
@@ -96,6 +111,7 @@ impl SquadRepository {
                 average_rating: r.average_rating,
                 total_value: r.total_value,
                 total_wage: r.total_wage,
+                constraints,
             });
         }
 
@@ -109,6 +125,9 @@ impl SquadRepository {
         let substitutes_json = serde_json::to_string(&squad.substitutes)
             .map_err(|e| AppError::InternalError(format!("Failed to serialize substitutes: {}", e)))?;
 
+        let constraints_json = serde_json::to_string(&squad.constraints)
+            .map_err(|e| AppError::InternalError(format!("Failed to serialize constraints: {}", e)))?;
+
         let result = sqlx::query!(
             r#"
             UPDATE squads SET
@@ -118,8 +137,9 @@ impl SquadRepository {
                 substitutes = ?4,
                 average_rating = ?5,
                 total_value = ?6,
-                total_wage = ?7
-            WHERE id = ?8
+                total_wage = ?7,
+                constraints = ?10
+            WHERE id = ?8 AND workspace_id = ?9
             "#,
             squad.name,
             squad.formation_id,
@@ -128,7 +148,9 @@ impl SquadRepository {
             squad.average_rating,
             squad.total_value,
             squad.total_wage,
-            id
+            id,
+            self.workspace_id,
+            constraints_json
         )
         .execute(&self.pool)
         .await?;
@@ -143,9 +165,10 @@ impl SquadRepository {
     pub async fn delete(&self, id: i64) -> AppResult<()> {
         let result = sqlx::query!(
             r#"
-            DELETE FROM squads WHERE id = ?1
+            DELETE FROM squads WHERE id = ?1 AND workspace_id = ?2
             "#,
-            id
+            id,
+            self.workspace_id
         )
         .execute(&self.pool)
         .await?;
@@ -160,9 +183,10 @@ impl SquadRepository {
     pub async fn get_by_formation(&self, formation_id: i64) -> AppResult<Vec<Squad>> {
         let rows = sqlx::query!(
             r#"
-            SELECT * FROM squads WHERE formation_id = ?1 ORDER BY name
+            SELECT * FROM squads WHERE formation_id = ?1 AND workspace_id = ?2 ORDER BY name
             "#,
-            formation_id
+            formation_id,
+            self.workspace_id
         )
         .fetch_all(&self.pool)
         .await?;
@@ -175,6 +199,9 @@ impl SquadRepository {
             let substitutes: Vec<i64> = serde_json::from_str(&r.substitutes)
                 .map_err(|e| AppError::InternalError(format!("Failed to parse substitutes: {}", e)))?;
 
+            let constraints: LineupConstraints = serde_json::from_str(&r.constraints)
+                .map_err(|e| AppError::InternalError(format!("Failed to parse constraints: {}", e)))?;
+
             squads.push(Squad {
                 id: Some(r.id),
                 name: r.name,
@@ -184,6 +211,7 @@ impl SquadRepository {
                 average_rating: r.average_rating,
                 total_value: r.total_value,
                 total_wage: r.total_wage,
+                constraints,
             });
         }
 
@@ -194,7 +222,10 @@ impl SquadRepository {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use shared::models::{Position, SquadPlayer};
+    use shared::models::{ExcludedPlayer, ExclusionReason, LockedPlayer, MinutesCap, Position, SquadPlayer};
+
+    /// The default workspace seeded by the migrations
+    const WORKSPACE: i64 = 1;
 
     async fn setup_test_db() -> SqlitePool {
         let pool = SqlitePool::connect(":memory:").await.unwrap();
@@ -221,7 +252,7 @@ mod tests {
     #[tokio::test]
     async fn test_create_and_get_squad() {
         let pool = setup_test_db().await;
-        let repo = SquadRepository::new(pool);
+        let repo = SquadRepository::new(pool, WORKSPACE);
 
         let squad = create_test_squad();
         let id = repo.create(&squad).await.unwrap();
@@ -237,7 +268,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_all_squads() {
         let pool = setup_test_db().await;
-        let repo = SquadRepository::new(pool);
+        let repo = SquadRepository::new(pool, WORKSPACE);
 
         let squad1 = create_test_squad();
         let mut squad2 = create_test_squad();
@@ -253,7 +284,7 @@ mod tests {
     #[tokio::test]
     async fn test_update_squad() {
         let pool = setup_test_db().await;
-        let repo = SquadRepository::new(pool);
+        let repo = SquadRepository::new(pool, WORKSPACE);
 
         let squad = create_test_squad();
         let id = repo.create(&squad).await.unwrap();
@@ -272,7 +303,7 @@ mod tests {
     #[tokio::test]
     async fn test_delete_squad() {
         let pool = setup_test_db().await;
-        let repo = SquadRepository::new(pool);
+        let repo = SquadRepository::new(pool, WORKSPACE);
 
         let squad = create_test_squad();
         let id = repo.create(&squad).await.unwrap();
@@ -286,7 +317,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_by_formation() {
         let pool = setup_test_db().await;
-        let repo = SquadRepository::new(pool);
+        let repo = SquadRepository::new(pool, WORKSPACE);
 
         let mut squad1 = create_test_squad();
         squad1.formation_id = 1;
@@ -311,7 +342,7 @@ mod tests {
     #[tokio::test]
     async fn test_squad_with_statistics() {
         let pool = setup_test_db().await;
-        let repo = SquadRepository::new(pool);
+        let repo = SquadRepository::new(pool, WORKSPACE);
 
         let mut squad = create_test_squad();
         squad.average_rating = Some(75.5);
@@ -325,4 +356,20 @@ mod tests {
         assert_eq!(retrieved.total_value, Some(50000000));
         assert_eq!(retrieved.total_wage, Some(250000));
     }
+
+    #[tokio::test]
+    async fn test_squad_constraints() {
+        let pool = setup_test_db().await;
+        let repo = SquadRepository::new(pool, WORKSPACE);
+
+        let mut squad = create_test_squad();
+        squad.constraints.locked.push(LockedPlayer { player_id: 1, slot: 0 });
+        squad.constraints.excluded.push(ExcludedPlayer { player_id: 4, reason: ExclusionReason::Injured });
+        let id = repo.create(&squad).await.unwrap();
+        assert_eq!(repo.get_by_id(id).await.unwrap().constraints, squad.constraints);
+
+        squad.constraints.minutes_caps.push(MinutesCap { player_id: 2, max_minutes: 45 });
+        repo.update(id, &squad).await.unwrap();
+        assert_eq!(repo.get_all().await.unwrap()[0].constraints, squad.constraints);
+    }
 }
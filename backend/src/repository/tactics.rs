@@ -5,11 +5,12 @@ use std::collections::HashMap;
 
 pub struct TacticsRepository {
     pool: SqlitePool,
+    workspace_id: i64,
 }
 
 impl TacticsRepository {
-    pub fn new(pool: SqlitePool) -> Self {
-        Self { pool }
+    pub fn new(pool: SqlitePool, workspace_id: i64) -> Self {
+        Self { pool, workspace_id }
     }
 
     pub async fn create(&self, tactics: &Tactics) -> AppResult<i64> {
@@ -20,18 +21,19 @@ impl TacticsRepository {
             r#"
             INSERT INTO tactics (
                 name, mentality, width, tempo, pressing_intensity, defensive_line,
-                team_instructions, squad_id
+                team_instructions, squad_id, workspace_id
             )
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
             "#,
             tactics.name,
             tactics.mentality.as_str(),
-            serde_json::to_string(&tactics.width).unwrap(),
-            serde_json::to_string(&tactics.tempo).unwrap(),
-            serde_json::to_string(&tactics.pressing_intensity).unwrap(),
-            serde_json::to_string(&tactics.defensive_line).unwrap(),
+            tactics.width.as_str(),
+            tactics.tempo.as_str(),
+            tactics.pressing_intensity.as_str(),
+            tactics.defensive_line.as_str(),
             team_instructions_json,
-            tactics.squad_id
+            tactics.squad_id,
+            self.workspace_id
         )
         .execute(&self.pool)
         .await?;
@@ -42,9 +44,10 @@ impl TacticsRepository {
     pub async fn get_by_id(&self, id: i64) -> AppResult<Tactics> {
         let row = sqlx::query!(
             r#"
-            SELECT * FROM tactics WHERE id = ?1
+            SELECT * FROM tactics WHERE id = ?1 AND workspace_id = ?2
             "#,
-            id
+            id,
+            self.workspace_id
         )
         .fetch_optional(&self.pool)
         .await?;
@@ -62,17 +65,17 @@ impl TacticsRepository {
                     _ => return Err(AppError::InternalError(format!("Invalid mentality: {}", r.mentality))),
                 };
 
-                let width: Width = serde_json::from_str(&r.width)
-                    .map_err(|e| AppError::InternalError(format!("Failed to parse width: {}", e)))?;
+                let width: Width = r.width.parse()
+                    .map_err(AppError::InternalError)?;
 
-                let tempo: Tempo = serde_json::from_str(&r.tempo)
-                    .map_err(|e| AppError::InternalError(format!("Failed to parse tempo: {}", e)))?;
+                let tempo: Tempo = r.tempo.parse()
+                    .map_err(AppError::InternalError)?;
 
-                let pressing_intensity: PressingIntensity = serde_json::from_str(&r.pressing_intensity)
-                    .map_err(|e| AppError::InternalError(format!("Failed to parse pressing_intensity: {}", e)))?;
+                let pressing_intensity: PressingIntensity = r.pressing_intensity.parse()
+                    .map_err(AppError::InternalError)?;
 
-                let defensive_line: DefensiveLine = serde_json::from_str(&r.defensive_line)
-                    .map_err(|e| AppError::InternalError(format!("Failed to parse defensive_line: {}", e)))?;
+                let defensive_line: DefensiveLine = r.defensive_line.parse()
+                    .map_err(AppError::InternalError)?;
 
                 let team_instructions: HashMap<String, bool> = serde_json::from_str(&r.team_instructions)
                     .map_err(|e| AppError::InternalError(format!("Failed to parse team_instructions: {}", e)))?;
@@ -96,8 +99,9 @@ impl TacticsRepository {
     pub async fn get_all(&self) -> AppResult<Vec<Tactics>> {
         let rows = sqlx::query!(
             r#"
-            SELECT * FROM tactics ORDER BY name
-            "#
+            SELECT * FROM tactics WHERE workspace_id = ?1 ORDER BY name
+            "#,
+            self.workspace_id
         )
         .fetch_all(&self.pool)
         .await?;
@@ -115,17 +119,17 @@ impl TacticsRepository {
                 _ => return Err(AppError::InternalError(format!("Invalid mentality: {}", r.mentality))),
             };
 
-            let width: Width = serde_json::from_str(&r.width)
-                .map_err(|e| AppError::InternalError(format!("Failed to parse width: {}", e)))?;
+            let width: Width = r.width.parse()
+                .map_err(AppError::InternalError)?;
 
-            let tempo: Tempo = serde_json::from_str(&r.tempo)
-                .map_err(|e| AppError::InternalError(format!("Failed to parse tempo: {}", e)))?;
+            let tempo: Tempo = r.tempo.parse()
+                .map_err(AppError::InternalError)?;
 
-            let pressing_intensity: PressingIntensity = serde_json::from_str(&r.pressing_intensity)
-                .map_err(|e| AppError::InternalError(format!("Failed to parse pressing_intensity: {}", e)))?;
+            let pressing_intensity: PressingIntensity = r.pressing_intensity.parse()
+                .map_err(AppError::InternalError)?;
 
-            let defensive_line: DefensiveLine = serde_json::from_str(&r.defensive_line)
-                .map_err(|e| AppError::InternalError(format!("Failed to parse defensive_line: {}", e)))?;
+            let defensive_line: DefensiveLine = r.defensive_line.parse()
+                .map_err(AppError::InternalError)?;
 
             let team_instructions: HashMap<String, bool> = serde_json::from_str(&r.team_instructions)
                 .map_err(|e| AppError::InternalError(format!("Failed to parse team_instructions: {}", e)))?;
@@ -161,17 +165,18 @@ impl TacticsRepository {
                 defensive_line = ?6,
                 team_instructions = ?7,
                 squad_id = ?8
-            WHERE id = ?9
+            WHERE id = ?9 AND workspace_id = ?10
             "#,
             tactics.name,
             tactics.mentality.as_str(),
-            serde_json::to_string(&tactics.width).unwrap(),
-            serde_json::to_string(&tactics.tempo).unwrap(),
-            serde_json::to_string(&tactics.pressing_intensity).unwrap(),
-            serde_json::to_string(&tactics.defensive_line).unwrap(),
+            tactics.width.as_str(),
+            tactics.tempo.as_str(),
+            tactics.pressing_intensity.as_str(),
+            tactics.defensive_line.as_str(),
             team_instructions_json,
             tactics.squad_id,
-            id
+            id,
+            self.workspace_id
         )
         .execute(&self.pool)
         .await?;
@@ -186,9 +191,10 @@ impl TacticsRepository {
     pub async fn delete(&self, id: i64) -> AppResult<()> {
         let result = sqlx::query!(
             r#"
-            DELETE FROM tactics WHERE id = ?1
+            DELETE FROM tactics WHERE id = ?1 AND workspace_id = ?2
             "#,
-            id
+            id,
+            self.workspace_id
         )
         .execute(&self.pool)
         .await?;
@@ -203,9 +209,10 @@ impl TacticsRepository {
     pub async fn get_by_squad(&self, squad_id: i64) -> AppResult<Vec<Tactics>> {
         let rows = sqlx::query!(
             r#"
-            SELECT * FROM tactics WHERE squad_id = ?1 ORDER BY name
+            SELECT * FROM tactics WHERE squad_id = ?1 AND workspace_id = ?2 ORDER BY name
             "#,
-            squad_id
+            squad_id,
+            self.workspace_id
         )
         .fetch_all(&self.pool)
         .await?;
@@ -223,17 +230,17 @@ impl TacticsRepository {
                 _ => return Err(AppError::InternalError(format!("Invalid mentality: {}", r.mentality))),
             };
 
-            let width: Width = serde_json::from_str(&r.width)
-                .map_err(|e| AppError::InternalError(format!("Failed to parse width: {}", e)))?;
+            let width: Width = r.width.parse()
+                .map_err(AppError::InternalError)?;
 
-            let tempo: Tempo = serde_json::from_str(&r.tempo)
-                .map_err(|e| AppError::InternalError(format!("Failed to parse tempo: {}", e)))?;
+            let tempo: Tempo = r.tempo.parse()
+                .map_err(AppError::InternalError)?;
 
-            let pressing_intensity: PressingIntensity = serde_json::from_str(&r.pressing_intensity)
-                .map_err(|e| AppError::InternalError(format!("Failed to parse pressing_intensity: {}", e)))?;
+            let pressing_intensity: PressingIntensity = r.pressing_intensity.parse()
+                .map_err(AppError::InternalError)?;
 
-            let defensive_line: DefensiveLine = serde_json::from_str(&r.defensive_line)
-                .map_err(|e| AppError::InternalError(format!("Failed to parse defensive_line: {}", e)))?;
+            let defensive_line: DefensiveLine = r.defensive_line.parse()
+                .map_err(AppError::InternalError)?;
 
             let team_instructions: HashMap<String, bool> = serde_json::from_str(&r.team_instructions)
                 .map_err(|e| AppError::InternalError(format!("Failed to parse team_instructions: {}", e)))?;
@@ -259,6 +266,9 @@ impl TacticsRepository {
 mod tests {
     use super::*;
 
+    /// The default workspace seeded by the migrations
+    const WORKSPACE: i64 = 1;
+
     async fn setup_test_db() -> SqlitePool {
         let pool = SqlitePool::connect(":memory:").await.unwrap();
         sqlx::migrate!("./migrations").run(&pool).await.unwrap();
@@ -282,7 +292,7 @@ mod tests {
     #[tokio::test]
     async fn test_create_and_get_tactics() {
         let pool = setup_test_db().await;
-        let repo = TacticsRepository::new(pool);
+        let repo = TacticsRepository::new(pool, WORKSPACE);
 
         let tactics = create_test_tactics();
         let id = repo.create(&tactics).await.unwrap();
@@ -299,7 +309,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_all_tactics() {
         let pool = setup_test_db().await;
-        let repo = TacticsRepository::new(pool);
+        let repo = TacticsRepository::new(pool, WORKSPACE);
 
         let tactics1 = create_test_tactics();
         let mut tactics2 = create_test_tactics();
@@ -315,7 +325,7 @@ mod tests {
     #[tokio::test]
     async fn test_update_tactics() {
         let pool = setup_test_db().await;
-        let repo = TacticsRepository::new(pool);
+        let repo = TacticsRepository::new(pool, WORKSPACE);
 
         let tactics = create_test_tactics();
         let id = repo.create(&tactics).await.unwrap();
@@ -334,7 +344,7 @@ mod tests {
     #[tokio::test]
     async fn test_delete_tactics() {
         let pool = setup_test_db().await;
-        let repo = TacticsRepository::new(pool);
+        let repo = TacticsRepository::new(pool, WORKSPACE);
 
         let tactics = create_test_tactics();
         let id = repo.create(&tactics).await.unwrap();
@@ -348,7 +358,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_by_squad() {
         let pool = setup_test_db().await;
-        let repo = TacticsRepository::new(pool);
+        let repo = TacticsRepository::new(pool, WORKSPACE);
 
         let mut tactics1 = create_test_tactics();
         tactics1.squad_id = Some(1);
@@ -373,7 +383,7 @@ mod tests {
     #[tokio::test]
     async fn test_tactics_with_all_settings() {
         let pool = setup_test_db().await;
-        let repo = TacticsRepository::new(pool);
+        let repo = TacticsRepository::new(pool, WORKSPACE);
 
         let mut tactics = create_test_tactics();
         tactics.pressing_intensity = PressingIntensity::MuchMore;
@@ -0,0 +1,504 @@
+use chrono::NaiveDate;
+use sqlx::SqlitePool;
+use shared::models::{GameVersion, LineupConstraints, SquadPlayer, Workspace};
+use crate::error::{AppError, AppResult};
+use std::collections::HashMap;
+
+pub struct WorkspaceRepository {
+    pool: SqlitePool,
+}
+
+impl WorkspaceRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create(&self, workspace: &Workspace) -> AppResult<i64> {
+        let game_version = workspace.game_version.as_str();
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO workspaces (name, game_date, game_version)
+            VALUES (?1, ?2, ?3)
+            "#,
+            workspace.name,
+            workspace.game_date,
+            game_version
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    pub async fn get_by_id(&self, id: i64) -> AppResult<Workspace> {
+        let row = sqlx::query!(
+            r#"
+            SELECT id AS "id!", name, game_date AS "game_date: NaiveDate", game_version
+            FROM workspaces WHERE id = ?1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some(r) => Ok(Workspace {
+                id: Some(r.id),
+                name: r.name,
+                game_date: r.game_date,
+                game_version: parse_game_version(&r.game_version)?,
+            }),
+            None => Err(AppError::NotFound(format!("Workspace with id {} not found", id))),
+        }
+    }
+
+    pub async fn get_all(&self) -> AppResult<Vec<Workspace>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id AS "id!", name, game_date AS "game_date: NaiveDate", game_version
+            FROM workspaces ORDER BY name
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|r| {
+                Ok(Workspace {
+                    id: Some(r.id),
+                    name: r.name,
+                    game_date: r.game_date,
+                    game_version: parse_game_version(&r.game_version)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Rename a workspace and, when given, switch the game version its save comes from
+    pub async fn update(&self, id: i64, name: &str, game_version: Option<GameVersion>) -> AppResult<()> {
+        let game_version = game_version.map(|version| version.as_str());
+        let result = sqlx::query!(
+            r#"
+            UPDATE workspaces
+            SET name = ?1, game_version = COALESCE(?2, game_version), updated_at = CURRENT_TIMESTAMP
+            WHERE id = ?3
+            "#,
+            name,
+            game_version,
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Workspace with id {} not found", id)));
+        }
+
+        Ok(())
+    }
+
+    /// Delete a workspace together with everything that belongs to it
+    pub async fn delete(&self, id: i64) -> AppResult<()> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM workspaces WHERE id = ?1
+            "#,
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Workspace with id {} not found", id)));
+        }
+
+        Ok(())
+    }
+
+    /// Copy a workspace and everything in it under a new name, returning the new workspace's id.
+    /// References between the copied rows (squad players and formations, tactics' squads)
+    /// are pointed at the copies; references to rows that no longer exist are dropped.
+    pub async fn clone_workspace(&self, id: i64, name: &str) -> AppResult<i64> {
+        let mut tx = self.pool.begin().await?;
+
+        let source = sqlx::query!(
+            r#"
+            SELECT game_date AS "game_date: NaiveDate", game_version FROM workspaces WHERE id = ?1
+            "#,
+            id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Workspace with id {} not found", id)))?;
+
+        let new_id = sqlx::query!(
+            r#"
+            INSERT INTO workspaces (name, game_date, game_version)
+            VALUES (?1, ?2, ?3)
+            "#,
+            name,
+            source.game_date,
+            source.game_version
+        )
+        .execute(&mut *tx)
+        .await?
+        .last_insert_rowid();
+
+        // Nothing refers to custom roles or mapping profiles by id, so they are copied wholesale
+        sqlx::query!(
+            r#"
+            INSERT INTO roles (
+                name, position, duty, description, attribute_weights, is_custom, workspace_id,
+                attribute_tiers, weighting, role_type
+            )
+            SELECT name, position, duty, description, attribute_weights, is_custom, ?1,
+                attribute_tiers, weighting, role_type
+            FROM roles WHERE workspace_id = ?2
+            ORDER BY id
+            "#,
+            new_id,
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO mapping_profiles (name, description, columns, is_custom, workspace_id)
+            SELECT name, description, columns, is_custom, ?1
+            FROM mapping_profiles WHERE workspace_id = ?2
+            ORDER BY id
+            "#,
+            new_id,
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let mut formation_ids = HashMap::new();
+        let old_formations = sqlx::query_scalar!(
+            r#"
+            SELECT id AS "id!" FROM formations WHERE workspace_id = ?1 ORDER BY id
+            "#,
+            id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for old_id in old_formations {
+            let copy_id = sqlx::query!(
+                r#"
+                INSERT INTO formations (name, description, positions, is_custom, workspace_id)
+                SELECT name, description, positions, is_custom, ?1 FROM formations WHERE id = ?2
+                "#,
+                new_id,
+                old_id
+            )
+            .execute(&mut *tx)
+            .await?
+            .last_insert_rowid();
+            formation_ids.insert(old_id, copy_id);
+        }
+
+        let mut player_ids = HashMap::new();
+        let old_players = sqlx::query_scalar!(
+            r#"
+            SELECT id AS "id!" FROM players WHERE workspace_id = ?1 ORDER BY id
+            "#,
+            id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for old_id in old_players {
+            let copy_id = sqlx::query!(
+                r#"
+                INSERT INTO players (
+                    workspace_id, fm_uid, date_of_birth, name, age, nationality, position,
+                    corners, crossing, dribbling, finishing, first_touch, free_kick_taking,
+                    heading, long_shots, long_throws, marking, passing, penalty_taking,
+                    tackling, technique,
+                    aggression, anticipation, bravery, composure, concentration, decisions,
+                    determination, flair, leadership, off_the_ball, positioning, teamwork,
+                    vision, work_rate,
+                    acceleration, agility, balance, jumping_reach, natural_fitness, pace,
+                    stamina, strength,
+                    aerial_reach, command_of_area, communication, eccentricity, handling,
+                    kicking, one_on_ones, reflexes, rushing_out, punching, throwing,
+                    value, wage, contract_expiry, attribute_ranges, position_familiarity
+                )
+                SELECT
+                    ?1, fm_uid, date_of_birth, name, age, nationality, position,
+                    corners, crossing, dribbling, finishing, first_touch, free_kick_taking,
+                    heading, long_shots, long_throws, marking, passing, penalty_taking,
+                    tackling, technique,
+                    aggression, anticipation, bravery, composure, concentration, decisions,
+                    determination, flair, leadership, off_the_ball, positioning, teamwork,
+                    vision, work_rate,
+                    acceleration, agility, balance, jumping_reach, natural_fitness, pace,
+                    stamina, strength,
+                    aerial_reach, command_of_area, communication, eccentricity, handling,
+                    kicking, one_on_ones, reflexes, rushing_out, punching, throwing,
+                    value, wage, contract_expiry, attribute_ranges, position_familiarity
+                FROM players WHERE id = ?2
+                "#,
+                new_id,
+                old_id
+            )
+            .execute(&mut *tx)
+            .await?
+            .last_insert_rowid();
+
+            sqlx::query!(
+                r#"
+                INSERT INTO player_attribute_snapshots (player_id, recorded_at, attributes)
+                SELECT ?1, recorded_at, attributes FROM player_attribute_snapshots
+                WHERE player_id = ?2
+                ORDER BY id
+                "#,
+                copy_id,
+                old_id
+            )
+            .execute(&mut *tx)
+            .await?;
+
+            player_ids.insert(old_id, copy_id);
+        }
+
+        let mut squad_ids = HashMap::new();
+        let old_squads = sqlx::query!(
+            r#"
+            SELECT id AS "id!", formation_id, starting_xi, substitutes, constraints FROM squads WHERE workspace_id = ?1 ORDER BY id
+            "#,
+            id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for r in old_squads {
+            let starting_xi: Vec<SquadPlayer> = serde_json::from_str(&r.starting_xi)
+                .map_err(|e| AppError::InternalError(format!("Failed to parse starting_xi: {}", e)))?;
+            let substitutes: Vec<i64> = serde_json::from_str(&r.substitutes)
+                .map_err(|e| AppError::InternalError(format!("Failed to parse substitutes: {}", e)))?;
+            let mut constraints: LineupConstraints = serde_json::from_str(&r.constraints)
+                .map_err(|e| AppError::InternalError(format!("Failed to parse constraints: {}", e)))?;
+
+            let starting_xi: Vec<SquadPlayer> = starting_xi
+                .into_iter()
+                .filter_map(|mut starter| {
+                    starter.player_id = *player_ids.get(&starter.player_id)?;
+                    Some(starter)
+                })
+                .collect();
+            let substitutes: Vec<i64> = substitutes
+                .iter()
+                .filter_map(|player_id| player_ids.get(player_id).copied())
+                .collect();
+            constraints.remap_players(|player_id| player_ids.get(&player_id).copied());
+            // Predefined formations are shared, so only custom ones are remapped
+            let formation_id = formation_ids.get(&r.formation_id).copied().unwrap_or(r.formation_id);
+
+            let starting_xi_json = serde_json::to_string(&starting_xi)
+                .map_err(|e| AppError::InternalError(format!("Failed to serialize starting_xi: {}", e)))?;
+            let substitutes_json = serde_json::to_string(&substitutes)
+                .map_err(|e| AppError::InternalError(format!("Failed to serialize substitutes: {}", e)))?;
+            let constraints_json = serde_json::to_string(&constraints)
+                .map_err(|e| AppError::InternalError(format!("Failed to serialize constraints: {}", e)))?;
+
+            let copy_id = sqlx::query!(
+                r#"
+                INSERT INTO squads (name, formation_id, starting_xi, substitutes, average_rating, total_value, total_wage, workspace_id, constraints)
+                SELECT name, ?1, ?2, ?3, average_rating, total_value, total_wage, ?4, ?6 FROM squads WHERE id = ?5
+                "#,
+                formation_id,
+                starting_xi_json,
+                substitutes_json,
+                new_id,
+                r.id,
+                constraints_json
+            )
+            .execute(&mut *tx)
+            .await?
+            .last_insert_rowid();
+            squad_ids.insert(r.id, copy_id);
+        }
+
+        let old_tactics = sqlx::query!(
+            r#"
+            SELECT id AS "id!", squad_id FROM tactics WHERE workspace_id = ?1 ORDER BY id
+            "#,
+            id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for r in old_tactics {
+            let squad_id = r.squad_id.and_then(|squad_id| squad_ids.get(&squad_id).copied());
+            sqlx::query!(
+                r#"
+                INSERT INTO tactics (
+                    name, mentality, width, tempo, pressing_intensity, defensive_line,
+                    team_instructions, squad_id, workspace_id
+                )
+                SELECT
+                    name, mentality, width, tempo, pressing_intensity, defensive_line,
+                    team_instructions, ?1, ?2
+                FROM tactics WHERE id = ?3
+                "#,
+                squad_id,
+                new_id,
+                r.id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(new_id)
+    }
+}
+
+fn parse_game_version(game_version: &str) -> AppResult<GameVersion> {
+    game_version
+        .parse()
+        .map_err(|e: String| AppError::InternalError(format!("Failed to parse game version: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::{FormationRepository, PlayerRepository, RoleRepository, SquadRepository, TacticsRepository};
+    use shared::models::{ExcludedPlayer, ExclusionReason, Formation, Player, Position, Squad, Tactics};
+
+    /// The default workspace seeded by the migrations
+    const WORKSPACE: i64 = 1;
+
+    async fn setup_test_db() -> SqlitePool {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+        pool
+    }
+
+    fn game_date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2031, 7, 1).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_create_update_and_delete_workspace() {
+        let pool = setup_test_db().await;
+        let repo = WorkspaceRepository::new(pool);
+
+        let id = repo.create(&Workspace::new("Wrexham".to_string(), game_date())).await.unwrap();
+        assert_eq!(repo.get_by_id(id).await.unwrap().game_version, GameVersion::FM24);
+        repo.update(id, "Wrexham 2031", Some(GameVersion::FM23)).await.unwrap();
+        repo.update(id, "Wrexham 2031", None).await.unwrap();
+
+        let retrieved = repo.get_by_id(id).await.unwrap();
+        assert_eq!(retrieved.name, "Wrexham 2031");
+        assert_eq!(retrieved.game_date, game_date());
+        assert_eq!(retrieved.game_version, GameVersion::FM23);
+        assert_eq!(repo.get_all().await.unwrap().len(), 2);
+
+        // Names are unique
+        let duplicate = Workspace::new("Wrexham 2031".to_string(), game_date());
+        assert!(matches!(repo.create(&duplicate).await, Err(AppError::Conflict(_))));
+
+        repo.delete(id).await.unwrap();
+        assert!(matches!(repo.get_by_id(id).await, Err(AppError::NotFound(_))));
+        assert!(repo.delete(id).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_workspaces_are_isolated() {
+        let pool = setup_test_db().await;
+        let repo = WorkspaceRepository::new(pool.clone());
+        let other = repo.create(&Workspace::new("Other Save".to_string(), game_date())).await.unwrap();
+
+        let players = PlayerRepository::new(pool.clone(), WORKSPACE);
+        let id = players.create(&Player::new("Home Player".to_string(), 24, Position::MC)).await.unwrap();
+
+        let other_players = PlayerRepository::new(pool.clone(), other);
+        assert!(other_players.get_all().await.unwrap().is_empty());
+        assert!(matches!(other_players.get_by_id(id).await, Err(AppError::NotFound(_))));
+        assert!(other_players.delete(id).await.is_err());
+
+        // Predefined roles are shared, custom ones are not
+        let roles = RoleRepository::new(pool.clone(), WORKSPACE);
+        let other_roles = RoleRepository::new(pool.clone(), other);
+        let predefined = other_roles.get_all().await.unwrap();
+        assert!(!predefined.is_empty());
+        let mut custom = predefined[0].clone();
+        custom.name = "House Role".to_string();
+        custom.is_custom = true;
+        let custom_id = roles.create(&custom).await.unwrap();
+        assert!(other_roles.get_by_id(custom_id).await.is_err());
+        assert_eq!(other_roles.get_all().await.unwrap().len(), predefined.len());
+
+        // The same name can be reused in another workspace, and predefined rows are read-only
+        other_roles.create(&custom).await.unwrap();
+        let predefined_id = predefined[0].id.unwrap();
+        assert!(matches!(roles.delete(predefined_id).await, Err(AppError::NotFound(_))));
+
+        // Deleting a workspace takes its data with it
+        repo.delete(WORKSPACE).await.unwrap();
+        assert!(PlayerRepository::new(pool.clone(), other).get_all().await.unwrap().is_empty());
+        assert!(other_roles.get_by_id(predefined_id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_clone_workspace() {
+        let pool = setup_test_db().await;
+        let repo = WorkspaceRepository::new(pool.clone());
+
+        let players = PlayerRepository::new(pool.clone(), WORKSPACE);
+        let keeper = players.create(&Player::new("Keeper".to_string(), 30, Position::GK)).await.unwrap();
+        let sub = players.create(&Player::new("Sub".to_string(), 19, Position::MC)).await.unwrap();
+
+        let formations = FormationRepository::new(pool.clone(), WORKSPACE);
+        let mut formation = Formation::new("House 4-4-2".to_string(), Vec::new());
+        formation.is_custom = true;
+        let formation_id = formations.create(&formation).await.unwrap();
+
+        let mut squad = Squad::new("First Team".to_string(), formation_id);
+        squad.add_starter(SquadPlayer {
+            player_id: keeper,
+            position: Position::GK,
+            tactical_role: None,
+            suitability: None,
+        });
+        squad.add_substitute(sub);
+        squad.constraints.excluded.push(ExcludedPlayer { player_id: sub, reason: ExclusionReason::Injured });
+        let squad_id = SquadRepository::new(pool.clone(), WORKSPACE).create(&squad).await.unwrap();
+        TacticsRepository::new(pool.clone(), WORKSPACE)
+            .create(&Tactics::new_for_squad("Gegenpress".to_string(), squad_id))
+            .await
+            .unwrap();
+
+        let copy = repo.clone_workspace(WORKSPACE, "Default (copy)").await.unwrap();
+        assert_eq!(repo.get_by_id(copy).await.unwrap().name, "Default (copy)");
+
+        let copied_players = PlayerRepository::new(pool.clone(), copy).get_all().await.unwrap();
+        assert_eq!(copied_players.len(), 2);
+        let copied_keeper = copied_players.iter().find(|p| p.name == "Keeper").unwrap();
+        assert_ne!(copied_keeper.id, Some(keeper));
+
+        let copied_squads = SquadRepository::new(pool.clone(), copy).get_all().await.unwrap();
+        assert_eq!(copied_squads.len(), 1);
+        assert_eq!(copied_squads[0].starting_xi[0].player_id, copied_keeper.id.unwrap());
+        let copied_sub = copied_players.iter().find(|p| p.name == "Sub").unwrap();
+        assert_eq!(copied_squads[0].constraints.excluded[0].player_id, copied_sub.id.unwrap());
+        assert_ne!(copied_squads[0].formation_id, formation_id);
+        let copied_formation = FormationRepository::new(pool.clone(), copy)
+            .get_by_id(copied_squads[0].formation_id)
+            .await
+            .unwrap();
+        assert_eq!(copied_formation.name, "House 4-4-2");
+
+        let copied_tactics = TacticsRepository::new(pool.clone(), copy).get_all().await.unwrap();
+        assert_eq!(copied_tactics[0].squad_id, copied_squads[0].id);
+
+        // The copy is independent of the original
+        repo.delete(WORKSPACE).await.unwrap();
+        assert_eq!(PlayerRepository::new(pool, copy).get_all().await.unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,361 @@
+//! Substitute bench selection.
+//!
+//! A slot's cover is the best suited substitute for its role, and a bench is judged by its
+//! worst covered slot. For a given suitability, a breadth-first search over which slots are
+//! covered finds the fewest substitutes who reach it everywhere, backup goalkeeper included;
+//! formations have eleven slots, so there are few enough sets of slots to search them all.
+//! The bench takes the highest suitability that fits in it, and any places left go to
+//! whoever improves the cover most. Players are considered in id order, so identical inputs
+//! always produce the same bench.
+
+use crate::models::{Bench, DepthChartPlayer, Familiarity, Formation, Player, Position, Role, SlotCover, Squad};
+use crate::scoring::{score_role, ScoringOptions};
+use crate::selection::check_roles;
+
+/// Bench sizes competitions allow
+pub const BENCH_SIZES: [usize; 2] = [7, 9];
+
+/// Bench size when none is asked for
+pub const DEFAULT_BENCH_SIZE: usize = 9;
+
+/// Whether a player can be the backup goalkeeper
+fn is_goalkeeper(player: &Player) -> bool {
+    player.plays(Position::GK, Familiarity::Competent)
+}
+
+/// Pick `bench_size` substitutes for a squad's starting XI. `roles` holds the role scored for
+/// each of the formation's slots, in order: those of `Squad::formation_as_picked`, so each
+/// slot is covered for the role its starter plays. Starters are matched to slots with
+/// `Squad::starters_by_slot`. Excluded players are left out; a goalkeeper is always picked.
+pub fn select_bench(
+    squad: &Squad,
+    formation: &Formation,
+    roles: &[Role],
+    players: &[Player],
+    options: &ScoringOptions,
+    bench_size: usize,
+    cover_threshold: f32,
+) -> Result<Bench, String> {
+    if !BENCH_SIZES.contains(&bench_size) {
+        return Err(format!("A bench has 7 or 9 substitutes, not {}", bench_size));
+    }
+    check_roles(formation, roles)?;
+
+    let starters = squad.starters_by_slot(formation)?;
+
+    let mut candidates: Vec<&Player> = players
+        .iter()
+        .filter(|player| {
+            let id = player.id.unwrap_or_default();
+            !squad.starting_xi.iter().any(|starter| starter.player_id == id) && squad.constraints.is_available(id)
+        })
+        .collect();
+    candidates.sort_by_key(|player| player.id);
+    let goalkeepers: Vec<bool> = candidates.iter().map(|player| is_goalkeeper(player)).collect();
+    if !goalkeepers.contains(&true) {
+        return Err("No goalkeeper outside the starting XI is available for the bench".to_string());
+    }
+
+    // scores[candidate][slot]; None when the player's score is not confident enough
+    let scores: Vec<Vec<Option<f32>>> = candidates
+        .iter()
+        .map(|player| {
+            roles
+                .iter()
+                .map(|role| {
+                    let suitability = score_role(player, role, options);
+                    options.accepts(&suitability).then_some(suitability.percentage)
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut picked = best_worst_case_cover(&scores, &goalkeepers, bench_size);
+    if !picked.iter().any(|candidate| goalkeepers[*candidate]) {
+        if let Some(keeper) = most_improving(&scores, &picked, |candidate| goalkeepers[candidate]) {
+            picked.push(keeper);
+        }
+    }
+    while picked.len() < bench_size {
+        match most_improving(&scores, &picked, |_| true) {
+            Some(candidate) => picked.push(candidate),
+            None => break,
+        }
+    }
+
+    let coverage: Vec<SlotCover> = formation
+        .positions
+        .iter()
+        .zip(&starters)
+        .enumerate()
+        .map(|(slot, (position, starter))| {
+            let substitute = picked
+                .iter()
+                .filter_map(|candidate| Some((candidates[*candidate], scores[*candidate][slot]?)))
+                .fold(None, |best: Option<(&Player, f32)>, (player, score)| match best {
+                    Some((best_player, best_score))
+                        if best_score > score || (best_score == score && best_player.id < player.id) =>
+                    {
+                        best
+                    }
+                    _ => Some((player, score)),
+                })
+                .map(|(player, suitability)| DepthChartPlayer {
+                    player_id: player.id.unwrap_or_default(),
+                    suitability,
+                });
+            SlotCover {
+                position: position.position,
+                tactical_role: starter.tactical_role.unwrap_or_else(|| position.tactical_role_or_default()),
+                starter_id: starter.player_id,
+                adequate: substitute.as_ref().is_some_and(|cover| cover.suitability >= cover_threshold),
+                substitute,
+            }
+        })
+        .collect();
+
+    Ok(Bench {
+        substitutes: picked.iter().map(|candidate| candidates[*candidate].id.unwrap_or_default()).collect(),
+        uncovered_starters: coverage
+            .iter()
+            .filter(|cover| !cover.adequate)
+            .map(|cover| cover.starter_id)
+            .collect(),
+        coverage,
+    })
+}
+
+/// The candidates reaching the highest suitability in every slot anyone can cover, with a
+/// goalkeeper among them, using at most `limit` of them. Empty when even the lowest
+/// suitability needs more.
+fn best_worst_case_cover(scores: &[Vec<Option<f32>>], goalkeepers: &[bool], limit: usize) -> Vec<usize> {
+    let slots = scores.first().map_or(0, Vec::len);
+    let coverable = (0..slots)
+        .filter(|slot| scores.iter().any(|row| row[*slot].is_some()))
+        .fold(0usize, |mask, slot| mask | 1 << slot);
+
+    let mut thresholds: Vec<f32> = scores.iter().flatten().flatten().copied().collect();
+    thresholds.sort_by(f32::total_cmp);
+    thresholds.dedup();
+
+    let cover_at = |threshold: f32| {
+        let covers: Vec<usize> = scores
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(_, score)| score.is_some_and(|score| score >= threshold))
+                    .fold(0, |mask, (slot, _)| mask | 1 << slot)
+            })
+            .collect();
+        smallest_cover(&covers, goalkeepers, coverable, limit)
+    };
+
+    // Reaching a suitability everywhere gets harder as it rises, so binary search for the
+    // highest one that still fits
+    let (mut low, mut high) = (0, thresholds.len());
+    let mut best = Vec::new();
+    while low < high {
+        let middle = (low + high) / 2;
+        match cover_at(thresholds[middle]) {
+            Some(cover) => {
+                best = cover;
+                low = middle + 1;
+            }
+            None => high = middle,
+        }
+    }
+    best
+}
+
+/// The fewest candidates who between them cover every slot in `required`, one of them a
+/// goalkeeper, or `None` when that takes more than `limit`. `covers` holds the slots each
+/// candidate covers as a bit mask.
+fn smallest_cover(covers: &[usize], goalkeepers: &[bool], required: usize, limit: usize) -> Option<Vec<usize>> {
+    // A state is the slots covered so far and whether a goalkeeper is picked. Searching
+    // breadth-first reaches each state with as few candidates as possible.
+    let state = |covered: usize, keeper: bool| covered * 2 + keeper as usize;
+    let goal = state(required, true);
+    let mut reached_from: Vec<Option<(usize, usize)>> = vec![None; goal + 1];
+    let mut frontier = vec![state(0, false)];
+
+    for _ in 0..limit {
+        let mut next = Vec::new();
+        for &current in &frontier {
+            let (covered, keeper) = (current / 2, current % 2 == 1);
+            for (candidate, cover) in covers.iter().enumerate() {
+                let reached = state(covered | (cover & required), keeper || goalkeepers[candidate]);
+                if reached != state(0, false) && reached_from[reached].is_none() {
+                    reached_from[reached] = Some((current, candidate));
+                    next.push(reached);
+                }
+            }
+        }
+
+        if reached_from[goal].is_some() {
+            let mut picked = Vec::new();
+            let mut current = goal;
+            while let Some((previous, candidate)) = reached_from[current] {
+                picked.push(candidate);
+                current = previous;
+            }
+            picked.reverse();
+            return Some(picked);
+        }
+        frontier = next;
+    }
+    None
+}
+
+/// The candidate allowed by `eligible` who most raises the best cover across slots, ties
+/// going to the better single fit and then the earlier candidate
+fn most_improving(scores: &[Vec<Option<f32>>], picked: &[usize], eligible: impl Fn(usize) -> bool) -> Option<usize> {
+    let slots = scores.first().map_or(0, Vec::len);
+    let covered: Vec<f32> = (0..slots)
+        .map(|slot| {
+            picked
+                .iter()
+                .filter_map(|candidate| scores[*candidate][slot])
+                .fold(0.0, f32::max)
+        })
+        .collect();
+
+    let mut best: Option<(usize, f32, f32)> = None;
+    for (candidate, row) in scores.iter().enumerate() {
+        if picked.contains(&candidate) || !eligible(candidate) {
+            continue;
+        }
+        let gain: f32 = row
+            .iter()
+            .zip(&covered)
+            .filter_map(|(score, covered)| score.map(|score| (score - covered).max(0.0)))
+            .sum();
+        let fit = row.iter().flatten().copied().fold(0.0, f32::max);
+        if best.is_none_or(|(_, best_gain, best_fit)| gain > best_gain || (gain == best_gain && fit > best_fit)) {
+            best = Some((candidate, gain, fit));
+        }
+    }
+    best.map(|(candidate, _, _)| candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::models::{Attribute, Duty, ExcludedPlayer, ExclusionReason, RoleType, SquadPlayer, TacticalRole};
+
+    fn formation() -> Formation {
+        fixtures::formation("Three-a-side", &[Position::GK, Position::DC, Position::STC])
+    }
+
+    fn roles() -> Vec<Role> {
+        [(Position::GK, Attribute::Reflexes), (Position::DC, Attribute::Tackling), (Position::STC, Attribute::Finishing)]
+            .into_iter()
+            .map(|(position, attribute)| fixtures::role(position.as_str(), position, &[(attribute, 1.0)]))
+            .collect()
+    }
+
+    /// Good at the attribute their position's role weighs and hopeless at the others
+    fn player(id: i64, position: Position, attribute: Attribute, value: i32) -> Player {
+        let attributes: Vec<(Attribute, i32)> = [Attribute::Reflexes, Attribute::Tackling, Attribute::Finishing]
+            .into_iter()
+            .map(|known| (known, if known == attribute { value } else { 1 }))
+            .collect();
+        fixtures::player(id, &format!("Player {}", id), position, &attributes)
+    }
+
+    /// Starters 1-3, two keepers, a defender and eight strikers who are all better than them
+    fn players() -> Vec<Player> {
+        let mut players = vec![
+            player(1, Position::GK, Attribute::Reflexes, 18),
+            player(2, Position::DC, Attribute::Tackling, 18),
+            player(3, Position::STC, Attribute::Finishing, 18),
+            player(4, Position::GK, Attribute::Reflexes, 10),
+            player(5, Position::GK, Attribute::Reflexes, 14),
+            player(6, Position::DC, Attribute::Tackling, 12),
+        ];
+        players.extend((10..18).map(|id| player(id, Position::STC, Attribute::Finishing, 17)));
+        players
+    }
+
+    fn squad() -> Squad {
+        let mut squad = Squad::new("First Team".to_string(), 1);
+        for (id, position) in [(1, Position::GK), (2, Position::DC), (3, Position::STC)] {
+            squad.add_starter(SquadPlayer { player_id: id, position, tactical_role: None, suitability: None });
+        }
+        squad
+    }
+
+    #[test]
+    fn test_worst_case_beats_greedy() {
+        // Taking the best player first leaves the last two slots at 40; the two all-rounders
+        // cover all four slots at 70
+        let scores = vec![
+            vec![Some(100.0), Some(100.0), None, None],
+            vec![None, None, Some(40.0), Some(40.0)],
+            vec![Some(70.0), None, Some(70.0), None],
+            vec![None, Some(70.0), None, Some(70.0)],
+        ];
+        assert_eq!(best_worst_case_cover(&scores, &[false, false, true, false], 2), vec![2, 3]);
+        assert_eq!(best_worst_case_cover(&scores, &[false; 4], 2), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_bench_covers_every_slot() {
+        let bench = select_bench(&squad(), &formation(), &roles(), &players(), &ScoringOptions::default(), 7, 50.0).unwrap();
+        assert_eq!(bench.substitutes.len(), 7);
+        assert!(bench.substitutes.contains(&5));
+        assert!(bench.substitutes.contains(&6));
+        assert!(!bench.substitutes.contains(&1));
+
+        let cover: Vec<(Option<i64>, bool)> = bench
+            .coverage
+            .iter()
+            .map(|slot| (slot.substitute.as_ref().map(|cover| cover.player_id), slot.adequate))
+            .collect();
+        assert_eq!(cover, vec![(Some(5), true), (Some(6), true), (Some(10), true)]);
+        assert!(bench.uncovered_starters.is_empty());
+    }
+
+    #[test]
+    fn test_uncovered_starters() {
+        let bench = select_bench(&squad(), &formation(), &roles(), &players(), &ScoringOptions::default(), 9, 65.0).unwrap();
+        assert_eq!(bench.substitutes.len(), 9);
+        // The best backup defender only reaches 60%
+        assert_eq!(bench.uncovered_starters, vec![2]);
+    }
+
+    #[test]
+    fn test_starters_matched_to_slots() {
+        // Listed striker first, with the defender picked as a ball-playing defender
+        let mut squad = squad();
+        squad.starting_xi.rotate_left(2);
+        let defender = TacticalRole { position: Position::DC, role: RoleType::BallPlayingDefender, duty: Duty::Defend };
+        squad.starting_xi[2].tactical_role = Some(defender);
+
+        let bench = select_bench(&squad, &formation(), &roles(), &players(), &ScoringOptions::default(), 9, 65.0).unwrap();
+        let starters: Vec<i64> = bench.coverage.iter().map(|slot| slot.starter_id).collect();
+        assert_eq!(starters, vec![1, 2, 3]);
+        assert_eq!(bench.coverage[1].tactical_role, defender);
+        assert_eq!(bench.uncovered_starters, vec![2]);
+        assert_eq!(squad.formation_as_picked(&formation()).unwrap().positions[1].tactical_role, Some(defender));
+
+        // An XI that doesn't fill the formation is rejected rather than guessed at
+        squad.starting_xi.pop();
+        assert!(select_bench(&squad, &formation(), &roles(), &players(), &ScoringOptions::default(), 9, 65.0).is_err());
+        // Two keepers and no defender
+        squad.add_starter(SquadPlayer { player_id: 4, position: Position::GK, tactical_role: None, suitability: None });
+        assert!(squad.starters_by_slot(&formation()).is_err());
+    }
+
+    #[test]
+    fn test_bench_needs_a_goalkeeper() {
+        let mut squad = squad();
+        squad.constraints.excluded = [4, 5]
+            .into_iter()
+            .map(|player_id| ExcludedPlayer { player_id, reason: ExclusionReason::Injured })
+            .collect();
+        assert!(select_bench(&squad, &formation(), &roles(), &players(), &ScoringOptions::default(), 7, 50.0).is_err());
+        assert!(select_bench(&self::squad(), &formation(), &roles(), &players(), &ScoringOptions::default(), 8, 50.0).is_err());
+    }
+}
@@ -0,0 +1,55 @@
+//! Formations, roles and players for the selection tests.
+
+use std::collections::HashMap;
+use crate::models::{Attribute, Formation, FormationPosition, Player, Position, Role, TacticalRole};
+
+pub fn slot(position: Position) -> FormationPosition {
+    FormationPosition { position, x: 50.0, y: 50.0, tactical_role: None }
+}
+
+/// A formation with a slot at each position, in order
+pub fn formation(name: &str, positions: &[Position]) -> Formation {
+    Formation::new(name.to_string(), positions.iter().copied().map(slot).collect())
+}
+
+/// A role for the position's default tactical role, weighted by hand
+pub fn role(name: &str, position: Position, weights: &[(Attribute, f32)]) -> Role {
+    let default = TacticalRole::default_for(position);
+    Role::new(name.to_string(), default.role, position, default.duty, weights.iter().copied().collect::<HashMap<_, _>>())
+}
+
+/// A 25-year-old with the given attributes and nothing else known
+pub fn player(id: i64, name: &str, position: Position, attributes: &[(Attribute, i32)]) -> Player {
+    let mut player = Player::new(name.to_string(), 25, position);
+    player.id = Some(id);
+    for (attribute, value) in attributes {
+        *attribute.value_mut(&mut player) = Some(*value);
+    }
+    player
+}
+
+/// A keeper and a striker, each role weighing a single attribute
+pub fn two_a_side() -> (Formation, Vec<Role>) {
+    let formation = formation("Two-a-side", &[Position::GK, Position::STC]);
+    let roles = vec![
+        role("Goalkeeper", Position::GK, &[(Attribute::Reflexes, 1.0)]),
+        role("Advanced Forward", Position::STC, &[(Attribute::Finishing, 1.0)]),
+    ];
+    (formation, roles)
+}
+
+/// Players for `two_a_side`: a keeper, a striker and a weaker striker, worth a million per id
+pub fn players() -> Vec<Player> {
+    [
+        (1, "Keeper", Position::GK, 16, 4),
+        (2, "Striker", Position::STC, 5, 15),
+        (3, "Sub", Position::STC, 2, 12),
+    ]
+    .into_iter()
+    .map(|(id, name, position, reflexes, finishing)| {
+        let mut player = player(id, name, position, &[(Attribute::Reflexes, reflexes), (Attribute::Finishing, finishing)]);
+        player.value = Some(id * 1_000_000);
+        player
+    })
+    .collect()
+}
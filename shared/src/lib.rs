@@ -1,3 +1,10 @@
 pub mod models;
+pub mod scoring;
+pub mod selection;
+pub mod bench;
+pub mod recruitment;
+
+#[cfg(test)]
+mod fixtures;
 
 pub use models::*;
@@ -0,0 +1,579 @@
+use serde::{Deserialize, Serialize};
+use super::player::Player;
+use crate::scoring::{MAX_ATTRIBUTE_VALUE, MIN_ATTRIBUTE_VALUE};
+
+/// Which of FM's attribute groups an attribute belongs to
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum AttributeCategory {
+    Technical,
+    Mental,
+    Physical,
+    Goalkeeping,
+}
+
+/// One of the 1-20 attributes on `Player`.
+/// Serialized as the `Player` field name, e.g. `"jumping_reach"`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum Attribute {
+    // Technical
+    Corners,
+    Crossing,
+    Dribbling,
+    Finishing,
+    FirstTouch,
+    FreeKickTaking,
+    Heading,
+    LongShots,
+    LongThrows,
+    Marking,
+    Passing,
+    PenaltyTaking,
+    Tackling,
+    Technique,
+    // Mental
+    Aggression,
+    Anticipation,
+    Bravery,
+    Composure,
+    Concentration,
+    Decisions,
+    Determination,
+    Flair,
+    Leadership,
+    OffTheBall,
+    Positioning,
+    Teamwork,
+    Vision,
+    WorkRate,
+    // Physical
+    Acceleration,
+    Agility,
+    Balance,
+    JumpingReach,
+    NaturalFitness,
+    Pace,
+    Stamina,
+    Strength,
+    // Goalkeeping
+    AerialReach,
+    CommandOfArea,
+    Communication,
+    Eccentricity,
+    Handling,
+    Kicking,
+    OneOnOnes,
+    Reflexes,
+    RushingOut,
+    Punching,
+    Throwing,
+}
+
+impl Attribute {
+    /// Every attribute, in `Player` declaration order
+    pub const ALL: [Attribute; 47] = [
+        // Technical
+        Attribute::Corners,
+        Attribute::Crossing,
+        Attribute::Dribbling,
+        Attribute::Finishing,
+        Attribute::FirstTouch,
+        Attribute::FreeKickTaking,
+        Attribute::Heading,
+        Attribute::LongShots,
+        Attribute::LongThrows,
+        Attribute::Marking,
+        Attribute::Passing,
+        Attribute::PenaltyTaking,
+        Attribute::Tackling,
+        Attribute::Technique,
+        // Mental
+        Attribute::Aggression,
+        Attribute::Anticipation,
+        Attribute::Bravery,
+        Attribute::Composure,
+        Attribute::Concentration,
+        Attribute::Decisions,
+        Attribute::Determination,
+        Attribute::Flair,
+        Attribute::Leadership,
+        Attribute::OffTheBall,
+        Attribute::Positioning,
+        Attribute::Teamwork,
+        Attribute::Vision,
+        Attribute::WorkRate,
+        // Physical
+        Attribute::Acceleration,
+        Attribute::Agility,
+        Attribute::Balance,
+        Attribute::JumpingReach,
+        Attribute::NaturalFitness,
+        Attribute::Pace,
+        Attribute::Stamina,
+        Attribute::Strength,
+        // Goalkeeping
+        Attribute::AerialReach,
+        Attribute::CommandOfArea,
+        Attribute::Communication,
+        Attribute::Eccentricity,
+        Attribute::Handling,
+        Attribute::Kicking,
+        Attribute::OneOnOnes,
+        Attribute::Reflexes,
+        Attribute::RushingOut,
+        Attribute::Punching,
+        Attribute::Throwing,
+    ];
+
+    /// The `Player` field name, which is also the serialized form
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Attribute::Corners => "corners",
+            Attribute::Crossing => "crossing",
+            Attribute::Dribbling => "dribbling",
+            Attribute::Finishing => "finishing",
+            Attribute::FirstTouch => "first_touch",
+            Attribute::FreeKickTaking => "free_kick_taking",
+            Attribute::Heading => "heading",
+            Attribute::LongShots => "long_shots",
+            Attribute::LongThrows => "long_throws",
+            Attribute::Marking => "marking",
+            Attribute::Passing => "passing",
+            Attribute::PenaltyTaking => "penalty_taking",
+            Attribute::Tackling => "tackling",
+            Attribute::Technique => "technique",
+            Attribute::Aggression => "aggression",
+            Attribute::Anticipation => "anticipation",
+            Attribute::Bravery => "bravery",
+            Attribute::Composure => "composure",
+            Attribute::Concentration => "concentration",
+            Attribute::Decisions => "decisions",
+            Attribute::Determination => "determination",
+            Attribute::Flair => "flair",
+            Attribute::Leadership => "leadership",
+            Attribute::OffTheBall => "off_the_ball",
+            Attribute::Positioning => "positioning",
+            Attribute::Teamwork => "teamwork",
+            Attribute::Vision => "vision",
+            Attribute::WorkRate => "work_rate",
+            Attribute::Acceleration => "acceleration",
+            Attribute::Agility => "agility",
+            Attribute::Balance => "balance",
+            Attribute::JumpingReach => "jumping_reach",
+            Attribute::NaturalFitness => "natural_fitness",
+            Attribute::Pace => "pace",
+            Attribute::Stamina => "stamina",
+            Attribute::Strength => "strength",
+            Attribute::AerialReach => "aerial_reach",
+            Attribute::CommandOfArea => "command_of_area",
+            Attribute::Communication => "communication",
+            Attribute::Eccentricity => "eccentricity",
+            Attribute::Handling => "handling",
+            Attribute::Kicking => "kicking",
+            Attribute::OneOnOnes => "one_on_ones",
+            Attribute::Reflexes => "reflexes",
+            Attribute::RushingOut => "rushing_out",
+            Attribute::Punching => "punching",
+            Attribute::Throwing => "throwing",
+        }
+    }
+
+    /// Name as FM shows it on the player profile
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Attribute::Corners => "Corners",
+            Attribute::Crossing => "Crossing",
+            Attribute::Dribbling => "Dribbling",
+            Attribute::Finishing => "Finishing",
+            Attribute::FirstTouch => "First Touch",
+            Attribute::FreeKickTaking => "Free Kick Taking",
+            Attribute::Heading => "Heading",
+            Attribute::LongShots => "Long Shots",
+            Attribute::LongThrows => "Long Throws",
+            Attribute::Marking => "Marking",
+            Attribute::Passing => "Passing",
+            Attribute::PenaltyTaking => "Penalty Taking",
+            Attribute::Tackling => "Tackling",
+            Attribute::Technique => "Technique",
+            Attribute::Aggression => "Aggression",
+            Attribute::Anticipation => "Anticipation",
+            Attribute::Bravery => "Bravery",
+            Attribute::Composure => "Composure",
+            Attribute::Concentration => "Concentration",
+            Attribute::Decisions => "Decisions",
+            Attribute::Determination => "Determination",
+            Attribute::Flair => "Flair",
+            Attribute::Leadership => "Leadership",
+            Attribute::OffTheBall => "Off the Ball",
+            Attribute::Positioning => "Positioning",
+            Attribute::Teamwork => "Teamwork",
+            Attribute::Vision => "Vision",
+            Attribute::WorkRate => "Work Rate",
+            Attribute::Acceleration => "Acceleration",
+            Attribute::Agility => "Agility",
+            Attribute::Balance => "Balance",
+            Attribute::JumpingReach => "Jumping Reach",
+            Attribute::NaturalFitness => "Natural Fitness",
+            Attribute::Pace => "Pace",
+            Attribute::Stamina => "Stamina",
+            Attribute::Strength => "Strength",
+            Attribute::AerialReach => "Aerial Reach",
+            Attribute::CommandOfArea => "Command of Area",
+            Attribute::Communication => "Communication",
+            Attribute::Eccentricity => "Eccentricity",
+            Attribute::Handling => "Handling",
+            Attribute::Kicking => "Kicking",
+            Attribute::OneOnOnes => "One on Ones",
+            Attribute::Reflexes => "Reflexes",
+            Attribute::RushingOut => "Rushing Out (Tendency)",
+            Attribute::Punching => "Punching (Tendency)",
+            Attribute::Throwing => "Throwing",
+        }
+    }
+
+    /// FM's column abbreviation, as used in its table views and text exports
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            Attribute::Corners => "Cor",
+            Attribute::Crossing => "Cro",
+            Attribute::Dribbling => "Dri",
+            Attribute::Finishing => "Fin",
+            Attribute::FirstTouch => "Fir",
+            Attribute::FreeKickTaking => "Fre",
+            Attribute::Heading => "Hea",
+            Attribute::LongShots => "Lon",
+            Attribute::LongThrows => "L Th",
+            Attribute::Marking => "Mar",
+            Attribute::Passing => "Pas",
+            Attribute::PenaltyTaking => "Pen",
+            Attribute::Tackling => "Tck",
+            Attribute::Technique => "Tec",
+            Attribute::Aggression => "Agg",
+            Attribute::Anticipation => "Ant",
+            Attribute::Bravery => "Bra",
+            Attribute::Composure => "Cmp",
+            Attribute::Concentration => "Cnt",
+            Attribute::Decisions => "Dec",
+            Attribute::Determination => "Det",
+            Attribute::Flair => "Fla",
+            Attribute::Leadership => "Ldr",
+            Attribute::OffTheBall => "OtB",
+            Attribute::Positioning => "Pos",
+            Attribute::Teamwork => "Tea",
+            Attribute::Vision => "Vis",
+            Attribute::WorkRate => "Wor",
+            Attribute::Acceleration => "Acc",
+            Attribute::Agility => "Agi",
+            Attribute::Balance => "Bal",
+            Attribute::JumpingReach => "Jum",
+            Attribute::NaturalFitness => "Nat",
+            Attribute::Pace => "Pac",
+            Attribute::Stamina => "Sta",
+            Attribute::Strength => "Str",
+            Attribute::AerialReach => "Aer",
+            Attribute::CommandOfArea => "Cmd",
+            Attribute::Communication => "Com",
+            Attribute::Eccentricity => "Ecc",
+            Attribute::Handling => "Han",
+            Attribute::Kicking => "Kic",
+            Attribute::OneOnOnes => "1v1",
+            Attribute::Reflexes => "Ref",
+            Attribute::RushingOut => "TRO",
+            Attribute::Punching => "Pun",
+            Attribute::Throwing => "Thr",
+        }
+    }
+
+    /// The attribute group it is listed under in FM
+    pub fn category(&self) -> AttributeCategory {
+        match self {
+            Attribute::Corners
+            | Attribute::Crossing
+            | Attribute::Dribbling
+            | Attribute::Finishing
+            | Attribute::FirstTouch
+            | Attribute::FreeKickTaking
+            | Attribute::Heading
+            | Attribute::LongShots
+            | Attribute::LongThrows
+            | Attribute::Marking
+            | Attribute::Passing
+            | Attribute::PenaltyTaking
+            | Attribute::Tackling
+            | Attribute::Technique => AttributeCategory::Technical,
+            Attribute::Aggression
+            | Attribute::Anticipation
+            | Attribute::Bravery
+            | Attribute::Composure
+            | Attribute::Concentration
+            | Attribute::Decisions
+            | Attribute::Determination
+            | Attribute::Flair
+            | Attribute::Leadership
+            | Attribute::OffTheBall
+            | Attribute::Positioning
+            | Attribute::Teamwork
+            | Attribute::Vision
+            | Attribute::WorkRate => AttributeCategory::Mental,
+            Attribute::Acceleration
+            | Attribute::Agility
+            | Attribute::Balance
+            | Attribute::JumpingReach
+            | Attribute::NaturalFitness
+            | Attribute::Pace
+            | Attribute::Stamina
+            | Attribute::Strength => AttributeCategory::Physical,
+            Attribute::AerialReach
+            | Attribute::CommandOfArea
+            | Attribute::Communication
+            | Attribute::Eccentricity
+            | Attribute::Handling
+            | Attribute::Kicking
+            | Attribute::OneOnOnes
+            | Attribute::Reflexes
+            | Attribute::RushingOut
+            | Attribute::Punching
+            | Attribute::Throwing => AttributeCategory::Goalkeeping,
+        }
+    }
+
+    /// The player's value for this attribute, if known
+    pub fn value(&self, player: &Player) -> Option<i32> {
+        match self {
+            Attribute::Corners => player.corners,
+            Attribute::Crossing => player.crossing,
+            Attribute::Dribbling => player.dribbling,
+            Attribute::Finishing => player.finishing,
+            Attribute::FirstTouch => player.first_touch,
+            Attribute::FreeKickTaking => player.free_kick_taking,
+            Attribute::Heading => player.heading,
+            Attribute::LongShots => player.long_shots,
+            Attribute::LongThrows => player.long_throws,
+            Attribute::Marking => player.marking,
+            Attribute::Passing => player.passing,
+            Attribute::PenaltyTaking => player.penalty_taking,
+            Attribute::Tackling => player.tackling,
+            Attribute::Technique => player.technique,
+            Attribute::Aggression => player.aggression,
+            Attribute::Anticipation => player.anticipation,
+            Attribute::Bravery => player.bravery,
+            Attribute::Composure => player.composure,
+            Attribute::Concentration => player.concentration,
+            Attribute::Decisions => player.decisions,
+            Attribute::Determination => player.determination,
+            Attribute::Flair => player.flair,
+            Attribute::Leadership => player.leadership,
+            Attribute::OffTheBall => player.off_the_ball,
+            Attribute::Positioning => player.positioning,
+            Attribute::Teamwork => player.teamwork,
+            Attribute::Vision => player.vision,
+            Attribute::WorkRate => player.work_rate,
+            Attribute::Acceleration => player.acceleration,
+            Attribute::Agility => player.agility,
+            Attribute::Balance => player.balance,
+            Attribute::JumpingReach => player.jumping_reach,
+            Attribute::NaturalFitness => player.natural_fitness,
+            Attribute::Pace => player.pace,
+            Attribute::Stamina => player.stamina,
+            Attribute::Strength => player.strength,
+            Attribute::AerialReach => player.aerial_reach,
+            Attribute::CommandOfArea => player.command_of_area,
+            Attribute::Communication => player.communication,
+            Attribute::Eccentricity => player.eccentricity,
+            Attribute::Handling => player.handling,
+            Attribute::Kicking => player.kicking,
+            Attribute::OneOnOnes => player.one_on_ones,
+            Attribute::Reflexes => player.reflexes,
+            Attribute::RushingOut => player.rushing_out,
+            Attribute::Punching => player.punching,
+            Attribute::Throwing => player.throwing,
+        }
+    }
+
+    /// The player's scouted range for this attribute; a known value is an exact range
+    pub fn range(&self, player: &Player) -> Option<AttributeRange> {
+        match player.attribute_ranges.get(self) {
+            Some(range) => Some(*range),
+            None => self.value(player).map(AttributeRange::exact),
+        }
+    }
+
+    /// Mutable access to the player's value for this attribute
+    pub fn value_mut<'a>(&self, player: &'a mut Player) -> &'a mut Option<i32> {
+        match self {
+            Attribute::Corners => &mut player.corners,
+            Attribute::Crossing => &mut player.crossing,
+            Attribute::Dribbling => &mut player.dribbling,
+            Attribute::Finishing => &mut player.finishing,
+            Attribute::FirstTouch => &mut player.first_touch,
+            Attribute::FreeKickTaking => &mut player.free_kick_taking,
+            Attribute::Heading => &mut player.heading,
+            Attribute::LongShots => &mut player.long_shots,
+            Attribute::LongThrows => &mut player.long_throws,
+            Attribute::Marking => &mut player.marking,
+            Attribute::Passing => &mut player.passing,
+            Attribute::PenaltyTaking => &mut player.penalty_taking,
+            Attribute::Tackling => &mut player.tackling,
+            Attribute::Technique => &mut player.technique,
+            Attribute::Aggression => &mut player.aggression,
+            Attribute::Anticipation => &mut player.anticipation,
+            Attribute::Bravery => &mut player.bravery,
+            Attribute::Composure => &mut player.composure,
+            Attribute::Concentration => &mut player.concentration,
+            Attribute::Decisions => &mut player.decisions,
+            Attribute::Determination => &mut player.determination,
+            Attribute::Flair => &mut player.flair,
+            Attribute::Leadership => &mut player.leadership,
+            Attribute::OffTheBall => &mut player.off_the_ball,
+            Attribute::Positioning => &mut player.positioning,
+            Attribute::Teamwork => &mut player.teamwork,
+            Attribute::Vision => &mut player.vision,
+            Attribute::WorkRate => &mut player.work_rate,
+            Attribute::Acceleration => &mut player.acceleration,
+            Attribute::Agility => &mut player.agility,
+            Attribute::Balance => &mut player.balance,
+            Attribute::JumpingReach => &mut player.jumping_reach,
+            Attribute::NaturalFitness => &mut player.natural_fitness,
+            Attribute::Pace => &mut player.pace,
+            Attribute::Stamina => &mut player.stamina,
+            Attribute::Strength => &mut player.strength,
+            Attribute::AerialReach => &mut player.aerial_reach,
+            Attribute::CommandOfArea => &mut player.command_of_area,
+            Attribute::Communication => &mut player.communication,
+            Attribute::Eccentricity => &mut player.eccentricity,
+            Attribute::Handling => &mut player.handling,
+            Attribute::Kicking => &mut player.kicking,
+            Attribute::OneOnOnes => &mut player.one_on_ones,
+            Attribute::Reflexes => &mut player.reflexes,
+            Attribute::RushingOut => &mut player.rushing_out,
+            Attribute::Punching => &mut player.punching,
+            Attribute::Throwing => &mut player.throwing,
+        }
+    }
+}
+
+/// Scouted bounds for an attribute FM only shows as a range, such as "11-15"
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AttributeRange {
+    pub low: i32,
+    pub high: i32,
+}
+
+impl AttributeRange {
+    pub fn new(low: i32, high: i32) -> Self {
+        Self { low, high }
+    }
+
+    /// A fully known value
+    pub fn exact(value: i32) -> Self {
+        Self::new(value, value)
+    }
+
+    pub fn is_exact(&self) -> bool {
+        self.low == self.high
+    }
+
+    /// The value stored on the player while only the range is known
+    pub fn midpoint(&self) -> i32 {
+        (self.low + self.high) / 2
+    }
+
+    /// Check both ends are 1-20 values and the range isn't reversed
+    pub fn validate(&self) -> Result<(), String> {
+        if self.low > self.high {
+            return Err("is reversed".to_string());
+        }
+        if self.low < MIN_ATTRIBUTE_VALUE || self.high > MAX_ATTRIBUTE_VALUE {
+            return Err(format!("must be between {} and {}", MIN_ATTRIBUTE_VALUE, MAX_ATTRIBUTE_VALUE));
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Attribute {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Attribute::ALL
+            .iter()
+            .copied()
+            .find(|attribute| attribute.as_str() == s)
+            .ok_or_else(|| format!("Invalid attribute: {}", s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Position;
+    use std::collections::{HashMap, HashSet};
+
+    #[test]
+    fn test_serde_uses_field_names() {
+        for attribute in Attribute::ALL {
+            let json = serde_json::to_string(&attribute).unwrap();
+            assert_eq!(json, format!("\"{}\"", attribute.as_str()));
+            assert_eq!(attribute.as_str().parse::<Attribute>(), Ok(attribute));
+        }
+
+        let weights: HashMap<Attribute, f32> = serde_json::from_str(r#"{"jumping_reach": 0.8}"#).unwrap();
+        assert_eq!(weights[&Attribute::JumpingReach], 0.8);
+        assert!(serde_json::from_str::<HashMap<Attribute, f32>>(r#"{"jumping": 0.8}"#).is_err());
+        assert!("jumping".parse::<Attribute>().is_err());
+    }
+
+    #[test]
+    fn test_metadata_is_unique() {
+        let abbreviations: HashSet<&str> = Attribute::ALL.iter().map(|a| a.abbreviation()).collect();
+        let display_names: HashSet<&str> = Attribute::ALL.iter().map(|a| a.display_name()).collect();
+        assert_eq!(abbreviations.len(), Attribute::ALL.len());
+        assert_eq!(display_names.len(), Attribute::ALL.len());
+
+        let goalkeeping = Attribute::ALL
+            .iter()
+            .filter(|a| a.category() == AttributeCategory::Goalkeeping)
+            .count();
+        assert_eq!(goalkeeping, 11);
+        assert_eq!(Attribute::OneOnOnes.abbreviation(), "1v1");
+        assert_eq!(Attribute::WorkRate.category(), AttributeCategory::Mental);
+    }
+
+    #[test]
+    fn test_value_accessors() {
+        let mut player = Player::new("Keeper".to_string(), 30, Position::GK);
+        player.reflexes = Some(17);
+        assert_eq!(Attribute::Reflexes.value(&player), Some(17));
+        assert_eq!(Attribute::Handling.value(&player), None);
+
+        *Attribute::Handling.value_mut(&mut player) = Some(15);
+        assert_eq!(player.handling, Some(15));
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut player = Player::new("Trialist".to_string(), 19, Position::STC);
+        player.set_attribute_range(Attribute::Finishing, AttributeRange::new(11, 15));
+        player.set_attribute_range(Attribute::Pace, AttributeRange::exact(14));
+
+        assert_eq!(player.finishing, Some(13));
+        assert_eq!(Attribute::Finishing.range(&player), Some(AttributeRange::new(11, 15)));
+        assert_eq!(player.pace, Some(14));
+        assert!(!player.attribute_ranges.contains_key(&Attribute::Pace));
+        assert_eq!(Attribute::Pace.range(&player), Some(AttributeRange::exact(14)));
+        assert_eq!(Attribute::Heading.range(&player), None);
+
+        // Scouting the attribute fully drops the range
+        player.set_attribute_range(Attribute::Finishing, AttributeRange::exact(12));
+        assert_eq!(Attribute::Finishing.range(&player), Some(AttributeRange::exact(12)));
+    }
+
+    #[test]
+    fn test_range_validation() {
+        assert_eq!(AttributeRange::new(1, 20).validate(), Ok(()));
+        assert_eq!(AttributeRange::exact(12).validate(), Ok(()));
+        assert!(AttributeRange::new(15, 11).validate().is_err());
+        assert!(AttributeRange::new(0, 5).validate().is_err());
+        assert!(AttributeRange::exact(21).validate().is_err());
+    }
+}
@@ -0,0 +1,8 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// The save's in-game date, which ages, contract lengths and snapshot dates follow
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GameCalendar {
+    pub game_date: NaiveDate,
+}
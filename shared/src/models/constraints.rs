@@ -0,0 +1,257 @@
+use serde::{Deserialize, Serialize};
+use super::formation::Formation;
+use super::player::Player;
+
+/// Minutes a starter is expected to play; players capped below this can only come off the bench
+pub const STARTER_MINUTES: u32 = 60;
+
+/// Why a player is unavailable
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExclusionReason {
+    Injured,
+    Suspended,
+    TransferListed,
+    #[default]
+    Other,
+}
+
+impl ExclusionReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExclusionReason::Injured => "injured",
+            ExclusionReason::Suspended => "suspended",
+            ExclusionReason::TransferListed => "transfer listed",
+            ExclusionReason::Other => "unavailable",
+        }
+    }
+}
+
+/// A player who must start in a slot
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LockedPlayer {
+    pub player_id: i64,
+    /// Index into the formation's positions
+    pub slot: usize,
+}
+
+/// A player who must not be picked
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExcludedPlayer {
+    pub player_id: i64,
+    #[serde(default)]
+    pub reason: ExclusionReason,
+}
+
+/// The most minutes a player should play
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MinutesCap {
+    pub player_id: i64,
+    pub max_minutes: u32,
+}
+
+/// Real-world limits on who can be picked, kept with the squad they were applied to
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LineupConstraints {
+    #[serde(default)]
+    pub locked: Vec<LockedPlayer>,
+    #[serde(default)]
+    pub excluded: Vec<ExcludedPlayer>,
+    #[serde(default)]
+    pub minutes_caps: Vec<MinutesCap>,
+}
+
+impl LineupConstraints {
+    pub fn is_empty(&self) -> bool {
+        self.locked.is_empty() && self.excluded.is_empty() && self.minutes_caps.is_empty()
+    }
+
+    pub fn exclusion(&self, player_id: i64) -> Option<ExclusionReason> {
+        self.excluded
+            .iter()
+            .find(|excluded| excluded.player_id == player_id)
+            .map(|excluded| excluded.reason)
+    }
+
+    /// The tightest cap on a player's minutes
+    pub fn max_minutes(&self, player_id: i64) -> Option<u32> {
+        self.minutes_caps
+            .iter()
+            .filter(|cap| cap.player_id == player_id)
+            .map(|cap| cap.max_minutes)
+            .min()
+    }
+
+    /// The slot a player is locked into
+    pub fn locked_slot(&self, player_id: i64) -> Option<usize> {
+        self.locked.iter().find(|locked| locked.player_id == player_id).map(|locked| locked.slot)
+    }
+
+    /// Whether a player may be picked at all
+    pub fn is_available(&self, player_id: i64) -> bool {
+        self.exclusion(player_id).is_none()
+    }
+
+    /// Whether a player may be picked to start
+    pub fn can_start(&self, player_id: i64) -> bool {
+        self.is_available(player_id) && self.max_minutes(player_id).is_none_or(|minutes| minutes >= STARTER_MINUTES)
+    }
+
+    /// Point every constraint at a new player id, dropping those whose player has none
+    pub fn remap_players(&mut self, new_id: impl Fn(i64) -> Option<i64>) {
+        self.locked.retain_mut(|locked| new_id(locked.player_id).map(|id| locked.player_id = id).is_some());
+        self.excluded.retain_mut(|excluded| new_id(excluded.player_id).map(|id| excluded.player_id = id).is_some());
+        self.minutes_caps.retain_mut(|cap| new_id(cap.player_id).map(|id| cap.player_id = id).is_some());
+    }
+
+    /// Every way the constraints contradict each other or can't be met with a formation and
+    /// players, described for the user. Empty when an XI can be picked.
+    pub fn conflicts(&self, formation: &Formation, players: &[Player]) -> Vec<String> {
+        let name = |player_id: i64| {
+            players
+                .iter()
+                .find(|player| player.id == Some(player_id))
+                .map_or_else(|| format!("Player {}", player_id), |player| player.name.clone())
+        };
+        let slot = |index: usize| format!("slot {} ({})", index, formation.positions[index].position.as_str());
+
+        let mut conflicts = Vec::new();
+        for (i, locked) in self.locked.iter().enumerate() {
+            if !players.iter().any(|player| player.id == Some(locked.player_id)) {
+                conflicts.push(format!("Player {} is locked in but is not in the workspace", locked.player_id));
+                continue;
+            }
+            let player = name(locked.player_id);
+            if locked.slot >= formation.positions.len() {
+                conflicts.push(format!(
+                    "{} is locked into slot {} but {} only has slots 0 to {}",
+                    player,
+                    locked.slot,
+                    formation.name,
+                    formation.positions.len().saturating_sub(1)
+                ));
+                continue;
+            }
+
+            let earlier = &self.locked[..i];
+            if let Some(other) = earlier.iter().find(|other| other.slot == locked.slot && other.player_id != locked.player_id) {
+                conflicts.push(format!(
+                    "{} and {} are both locked into {}",
+                    name(other.player_id),
+                    player,
+                    slot(locked.slot)
+                ));
+            }
+            if let Some(other) = earlier.iter().find(|other| other.player_id == locked.player_id && other.slot != locked.slot) {
+                conflicts.push(format!("{} is locked into both slot {} and slot {}", player, other.slot, locked.slot));
+            }
+            if let Some(reason) = self.exclusion(locked.player_id) {
+                conflicts.push(format!("{} is locked into {} but excluded ({})", player, slot(locked.slot), reason.as_str()));
+            } else if let Some(minutes) = self.max_minutes(locked.player_id).filter(|minutes| *minutes < STARTER_MINUTES) {
+                conflicts.push(format!(
+                    "{} is locked into {} but capped at {} minutes, and starters play at least {}",
+                    player,
+                    slot(locked.slot),
+                    minutes,
+                    STARTER_MINUTES
+                ));
+            }
+        }
+
+        let mut locked_slots: Vec<usize> = self
+            .locked
+            .iter()
+            .map(|locked| locked.slot)
+            .filter(|slot| *slot < formation.positions.len())
+            .collect();
+        locked_slots.sort_unstable();
+        locked_slots.dedup();
+        let open_slots = formation.positions.len().saturating_sub(locked_slots.len());
+        let starters = players
+            .iter()
+            .filter_map(|player| player.id)
+            .filter(|id| self.can_start(*id) && self.locked_slot(*id).is_none())
+            .count();
+        if starters < open_slots {
+            conflicts.push(format!(
+                "Only {} players can start in the {} unlocked slots once excluded and minutes-capped players are left out",
+                starters, open_slots
+            ));
+        }
+
+        conflicts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{players, two_a_side};
+
+    fn formation() -> Formation {
+        two_a_side().0
+    }
+
+    #[test]
+    fn test_no_conflicts() {
+        let constraints = LineupConstraints {
+            locked: vec![LockedPlayer { player_id: 1, slot: 0 }],
+            excluded: vec![ExcludedPlayer { player_id: 3, reason: ExclusionReason::Injured }],
+            minutes_caps: vec![MinutesCap { player_id: 2, max_minutes: 75 }],
+        };
+        assert!(constraints.conflicts(&formation(), &players()).is_empty());
+        assert!(!constraints.can_start(3));
+        assert!(constraints.can_start(2));
+    }
+
+    #[test]
+    fn test_conflicts() {
+        let constraints = LineupConstraints {
+            locked: vec![
+                LockedPlayer { player_id: 1, slot: 0 },
+                LockedPlayer { player_id: 2, slot: 0 },
+                LockedPlayer { player_id: 3, slot: 5 },
+            ],
+            excluded: vec![ExcludedPlayer { player_id: 2, reason: ExclusionReason::Suspended }],
+            minutes_caps: vec![],
+        };
+        let conflicts = constraints.conflicts(&formation(), &players());
+        assert_eq!(
+            conflicts,
+            vec![
+                "Keeper and Striker are both locked into slot 0 (GK)",
+                "Striker is locked into slot 0 (GK) but excluded (suspended)",
+                "Sub is locked into slot 5 but Two-a-side only has slots 0 to 1",
+                "Only 0 players can start in the 1 unlocked slots once excluded and minutes-capped players are left out",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_minutes_cap_keeps_player_on_bench() {
+        let constraints = LineupConstraints {
+            locked: vec![LockedPlayer { player_id: 1, slot: 1 }],
+            minutes_caps: vec![MinutesCap { player_id: 1, max_minutes: 30 }],
+            ..Default::default()
+        };
+        assert!(constraints.is_available(1));
+        assert!(!constraints.can_start(1));
+        assert_eq!(
+            constraints.conflicts(&formation(), &players()),
+            vec!["Keeper is locked into slot 1 (STC) but capped at 30 minutes, and starters play at least 60"]
+        );
+    }
+
+    #[test]
+    fn test_remap_players() {
+        let mut constraints = LineupConstraints {
+            locked: vec![LockedPlayer { player_id: 1, slot: 0 }],
+            excluded: vec![ExcludedPlayer { player_id: 2, reason: ExclusionReason::Injured }],
+            minutes_caps: vec![MinutesCap { player_id: 3, max_minutes: 45 }],
+        };
+        constraints.remap_players(|id| (id != 2).then_some(id + 10));
+        assert_eq!(constraints.locked[0].player_id, 11);
+        assert!(constraints.excluded.is_empty());
+        assert_eq!(constraints.minutes_caps[0].player_id, 13);
+    }
+}
@@ -11,6 +11,13 @@ pub struct FormationPosition {
     pub tactical_role: Option<TacticalRole>, // Optional assigned tactical role
 }
 
+impl FormationPosition {
+    /// The slot's tactical role, or the position's default when none is assigned
+    pub fn tactical_role_or_default(&self) -> TacticalRole {
+        self.tactical_role.unwrap_or_else(|| TacticalRole::default_for(self.position))
+    }
+}
+
 /// Formation configuration with position layout
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Formation {
@@ -49,6 +56,14 @@ impl Formation {
         self.positions.len()
     }
 
+    /// The formation with every slot's chosen tactical role cleared, so each plays its
+    /// position's default
+    pub fn with_default_roles(&self) -> Formation {
+        let mut formation = self.clone();
+        formation.positions.iter_mut().for_each(|slot| slot.tactical_role = None);
+        formation
+    }
+
     /// Validate formation has 11 positions (including GK)
     pub fn is_valid(&self) -> bool {
         self.player_count() == 11
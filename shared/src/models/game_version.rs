@@ -0,0 +1,175 @@
+use serde::{Deserialize, Serialize};
+use super::player::Position;
+use super::tactical_role::{Duty, RoleType};
+
+/// Football Manager release a workspace's save comes from; each has its own set of roles
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum GameVersion {
+    FM23,
+    #[default]
+    FM24,
+    /// FM26 replaced duties with separate in- and out-of-possession roles. Until that is
+    /// modelled it offers FM24's roles.
+    FM26,
+}
+
+/// Where a role can be played, and on which duties
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoleAvailability {
+    pub positions: &'static [Position],
+    pub role: RoleType,
+    pub duties: &'static [Duty],
+}
+
+impl RoleAvailability {
+    pub fn allows(&self, position: Position, role: RoleType, duty: Duty) -> bool {
+        self.role == role && self.positions.contains(&position) && self.duties.contains(&duty)
+    }
+}
+
+const fn available(positions: &'static [Position], role: RoleType, duties: &'static [Duty]) -> RoleAvailability {
+    RoleAvailability { positions, role, duties }
+}
+
+use Duty::*;
+use Position::*;
+use RoleType::*;
+
+const DEFEND_SUPPORT_ATTACK: &[Duty] = &[Defend, Support, Attack];
+const DEFEND_SUPPORT: &[Duty] = &[Defend, Support];
+const SUPPORT_ATTACK: &[Duty] = &[Support, Attack];
+
+/// The roles of FM23
+const FM23_ROLES: &[RoleAvailability] = &[
+    // Goalkeepers
+    available(&[GK], Goalkeeper, &[Automatic]),
+    available(&[GK], SweeperKeeper, &[Automatic]),
+
+    // Full backs
+    available(&[DR, DL], FullBack, DEFEND_SUPPORT_ATTACK),
+    available(&[DR, DL, WBR, WBL], WingBack, DEFEND_SUPPORT_ATTACK),
+    available(&[DR, DL, WBR, WBL], CompleteWingBack, SUPPORT_ATTACK),
+    available(&[DR, DL, WBR, WBL], InvertedWingBack, DEFEND_SUPPORT_ATTACK),
+    available(&[DR, DL, DC], NoChallengeDefender, &[Defend]),
+
+    // Centre backs
+    available(&[DC], CentralDefender, DEFEND_SUPPORT),
+    available(&[DC], BallPlayingDefender, DEFEND_SUPPORT),
+    available(&[DC], Libero, DEFEND_SUPPORT),
+
+    // Defensive midfielders
+    available(&[DMC], Anchor, &[Defend]),
+    available(&[DMC, MC], BallWinningMidfielder, DEFEND_SUPPORT),
+    available(&[DMC], DeepLyingPlaymaker, DEFEND_SUPPORT),
+    available(&[DMC], HalfBack, &[Defend]),
+    available(&[DMC], Regista, &[Support]),
+    available(&[DMC], SegundoVolante, SUPPORT_ATTACK),
+
+    // Central midfielders
+    available(&[MC], BoxToBoxMidfielder, &[Support]),
+    available(&[MC], CentralMidfielder, DEFEND_SUPPORT_ATTACK),
+    available(&[MC], Carrilero, &[Support]),
+    available(&[MC], DeepLyingMidfielderSupport, &[Support]),
+    available(&[MC], Mezzala, SUPPORT_ATTACK),
+    available(&[MC], RoamingPlaymaker, &[Support]),
+    available(&[MC, AMC], AdvancedPlaymaker, SUPPORT_ATTACK),
+
+    // Wide midfielders and attacking wingers
+    available(&[MR, ML, AMR, AML], Winger, SUPPORT_ATTACK),
+    available(&[MR, ML, AMR, AML], InvertedWinger, SUPPORT_ATTACK),
+    available(&[MR, ML, AMR, AML], WidePlaymaker, SUPPORT_ATTACK),
+    available(&[MR, ML], WideMidfielder, DEFEND_SUPPORT_ATTACK),
+    available(&[MR, ML], DefensiveWinger, DEFEND_SUPPORT),
+    available(&[AMR, AML], InsideForward, SUPPORT_ATTACK),
+    available(&[AMR, AML], Raumdeuter, &[Attack]),
+
+    // Attacking midfielders
+    available(&[AMC], AttackingMidfielder, SUPPORT_ATTACK),
+    available(&[AMC], Enganche, &[Support]),
+    available(&[AMC], ShadowStriker, &[Attack]),
+    available(&[AMC], Trequartista, &[Support]),
+
+    // Strikers
+    available(&[STC], AdvancedForward, &[Attack]),
+    available(&[STC], CompleteForward, SUPPORT_ATTACK),
+    available(&[STC], DeepLyingForward, SUPPORT_ATTACK),
+    available(&[STC], FalseNine, &[Support]),
+    available(&[STC], Poacher, &[Attack]),
+    available(&[STC], PressingForward, DEFEND_SUPPORT_ATTACK),
+    available(&[STC], TargetMan, SUPPORT_ATTACK),
+    available(&[STC], TrequartistaForward, &[Support]),
+];
+
+/// Roles FM24 added to FM23's
+const FM24_ADDED_ROLES: &[RoleAvailability] = &[
+    available(&[DR, DL], InvertedFullBack, &[Defend]),
+    available(&[DC], WideCentreBack, DEFEND_SUPPORT_ATTACK),
+    available(&[MC], ChannelMidfielder, &[Support]),
+];
+
+impl GameVersion {
+    pub const ALL: [GameVersion; 3] = [GameVersion::FM23, GameVersion::FM24, GameVersion::FM26];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GameVersion::FM23 => "FM23",
+            GameVersion::FM24 => "FM24",
+            GameVersion::FM26 => "FM26",
+        }
+    }
+
+    /// Every role in this version, with where and on which duties it can be played
+    pub fn roles(&self) -> impl Iterator<Item = &'static RoleAvailability> {
+        let added: &'static [RoleAvailability] = match self {
+            GameVersion::FM23 => &[],
+            GameVersion::FM24 | GameVersion::FM26 => FM24_ADDED_ROLES,
+        };
+        FM23_ROLES.iter().chain(added)
+    }
+
+    /// Whether this version lets the role be played in the position on the duty
+    pub fn allows(&self, position: Position, role: RoleType, duty: Duty) -> bool {
+        self.roles().any(|availability| availability.allows(position, role, duty))
+    }
+}
+
+impl std::str::FromStr for GameVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        GameVersion::ALL
+            .into_iter()
+            .find(|version| version.as_str() == s)
+            .ok_or_else(|| format!("Invalid game version: {}", s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fm24_roles() {
+        assert!(!GameVersion::FM23.allows(DR, InvertedFullBack, Defend));
+        assert!(GameVersion::FM24.allows(DR, InvertedFullBack, Defend));
+        assert!(GameVersion::FM24.allows(DC, WideCentreBack, Attack));
+        assert!(!GameVersion::FM24.allows(DC, WideCentreBack, Automatic));
+        assert!(GameVersion::FM24.allows(MC, ChannelMidfielder, Support));
+        assert!(!GameVersion::FM23.allows(MC, ChannelMidfielder, Support));
+    }
+
+    #[test]
+    fn test_fm26_offers_fm24_roles() {
+        assert!(GameVersion::FM26.roles().eq(GameVersion::FM24.roles()));
+        assert!(GameVersion::FM26.allows(DC, WideCentreBack, Defend));
+    }
+
+    #[test]
+    fn test_game_version_round_trip() {
+        for version in GameVersion::ALL {
+            assert_eq!(version.as_str().parse::<GameVersion>(), Ok(version));
+            assert_eq!(serde_json::to_string(&version).unwrap(), format!("\"{}\"", version.as_str()));
+        }
+        assert!("FM25".parse::<GameVersion>().is_err());
+    }
+}
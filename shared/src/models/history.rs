@@ -0,0 +1,210 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use super::attribute::Attribute;
+use super::player::Player;
+
+/// A player's known attributes as recorded by one import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttributeSnapshot {
+    pub id: Option<i64>,
+    pub player_id: i64,
+    pub recorded_at: NaiveDateTime,
+
+    /// Attribute values (1-20) keyed by attribute name; unknown attributes are absent
+    pub attributes: HashMap<String, i32>,
+}
+
+/// Change in one attribute between two snapshots
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttributeDelta {
+    pub attribute: String,
+    pub from: i32,
+    pub to: i32,
+    pub change: i32,
+}
+
+/// How far a player has developed over their recorded history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerProgress {
+    pub player_id: i64,
+    pub name: String,
+    pub from: NaiveDateTime,
+    pub to: NaiveDateTime,
+    /// Sum of every attribute change
+    pub total_change: i32,
+    /// Attributes that changed, largest movement first
+    pub deltas: Vec<AttributeDelta>,
+}
+
+/// Squad-wide progression: the players who improved and declined the most
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgressionReport {
+    pub improvers: Vec<PlayerProgress>,
+    pub decliners: Vec<PlayerProgress>,
+}
+
+impl AttributeSnapshot {
+    /// Snapshot of a player's current attributes
+    pub fn of(player_id: i64, player: &Player, recorded_at: NaiveDateTime) -> Self {
+        Self {
+            id: None,
+            player_id,
+            recorded_at,
+            attributes: player.attribute_values(),
+        }
+    }
+
+    /// Per-attribute change from this snapshot to a later one.
+    /// Only attributes known in both snapshots are compared; they are listed in
+    /// `Attribute::ALL` order, including those that did not change.
+    pub fn deltas_to(&self, later: &AttributeSnapshot) -> Vec<AttributeDelta> {
+        Attribute::ALL
+            .iter()
+            .filter_map(|attribute| {
+                let name = attribute.as_str();
+                let from = *self.attributes.get(name)?;
+                let to = *later.attributes.get(name)?;
+                Some(AttributeDelta {
+                    attribute: name.to_string(),
+                    from,
+                    to,
+                    change: to - from,
+                })
+            })
+            .collect()
+    }
+}
+
+impl PlayerProgress {
+    /// Compare a player's first and last snapshot.
+    /// Returns `None` when fewer than two snapshots are given.
+    pub fn from_history(player: &Player, history: &[&AttributeSnapshot]) -> Option<Self> {
+        if history.len() < 2 {
+            return None;
+        }
+        let first = history.iter().min_by_key(|s| (s.recorded_at, s.id))?;
+        let last = history.iter().max_by_key(|s| (s.recorded_at, s.id))?;
+
+        let mut deltas: Vec<AttributeDelta> = first
+            .deltas_to(last)
+            .into_iter()
+            .filter(|d| d.change != 0)
+            .collect();
+        deltas.sort_by_key(|d| std::cmp::Reverse(d.change.abs()));
+
+        Some(Self {
+            player_id: last.player_id,
+            name: player.name.clone(),
+            from: first.recorded_at,
+            to: last.recorded_at,
+            total_change: deltas.iter().map(|d| d.change).sum(),
+            deltas,
+        })
+    }
+}
+
+impl ProgressionReport {
+    /// Rank players by total attribute change across their snapshots.
+    /// Snapshots recorded before `since` are ignored; at most `limit` players are listed each way.
+    pub fn build(
+        players: &[Player],
+        snapshots: &[AttributeSnapshot],
+        since: Option<NaiveDateTime>,
+        limit: usize,
+    ) -> Self {
+        let mut progress: Vec<PlayerProgress> = players
+            .iter()
+            .filter_map(|player| {
+                let id = player.id?;
+                let history: Vec<&AttributeSnapshot> = snapshots
+                    .iter()
+                    .filter(|s| s.player_id == id)
+                    .filter(|s| since.is_none_or(|since| s.recorded_at >= since))
+                    .collect();
+                PlayerProgress::from_history(player, &history)
+            })
+            .collect();
+
+        progress.sort_by_key(|p| std::cmp::Reverse(p.total_change));
+        let improvers = progress
+            .iter()
+            .filter(|p| p.total_change > 0)
+            .take(limit)
+            .cloned()
+            .collect();
+        let decliners = progress
+            .iter()
+            .rev()
+            .filter(|p| p.total_change < 0)
+            .take(limit)
+            .cloned()
+            .collect();
+
+        Self { improvers, decliners }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Position;
+    use chrono::NaiveDate;
+
+    fn at(day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 1, day).unwrap().and_hms_opt(12, 0, 0).unwrap()
+    }
+
+    fn player(id: i64, name: &str) -> Player {
+        let mut player = Player::new(name.to_string(), 20, Position::MC);
+        player.id = Some(id);
+        player
+    }
+
+    fn snapshot(player_id: i64, day: u32, attributes: &[(&str, i32)]) -> AttributeSnapshot {
+        AttributeSnapshot {
+            id: None,
+            player_id,
+            recorded_at: at(day),
+            attributes: attributes.iter().map(|(n, v)| (n.to_string(), *v)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_deltas_to() {
+        let before = snapshot(1, 1, &[("passing", 12), ("pace", 15), ("vision", 10)]);
+        let after = snapshot(1, 2, &[("passing", 14), ("pace", 14), ("flair", 9)]);
+
+        let deltas = before.deltas_to(&after);
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(deltas[0].attribute, "passing");
+        assert_eq!(deltas[0].change, 2);
+        assert_eq!(deltas[1].attribute, "pace");
+        assert_eq!(deltas[1].change, -1);
+    }
+
+    #[test]
+    fn test_progression_report() {
+        let players = vec![player(1, "Riser"), player(2, "Faller"), player(3, "New")];
+        let snapshots = vec![
+            snapshot(1, 1, &[("passing", 12), ("pace", 15)]),
+            snapshot(1, 5, &[("passing", 15), ("pace", 16)]),
+            snapshot(2, 1, &[("pace", 17)]),
+            snapshot(2, 5, &[("pace", 14)]),
+            snapshot(3, 5, &[("pace", 10)]),
+        ];
+
+        let report = ProgressionReport::build(&players, &snapshots, None, 5);
+        assert_eq!(report.improvers.len(), 1);
+        assert_eq!(report.improvers[0].name, "Riser");
+        assert_eq!(report.improvers[0].total_change, 4);
+        assert_eq!(report.improvers[0].deltas[0].attribute, "passing");
+        assert_eq!(report.decliners.len(), 1);
+        assert_eq!(report.decliners[0].total_change, -3);
+
+        // Nothing to compare once the earlier snapshots are excluded
+        let report = ProgressionReport::build(&players, &snapshots, Some(at(2)), 5);
+        assert!(report.improvers.is_empty());
+        assert!(report.decliners.is_empty());
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Named mapping from a data source's column headers onto `Player` fields,
+/// e.g. Genie Scout's "Jumping" onto `jumping_reach`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MappingProfile {
+    pub id: Option<i64>,
+    pub name: String,
+    pub description: Option<String>,
+
+    /// Source header -> `Player` field name.
+    /// A `None` target marks a column that is known and deliberately skipped.
+    /// Headers are compared ignoring case, spacing and punctuation.
+    pub columns: HashMap<String, Option<String>>,
+
+    pub is_custom: bool,
+}
+
+impl MappingProfile {
+    /// Create a new custom profile
+    pub fn new(name: String, columns: HashMap<String, Option<String>>) -> Self {
+        Self {
+            id: None,
+            name,
+            description: None,
+            columns,
+            is_custom: true,
+        }
+    }
+}
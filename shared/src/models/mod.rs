@@ -1,13 +1,29 @@
+pub mod attribute;
 pub mod player;
 pub mod formation;
 pub mod role;
 pub mod squad;
 pub mod tactics;
 pub mod tactical_role;
+pub mod mapping_profile;
+pub mod history;
+pub mod calendar;
+pub mod workspace;
+pub mod game_version;
+pub mod constraints;
+pub mod recruitment;
 
+pub use attribute::*;
 pub use player::*;
 pub use formation::*;
 pub use role::*;
 pub use squad::*;
 pub use tactics::*;
 pub use tactical_role::*;
+pub use mapping_profile::*;
+pub use history::*;
+pub use calendar::*;
+pub use workspace::*;
+pub use game_version::*;
+pub use constraints::*;
+pub use recruitment::*;
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
-use chrono::NaiveDate;
+use chrono::{Datelike, NaiveDate};
+use std::collections::HashMap;
+use super::attribute::{Attribute, AttributeRange};
 
 /// Football Manager player position
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -21,6 +23,24 @@ pub enum Position {
 }
 
 impl Position {
+    /// Every position, from goalkeeper to striker
+    pub const ALL: [Position; 14] = [
+        Position::GK,
+        Position::DR,
+        Position::DC,
+        Position::DL,
+        Position::WBR,
+        Position::WBL,
+        Position::DMC,
+        Position::MC,
+        Position::MR,
+        Position::ML,
+        Position::AMR,
+        Position::AML,
+        Position::AMC,
+        Position::STC,
+    ];
+
     pub fn as_str(&self) -> &str {
         match self {
             Position::GK => "GK",
@@ -65,14 +85,79 @@ impl std::str::FromStr for Position {
     }
 }
 
+/// How well a player knows a position, FM's five levels from worst to best
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum Familiarity {
+    Awkward,
+    Unconvincing,
+    Competent,
+    Accomplished,
+    Natural,
+}
+
+impl Familiarity {
+    pub const ALL: [Familiarity; 5] = [
+        Familiarity::Awkward,
+        Familiarity::Unconvincing,
+        Familiarity::Competent,
+        Familiarity::Accomplished,
+        Familiarity::Natural,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Familiarity::Awkward => "awkward",
+            Familiarity::Unconvincing => "unconvincing",
+            Familiarity::Competent => "competent",
+            Familiarity::Accomplished => "accomplished",
+            Familiarity::Natural => "natural",
+        }
+    }
+
+    /// The level for a 1-20 position rating, as shown by scouting tools
+    pub fn from_rating(rating: i32) -> Option<Self> {
+        let familiarity = match rating {
+            20 => Familiarity::Natural,
+            15..=19 => Familiarity::Accomplished,
+            10..=14 => Familiarity::Competent,
+            5..=9 => Familiarity::Unconvincing,
+            1..=4 => Familiarity::Awkward,
+            _ => return None,
+        };
+        Some(familiarity)
+    }
+}
+
+impl std::str::FromStr for Familiarity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Familiarity::ALL
+            .into_iter()
+            .find(|familiarity| familiarity.as_str() == s)
+            .ok_or_else(|| format!("Invalid familiarity: {}", s))
+    }
+}
+
 /// Complete player model with all Football Manager attributes
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Player {
     pub id: Option<i64>,
+    /// FM's unique ID for the player, used to recognise them on re-import
+    #[serde(default)]
+    pub fm_uid: Option<i64>,
     pub name: String,
     pub age: i32,
+    /// When known, `age` is derived from this and the game date
+    #[serde(default)]
+    pub date_of_birth: Option<NaiveDate>,
     pub nationality: Option<String>,
     pub position: Position,
+    /// Familiarity with other positions; `position` is natural unless recorded otherwise
+    /// and unrecorded positions are awkward
+    #[serde(default)]
+    pub position_familiarity: HashMap<Position, Familiarity>,
 
     // Technical attributes (1-20)
     pub corners: Option<i32>,
@@ -129,10 +214,18 @@ pub struct Player {
     pub punching: Option<i32>,
     pub throwing: Option<i32>,
 
+    /// Scouted bounds for attributes only known as a range; the attribute itself holds the midpoint
+    #[serde(default)]
+    pub attribute_ranges: HashMap<Attribute, AttributeRange>,
+
     // Additional info
     pub value: Option<i64>,
     pub wage: Option<i64>,
     pub contract_expiry: Option<NaiveDate>,
+
+    /// Whole months from the game date to `contract_expiry`; derived, not stored
+    #[serde(default)]
+    pub contract_months_left: Option<i32>,
 }
 
 impl Player {
@@ -140,10 +233,13 @@ impl Player {
     pub fn new(name: String, age: i32, position: Position) -> Self {
         Self {
             id: None,
+            fm_uid: None,
             name,
             age,
+            date_of_birth: None,
             nationality: None,
             position,
+            position_familiarity: HashMap::new(),
             corners: None,
             crossing: None,
             dribbling: None,
@@ -191,14 +287,145 @@ impl Player {
             rushing_out: None,
             punching: None,
             throwing: None,
+            attribute_ranges: HashMap::new(),
             value: None,
             wage: None,
             contract_expiry: None,
+            contract_months_left: None,
+        }
+    }
+
+    /// Age on the given game date, from the date of birth when known
+    pub fn age_on(&self, game_date: NaiveDate) -> i32 {
+        match self.date_of_birth {
+            Some(date_of_birth) => age_at(date_of_birth, game_date),
+            None => self.age,
         }
     }
 
+    /// Whole months left on the contract at the given game date; 0 once it has expired
+    pub fn months_left_on_contract(&self, game_date: NaiveDate) -> Option<i32> {
+        self.contract_expiry
+            .map(|expiry| whole_months_between(game_date, expiry).max(0))
+    }
+
+    /// Recompute every value that depends on the game date
+    pub fn refresh_derived(&mut self, game_date: NaiveDate) {
+        self.age = self.age_on(game_date);
+        self.contract_months_left = self.months_left_on_contract(game_date);
+    }
+
     /// Check if this is a goalkeeper
     pub fn is_goalkeeper(&self) -> bool {
         matches!(self.position, Position::GK)
     }
+
+    /// How well the player knows a position
+    pub fn familiarity(&self, position: Position) -> Familiarity {
+        match self.position_familiarity.get(&position) {
+            Some(familiarity) => *familiarity,
+            None if position == self.position => Familiarity::Natural,
+            None => Familiarity::Awkward,
+        }
+    }
+
+    /// Whether the player knows the position at least as well as `min_familiarity`
+    pub fn plays(&self, position: Position, min_familiarity: Familiarity) -> bool {
+        self.familiarity(position) >= min_familiarity
+    }
+
+    /// Set an attribute from its scouted range: the midpoint is stored as the value and
+    /// the range is kept unless it is exact
+    pub fn set_attribute_range(&mut self, attribute: Attribute, range: AttributeRange) {
+        *attribute.value_mut(self) = Some(range.midpoint());
+        if range.is_exact() {
+            self.attribute_ranges.remove(&attribute);
+        } else {
+            self.attribute_ranges.insert(attribute, range);
+        }
+    }
+
+    /// Every known 1-20 attribute, keyed by field name
+    pub fn attribute_values(&self) -> HashMap<String, i32> {
+        Attribute::ALL
+            .iter()
+            .filter_map(|attribute| attribute.value(self).map(|value| (attribute.as_str().to_string(), value)))
+            .collect()
+    }
+}
+
+/// Age in completed years on the given date
+pub fn age_at(date_of_birth: NaiveDate, date: NaiveDate) -> i32 {
+    whole_months_between(date_of_birth, date) / 12
+}
+
+/// Completed calendar months from `from` to `to`; negative when `to` is earlier
+fn whole_months_between(from: NaiveDate, to: NaiveDate) -> i32 {
+    let months = (to.year() - from.year()) * 12 + to.month() as i32 - from.month() as i32;
+    if to >= from && to.day() < from.day() {
+        months - 1
+    } else if to < from && to.day() > from.day() {
+        months + 1
+    } else {
+        months
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_age_on() {
+        let mut player = Player::new("Youngster".to_string(), 17, Position::MC);
+        assert_eq!(player.age_on(date(2030, 1, 1)), 17);
+
+        player.date_of_birth = Some(date(2007, 8, 15));
+        assert_eq!(player.age_on(date(2025, 8, 14)), 17);
+        assert_eq!(player.age_on(date(2025, 8, 15)), 18);
+    }
+
+    #[test]
+    fn test_months_left_on_contract() {
+        let mut player = Player::new("Veteran".to_string(), 33, Position::DC);
+        assert_eq!(player.months_left_on_contract(date(2025, 1, 1)), None);
+
+        player.contract_expiry = Some(date(2026, 6, 30));
+        assert_eq!(player.months_left_on_contract(date(2025, 7, 1)), Some(11));
+        assert_eq!(player.months_left_on_contract(date(2025, 6, 30)), Some(12));
+        assert_eq!(player.months_left_on_contract(date(2026, 7, 1)), Some(0));
+    }
+
+    #[test]
+    fn test_familiarity() {
+        let mut player = Player::new("Utility".to_string(), 26, Position::DC);
+        player.position_familiarity.insert(Position::DMC, Familiarity::Accomplished);
+        player.position_familiarity.insert(Position::DR, Familiarity::Unconvincing);
+
+        assert_eq!(player.familiarity(Position::DC), Familiarity::Natural);
+        assert_eq!(player.familiarity(Position::DMC), Familiarity::Accomplished);
+        assert_eq!(player.familiarity(Position::STC), Familiarity::Awkward);
+        assert!(player.plays(Position::DMC, Familiarity::Competent));
+        assert!(!player.plays(Position::DR, Familiarity::Competent));
+
+        assert_eq!(Familiarity::from_rating(20), Some(Familiarity::Natural));
+        assert_eq!(Familiarity::from_rating(12), Some(Familiarity::Competent));
+        assert_eq!(Familiarity::from_rating(0), None);
+    }
+
+    #[test]
+    fn test_refresh_derived() {
+        let mut player = Player::new("Loanee".to_string(), 20, Position::STC);
+        player.date_of_birth = Some(date(2005, 3, 1));
+        player.contract_expiry = Some(date(2026, 6, 30));
+
+        player.refresh_derived(date(2025, 7, 1));
+        assert_eq!((player.age, player.contract_months_left), (20, Some(11)));
+        player.refresh_derived(date(2026, 3, 1));
+        assert_eq!((player.age, player.contract_months_left), (21, Some(3)));
+    }
 }
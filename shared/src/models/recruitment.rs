@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use super::attribute::Attribute;
+use super::player::{Familiarity, Player, Position};
+use super::tactical_role::TacticalRole;
+
+/// The player to look for to fill a slot, usable as a player search filter
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecruitmentBrief {
+    pub position: Position,
+    /// The role the player would play; not used when matching
+    pub tactical_role: TacticalRole,
+    /// How well the player has to know the position
+    pub min_familiarity: Familiarity,
+    /// Lowest value for each of the role's key attributes
+    pub min_attributes: HashMap<Attribute, i32>,
+    pub max_age: Option<i32>,
+    /// Highest weekly wage
+    pub max_wage: Option<i64>,
+}
+
+impl RecruitmentBrief {
+    /// Whether a player fits the brief. An attribute scouted as a range matches when its
+    /// high end reaches the minimum; an unknown one doesn't match. Unknown wages match.
+    pub fn matches(&self, player: &Player) -> bool {
+        player.plays(self.position, self.min_familiarity)
+            && self.max_age.is_none_or(|max_age| player.age <= max_age)
+            && self.max_wage.is_none_or(|max_wage| player.wage.is_none_or(|wage| wage <= max_wage))
+            && self
+                .min_attributes
+                .iter()
+                .all(|(attribute, minimum)| attribute.range(player).is_some_and(|range| range.high >= *minimum))
+    }
+}
+
+/// An attribute holding a player back in a role
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttributeShortfall {
+    pub attribute: Attribute,
+    /// The player's value, if known
+    pub value: Option<i32>,
+    /// Suitability percentage points lost to it. An unknown attribute scored as the minimum
+    /// or an imputed average loses what that value does.
+    pub lost: f32,
+}
+
+/// A formation slot the best XI falls short of the target suitability in
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SlotGap {
+    /// Index into the formation's positions
+    pub slot: usize,
+    pub position: Position,
+    pub tactical_role: TacticalRole,
+    pub role_name: String,
+    /// The player the best XI puts in the slot, if anyone is left who can be scored for it
+    pub best_player_id: Option<i64>,
+    pub best_suitability: Option<f32>,
+    /// What holds that player back most, worst first
+    pub limiting_attributes: Vec<AttributeShortfall>,
+    /// Attributes of the role that player has no value for and that were left out of their
+    /// score, so scouting them could move it either way
+    #[serde(default)]
+    pub unknown_attributes: Vec<Attribute>,
+    pub brief: RecruitmentBrief,
+}
+
+/// The slots of a formation the squad falls short in
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GapReport {
+    pub formation_id: i64,
+    /// Suitability percentage every slot was measured against
+    pub target: f32,
+    pub gaps: Vec<SlotGap>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{AttributeRange, Duty, RoleType};
+
+    fn brief() -> RecruitmentBrief {
+        RecruitmentBrief {
+            position: Position::DC,
+            tactical_role: TacticalRole {
+                position: Position::DC,
+                role: RoleType::CentralDefender,
+                duty: Duty::Defend,
+            },
+            min_familiarity: Familiarity::Accomplished,
+            min_attributes: HashMap::from([(Attribute::Tackling, 14), (Attribute::Marking, 14)]),
+            max_age: Some(28),
+            max_wage: Some(40_000),
+        }
+    }
+
+    #[test]
+    fn test_brief_matches() {
+        let mut player = Player::new("Defender".to_string(), 24, Position::DC);
+        player.tackling = Some(15);
+        player.marking = Some(14);
+        player.wage = Some(30_000);
+        assert!(brief().matches(&player));
+
+        let mut older = player.clone();
+        older.age = 31;
+        assert!(!brief().matches(&older));
+
+        let mut expensive = player.clone();
+        expensive.wage = Some(60_000);
+        assert!(!brief().matches(&expensive));
+
+        let mut unknown = player.clone();
+        unknown.marking = None;
+        assert!(!brief().matches(&unknown));
+
+        // A scouted range that could reach the minimum is worth a look
+        let mut scouted = unknown.clone();
+        scouted.set_attribute_range(Attribute::Marking, AttributeRange { low: 10, high: 16 });
+        assert!(brief().matches(&scouted));
+
+        let mut midfielder = player.clone();
+        midfielder.position = Position::MC;
+        assert!(!brief().matches(&midfielder));
+        midfielder.position_familiarity.insert(Position::DC, Familiarity::Accomplished);
+        assert!(brief().matches(&midfielder));
+    }
+}
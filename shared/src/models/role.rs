@@ -1,20 +1,99 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use super::attribute::Attribute;
+use super::game_version::GameVersion;
 use super::player::Position;
-use super::tactical_role::Duty;
+use super::tactical_role::{Duty, RoleType, TacticalRole};
+
+/// How FM rates an attribute's importance for a role
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum AttributeTier {
+    /// Shown in green in FM's role description
+    Key,
+    /// Shown in blue in FM's role description
+    Preferred,
+}
+
+/// Where a role's scoring weights come from
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RoleWeighting {
+    /// The hand-set `attribute_weights`
+    #[default]
+    Weights,
+    /// Derived from `attribute_tiers`, one multiplier per tier
+    Tiers,
+}
+
+impl RoleWeighting {
+    pub fn as_str(&self) -> &str {
+        match self {
+            RoleWeighting::Weights => "weights",
+            RoleWeighting::Tiers => "tiers",
+        }
+    }
+}
+
+impl std::str::FromStr for RoleWeighting {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "weights" => Ok(RoleWeighting::Weights),
+            "tiers" => Ok(RoleWeighting::Tiers),
+            _ => Err(format!("Invalid role weighting: {}", s)),
+        }
+    }
+}
+
+/// Weight given to each tier when a role is weighted by tiers
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TierMultipliers {
+    pub key: f32,
+    pub preferred: f32,
+}
+
+impl Default for TierMultipliers {
+    fn default() -> Self {
+        Self {
+            key: 1.0,
+            preferred: 0.5,
+        }
+    }
+}
+
+impl TierMultipliers {
+    pub fn get(&self, tier: AttributeTier) -> f32 {
+        match tier {
+            AttributeTier::Key => self.key,
+            AttributeTier::Preferred => self.preferred,
+        }
+    }
+}
 
 /// Role definition with attribute importance weights
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Role {
     pub id: Option<i64>,
     pub name: String,
+    /// The FM role this is, or for a custom role the one it specialises
+    pub role_type: RoleType,
     pub position: Position,  // Position enum
     pub duty: Duty,
     pub description: Option<String>,
 
     /// Attribute weights (0.0 to 1.0) indicating importance for this role
-    /// Key is attribute name, value is weight
-    pub attribute_weights: HashMap<String, f32>,
+    /// Keyed by attribute, serialized as its `Player` field name; unknown names are rejected
+    pub attribute_weights: HashMap<Attribute, f32>,
+
+    /// FM's key and preferred attributes for the role
+    #[serde(default)]
+    pub attribute_tiers: HashMap<Attribute, AttributeTier>,
+
+    /// Whether scoring uses `attribute_weights` or `attribute_tiers`
+    #[serde(default)]
+    pub weighting: RoleWeighting,
 
     pub is_custom: bool,
 }
@@ -23,17 +102,21 @@ impl Role {
     /// Create a new role
     pub fn new(
         name: String,
+        role_type: RoleType,
         position: Position,
         duty: Duty,
-        attribute_weights: HashMap<String, f32>,
+        attribute_weights: HashMap<Attribute, f32>,
     ) -> Self {
         Self {
             id: None,
             name,
+            role_type,
             position,
             duty,
             description: None,
             attribute_weights,
+            attribute_tiers: HashMap::new(),
+            weighting: RoleWeighting::Weights,
             is_custom: false,
         }
     }
@@ -41,24 +124,54 @@ impl Role {
     /// Create a custom role
     pub fn new_custom(
         name: String,
+        role_type: RoleType,
         position: Position,
         duty: Duty,
-        attribute_weights: HashMap<String, f32>,
+        attribute_weights: HashMap<Attribute, f32>,
     ) -> Self {
         Self {
             id: None,
             name,
+            role_type,
             position,
             duty,
             description: None,
             attribute_weights,
+            attribute_tiers: HashMap::new(),
+            weighting: RoleWeighting::Weights,
             is_custom: true,
         }
     }
 
+    /// The position, role type and duty this role covers; fails if the game version has no
+    /// such combination
+    pub fn tactical_role(&self, version: GameVersion) -> Result<TacticalRole, String> {
+        TacticalRole::new(self.position, self.role_type, self.duty, version)
+    }
+
+    /// Weight the role by FM's key and preferred attributes instead of hand-set weights
+    pub fn with_tiers(mut self, attribute_tiers: HashMap<Attribute, AttributeTier>) -> Self {
+        self.attribute_tiers = attribute_tiers;
+        self.weighting = RoleWeighting::Tiers;
+        self
+    }
+
     /// Get weight for a specific attribute
-    pub fn get_weight(&self, attribute: &str) -> f32 {
-        *self.attribute_weights.get(attribute).unwrap_or(&0.0)
+    pub fn get_weight(&self, attribute: Attribute) -> f32 {
+        *self.attribute_weights.get(&attribute).unwrap_or(&0.0)
+    }
+
+    /// The weights scoring uses: the hand-set ones, or ones derived from the tiers. Kept in
+    /// attribute order so sums over them come out the same to the last bit every time.
+    pub fn effective_weights(&self, multipliers: &TierMultipliers) -> BTreeMap<Attribute, f32> {
+        match self.weighting {
+            RoleWeighting::Weights => self.attribute_weights.iter().map(|(attribute, weight)| (*attribute, *weight)).collect(),
+            RoleWeighting::Tiers => self
+                .attribute_tiers
+                .iter()
+                .map(|(attribute, tier)| (*attribute, multipliers.get(*tier)))
+                .collect(),
+        }
     }
 }
 
@@ -69,21 +182,40 @@ pub struct RoleSuitability {
     pub role_name: String,
     pub score: f32,        // Raw score
     pub percentage: f32,   // Percentage suitability (0-100)
+    /// Share (0-1) of the role's attribute weight the player has known values for
+    pub confidence: f32,
+    /// Percentage with every scouted range at its low end
+    pub pessimistic: f32,
+    /// Percentage with every scouted range at its high end
+    pub optimistic: f32,
 }
 
 impl RoleSuitability {
-    pub fn new(role_id: i64, role_name: String, score: f32, max_score: f32) -> Self {
-        let percentage = if max_score > 0.0 {
-            (score / max_score) * 100.0
-        } else {
-            0.0
-        };
-
+    pub fn new(role_id: i64, role_name: String, score: f32, max_score: f32, confidence: f32) -> Self {
+        let percentage = percentage_of(score, max_score);
         Self {
             role_id,
             role_name,
             score,
-            percentage: percentage.min(100.0),
+            percentage,
+            confidence,
+            pessimistic: percentage,
+            optimistic: percentage,
         }
     }
+
+    /// Set the spread from the scores at the low and high end of the player's scouted ranges
+    pub fn with_spread(mut self, pessimistic_score: f32, optimistic_score: f32, max_score: f32) -> Self {
+        self.pessimistic = percentage_of(pessimistic_score, max_score);
+        self.optimistic = percentage_of(optimistic_score, max_score);
+        self
+    }
+}
+
+fn percentage_of(score: f32, max_score: f32) -> f32 {
+    if max_score > 0.0 {
+        ((score / max_score) * 100.0).min(100.0)
+    } else {
+        0.0
+    }
 }
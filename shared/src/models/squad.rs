@@ -1,4 +1,6 @@
 use serde::{Deserialize, Serialize};
+use super::constraints::LineupConstraints;
+use super::formation::Formation;
 use super::player::{Player, Position};
 use super::tactical_role::TacticalRole;
 
@@ -28,6 +30,10 @@ pub struct Squad {
     pub average_rating: Option<f32>,
     pub total_value: Option<i64>,
     pub total_wage: Option<i64>,
+
+    /// Locked, excluded and minutes-capped players the squad was picked under
+    #[serde(default)]
+    pub constraints: LineupConstraints,
 }
 
 impl Squad {
@@ -42,6 +48,7 @@ impl Squad {
             average_rating: None,
             total_value: None,
             total_wage: None,
+            constraints: LineupConstraints::default(),
         }
     }
 
@@ -55,6 +62,67 @@ impl Squad {
         self.substitutes.push(player_id);
     }
 
+    /// The starter filling each of a formation's slots, in slot order. Starters are matched
+    /// to slots by position, those whose tactical role is the slot's first, so a hand-built XI
+    /// can be in any order. Fails unless there is exactly one starter per slot.
+    pub fn starters_by_slot(&self, formation: &Formation) -> Result<Vec<&SquadPlayer>, String> {
+        if self.starting_xi.len() != formation.positions.len() {
+            return Err(format!(
+                "The starting XI has {} players but {} has {} slots",
+                self.starting_xi.len(),
+                formation.name,
+                formation.positions.len()
+            ));
+        }
+
+        let mut by_slot: Vec<Option<&SquadPlayer>> = vec![None; formation.positions.len()];
+        let mut placed = vec![false; self.starting_xi.len()];
+        for same_role in [true, false] {
+            for (slot, position) in formation.positions.iter().enumerate() {
+                if by_slot[slot].is_some() {
+                    continue;
+                }
+                let starter = self.starting_xi.iter().enumerate().find(|(i, starter)| {
+                    !placed[*i]
+                        && starter.position == position.position
+                        && (!same_role || starter.tactical_role == Some(position.tactical_role_or_default()))
+                });
+                if let Some((i, starter)) = starter {
+                    placed[i] = true;
+                    by_slot[slot] = Some(starter);
+                }
+            }
+        }
+
+        by_slot
+            .into_iter()
+            .enumerate()
+            .map(|(slot, starter)| {
+                starter.ok_or_else(|| {
+                    format!(
+                        "Nobody in the starting XI plays slot {} ({}) of {}",
+                        slot,
+                        formation.positions[slot].position.as_str(),
+                        formation.name
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// A formation with each slot's tactical role set to the one its starter was picked for,
+    /// for scoring the slots the way the squad plays them
+    pub fn formation_as_picked(&self, formation: &Formation) -> Result<Formation, String> {
+        let starters = self.starters_by_slot(formation)?;
+        let mut picked = formation.clone();
+        for (position, starter) in picked.positions.iter_mut().zip(starters) {
+            if starter.tactical_role.is_some() {
+                position.tactical_role = starter.tactical_role;
+            }
+        }
+        Ok(picked)
+    }
+
     /// Calculate average suitability rating
     pub fn calculate_average_rating(&mut self) {
         let total: f32 = self
@@ -95,6 +163,106 @@ pub struct SquadWithPlayers {
     pub average_rating: Option<f32>,
     pub total_value: Option<i64>,
     pub total_wage: Option<i64>,
+
+    /// Who covers each of the formation's slots, best suited first
+    #[serde(default)]
+    pub depth_chart: Vec<SlotDepth>,
+}
+
+impl SquadWithPlayers {
+    /// Join a squad with its players' data. Players not in `players` are left out.
+    pub fn new(squad: &Squad, formation: &Formation, players: &[Player], depth_chart: Vec<SlotDepth>) -> Self {
+        let find = |id: i64| players.iter().find(|player| player.id == Some(id)).cloned();
+        Self {
+            id: squad.id,
+            name: squad.name.clone(),
+            formation_id: squad.formation_id,
+            formation_name: formation.name.clone(),
+            starting_xi: squad
+                .starting_xi
+                .iter()
+                .filter_map(|starter| {
+                    Some(SquadPlayerWithData {
+                        player: find(starter.player_id)?,
+                        position: starter.position,
+                        tactical_role: starter.tactical_role,
+                        suitability: starter.suitability,
+                    })
+                })
+                .collect(),
+            substitutes: squad.substitutes.iter().filter_map(|id| find(*id)).collect(),
+            average_rating: squad.average_rating,
+            total_value: squad.total_value,
+            total_wage: squad.total_wage,
+            depth_chart,
+        }
+    }
+}
+
+/// A player ranked for a formation slot
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DepthChartPlayer {
+    pub player_id: i64,
+    /// Suitability percentage (0-100) for the slot's role
+    pub suitability: f32,
+}
+
+/// The players best suited to one formation slot, best first
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SlotDepth {
+    pub position: Position,
+    pub tactical_role: TacticalRole,
+    pub players: Vec<DepthChartPlayer>,
+    /// The second choice is missing or below the cover threshold
+    pub thin_cover: bool,
+}
+
+/// How well the bench covers one starter's slot
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SlotCover {
+    pub position: Position,
+    pub tactical_role: TacticalRole,
+    pub starter_id: i64,
+    /// The substitute best suited to the slot, if any can be scored for it
+    pub substitute: Option<DepthChartPlayer>,
+    /// The substitute reaches the cover threshold
+    pub adequate: bool,
+}
+
+/// Substitutes picked for a squad, with how well they cover each slot
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bench {
+    pub substitutes: Vec<i64>,
+    pub coverage: Vec<SlotCover>,
+    /// Starters with no substitute reaching the cover threshold for their slot
+    pub uncovered_starters: Vec<i64>,
+}
+
+/// How well a formation suits the players, judged by its best XI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FormationFit {
+    pub formation_id: i64,
+    pub formation_name: String,
+    pub average_suitability: f32,
+    pub total_suitability: f32,
+    /// The starter who fits their slot worst
+    pub weakest_slot: SquadPlayer,
+    pub squad: Squad,
+}
+
+/// A formation no XI could be picked for, and why
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnfitFormation {
+    pub formation_id: i64,
+    pub formation_name: String,
+    pub reason: String,
+}
+
+/// Formations ranked by how well they suit the players
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FormationComparison {
+    pub ranking: Vec<FormationFit>,
+    pub unfit: Vec<UnfitFormation>,
 }
 
 /// Squad player with full player data
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use super::game_version::GameVersion;
 use super::player::Position;
 
 /// Player duty for a role
@@ -33,10 +34,12 @@ pub enum RoleType {
     WingBack,
     CompleteWingBack,
     InvertedWingBack,
+    InvertedFullBack,
     CentralDefender,
     BallPlayingDefender,
     Libero,
     NoChallengeDefender,
+    WideCentreBack,
 
     // Defensive Midfielder roles
     Anchor,
@@ -53,6 +56,7 @@ pub enum RoleType {
     DeepLyingMidfielderSupport,
     Mezzala,
     RoamingPlaymaker,
+    ChannelMidfielder,
 
     // Wide Midfielder roles
     Winger,
@@ -81,6 +85,70 @@ pub enum RoleType {
     TrequartistaForward,
 }
 
+impl RoleType {
+    /// The variant name, as stored in the database and used in JSON
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RoleType::Goalkeeper => "Goalkeeper",
+            RoleType::SweeperKeeper => "SweeperKeeper",
+            RoleType::FullBack => "FullBack",
+            RoleType::WingBack => "WingBack",
+            RoleType::CompleteWingBack => "CompleteWingBack",
+            RoleType::InvertedWingBack => "InvertedWingBack",
+            RoleType::InvertedFullBack => "InvertedFullBack",
+            RoleType::CentralDefender => "CentralDefender",
+            RoleType::BallPlayingDefender => "BallPlayingDefender",
+            RoleType::Libero => "Libero",
+            RoleType::NoChallengeDefender => "NoChallengeDefender",
+            RoleType::WideCentreBack => "WideCentreBack",
+            RoleType::Anchor => "Anchor",
+            RoleType::BallWinningMidfielder => "BallWinningMidfielder",
+            RoleType::DeepLyingPlaymaker => "DeepLyingPlaymaker",
+            RoleType::HalfBack => "HalfBack",
+            RoleType::Regista => "Regista",
+            RoleType::SegundoVolante => "SegundoVolante",
+            RoleType::BoxToBoxMidfielder => "BoxToBoxMidfielder",
+            RoleType::CentralMidfielder => "CentralMidfielder",
+            RoleType::Carrilero => "Carrilero",
+            RoleType::DeepLyingMidfielderSupport => "DeepLyingMidfielderSupport",
+            RoleType::Mezzala => "Mezzala",
+            RoleType::RoamingPlaymaker => "RoamingPlaymaker",
+            RoleType::ChannelMidfielder => "ChannelMidfielder",
+            RoleType::Winger => "Winger",
+            RoleType::InvertedWinger => "InvertedWinger",
+            RoleType::WideMidfielder => "WideMidfielder",
+            RoleType::WidePlaymaker => "WidePlaymaker",
+            RoleType::DefensiveWinger => "DefensiveWinger",
+            RoleType::AdvancedPlaymaker => "AdvancedPlaymaker",
+            RoleType::AttackingMidfielder => "AttackingMidfielder",
+            RoleType::Enganche => "Enganche",
+            RoleType::ShadowStriker => "ShadowStriker",
+            RoleType::Trequartista => "Trequartista",
+            RoleType::AdvancedForward => "AdvancedForward",
+            RoleType::CompleteForward => "CompleteForward",
+            RoleType::DeepLyingForward => "DeepLyingForward",
+            RoleType::FalseNine => "FalseNine",
+            RoleType::InsideForward => "InsideForward",
+            RoleType::Poacher => "Poacher",
+            RoleType::PressingForward => "PressingForward",
+            RoleType::Raumdeuter => "Raumdeuter",
+            RoleType::TargetMan => "TargetMan",
+            RoleType::TrequartistaForward => "TrequartistaForward",
+        }
+    }
+}
+
+impl std::str::FromStr for RoleType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TacticalRole::all_role_types()
+            .into_iter()
+            .find(|role| role.as_str() == s)
+            .ok_or_else(|| format!("Invalid role type: {}", s))
+    }
+}
+
 /// Valid tactical role combining position, role type, and duty
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct TacticalRole {
@@ -90,9 +158,9 @@ pub struct TacticalRole {
 }
 
 impl TacticalRole {
-    /// Create a new tactical role with validation
-    pub fn new(position: Position, role: RoleType, duty: Duty) -> Result<Self, String> {
-        if Self::is_valid_combination(position, role, duty) {
+    /// Create a new tactical role, checking the game version has it
+    pub fn new(position: Position, role: RoleType, duty: Duty, version: GameVersion) -> Result<Self, String> {
+        if Self::is_valid_combination(position, role, duty, version) {
             Ok(Self {
                 position,
                 role,
@@ -100,102 +168,25 @@ impl TacticalRole {
             })
         } else {
             Err(format!(
-                "Invalid combination: {:?} cannot play {:?} on {:?} duty",
-                role, position, duty
+                "Invalid combination: {:?} cannot play {:?} on {:?} duty in {}",
+                role, position, duty, version.as_str()
             ))
         }
     }
 
-    /// Check if a combination of position, role, and duty is valid
-    pub fn is_valid_combination(position: Position, role: RoleType, duty: Duty) -> bool {
-        use Position::*;
-        use RoleType::*;
-        use Duty::*;
-
-        match (position, role, duty) {
-            // Goalkeeper roles - only automatic duty
-            (GK, Goalkeeper, Automatic) => true,
-            (GK, SweeperKeeper, Automatic) => true,
-            (GK, _, _) => false,
-
-            // Defender roles (DR, DC, DL)
-            (DR | DL, FullBack, Defend | Support | Attack) => true,
-            (DR | DL, WingBack, Defend | Support | Attack) => true,
-            (DR | DL, CompleteWingBack, Support | Attack) => true,
-            (DR | DL, InvertedWingBack, Defend | Support | Attack) => true,
-            (DR | DL, NoChallengeDefender, Defend) => true,
-
-            (DC, CentralDefender, Defend | Support) => true,
-            (DC, BallPlayingDefender, Defend | Support) => true,
-            (DC, Libero, Defend | Support) => true,
-            (DC, NoChallengeDefender, Defend) => true,
-
-            // Wing-back positions (WBR, WBL)
-            (WBR | WBL, WingBack, Defend | Support | Attack) => true,
-            (WBR | WBL, CompleteWingBack, Support | Attack) => true,
-            (WBR | WBL, InvertedWingBack, Defend | Support | Attack) => true,
-
-            // Defensive Midfielder (DMC)
-            (DMC, Anchor, Defend) => true,
-            (DMC, BallWinningMidfielder, Defend | Support) => true,
-            (DMC, DeepLyingPlaymaker, Defend | Support) => true,
-            (DMC, HalfBack, Defend) => true,
-            (DMC, Regista, Support) => true,
-            (DMC, SegundoVolante, Support | Attack) => true,
-
-            // Central Midfielder (MC)
-            (MC, BoxToBoxMidfielder, Support) => true,
-            (MC, CentralMidfielder, Defend | Support | Attack) => true,
-            (MC, Carrilero, Support) => true,
-            (MC, DeepLyingMidfielderSupport, Support) => true,
-            (MC, Mezzala, Support | Attack) => true,
-            (MC, RoamingPlaymaker, Support) => true,
-            (MC, BallWinningMidfielder, Defend | Support) => true,
-            (MC, AdvancedPlaymaker, Support | Attack) => true,
-
-            // Wide Midfielders (MR, ML)
-            (MR | ML, Winger, Support | Attack) => true,
-            (MR | ML, InvertedWinger, Support | Attack) => true,
-            (MR | ML, WideMidfielder, Defend | Support | Attack) => true,
-            (MR | ML, WidePlaymaker, Support | Attack) => true,
-            (MR | ML, DefensiveWinger, Defend | Support) => true,
-
-            // Attacking Midfielders (AMR, AML, AMC)
-            (AMR | AML, Winger, Support | Attack) => true,
-            (AMR | AML, InvertedWinger, Support | Attack) => true,
-            (AMR | AML, WidePlaymaker, Support | Attack) => true,
-            (AMR | AML, InsideForward, Support | Attack) => true,
-            (AMR | AML, Raumdeuter, Attack) => true,
-
-            (AMC, AdvancedPlaymaker, Support | Attack) => true,
-            (AMC, AttackingMidfielder, Support | Attack) => true,
-            (AMC, Enganche, Support) => true,
-            (AMC, ShadowStriker, Attack) => true,
-            (AMC, Trequartista, Support) => true,
-
-            // Strikers (STC)
-            (STC, AdvancedForward, Attack) => true,
-            (STC, CompleteForward, Support | Attack) => true,
-            (STC, DeepLyingForward, Support | Attack) => true,
-            (STC, FalseNine, Support) => true,
-            (STC, Poacher, Attack) => true,
-            (STC, PressingForward, Defend | Support | Attack) => true,
-            (STC, TargetMan, Support | Attack) => true,
-            (STC, TrequartistaForward, Support) => true,
-
-            // All other combinations are invalid
-            _ => false,
-        }
+    /// Check if a combination of position, role, and duty is valid in the game version
+    pub fn is_valid_combination(position: Position, role: RoleType, duty: Duty, version: GameVersion) -> bool {
+        version.allows(position, role, duty)
     }
 
-    /// Get all valid tactical roles for a position
-    pub fn get_all_for_position(position: Position) -> Vec<TacticalRole> {
+    /// Get all valid tactical roles for a position in the game version
+    pub fn get_all_for_position(position: Position, version: GameVersion) -> Vec<TacticalRole> {
         let mut roles = Vec::new();
 
         // Try all role types with all duties
         for role in Self::all_role_types() {
             for duty in Self::all_duties() {
-                if Self::is_valid_combination(position, role, duty) {
+                if Self::is_valid_combination(position, role, duty, version) {
                     roles.push(TacticalRole {
                         position,
                         role,
@@ -208,6 +199,33 @@ impl TacticalRole {
         roles
     }
 
+    /// The generic role for a position, for slots no role has been chosen for. Valid in every
+    /// game version.
+    pub fn default_for(position: Position) -> TacticalRole {
+        use Position::*;
+        let (role, duty) = match position {
+            GK => (RoleType::Goalkeeper, Duty::Automatic),
+            DR | DL => (RoleType::FullBack, Duty::Support),
+            DC => (RoleType::CentralDefender, Duty::Defend),
+            WBR | WBL => (RoleType::WingBack, Duty::Support),
+            DMC => (RoleType::Anchor, Duty::Defend),
+            MC => (RoleType::CentralMidfielder, Duty::Support),
+            MR | ML => (RoleType::WideMidfielder, Duty::Support),
+            AMR | AML => (RoleType::Winger, Duty::Attack),
+            AMC => (RoleType::AttackingMidfielder, Duty::Support),
+            STC => (RoleType::AdvancedForward, Duty::Attack),
+        };
+        TacticalRole { position, role, duty }
+    }
+
+    /// Every valid tactical role in the game version, for every position
+    pub fn all_valid(version: GameVersion) -> Vec<TacticalRole> {
+        Position::ALL
+            .into_iter()
+            .flat_map(|position| Self::get_all_for_position(position, version))
+            .collect()
+    }
+
     /// Get all role types
     fn all_role_types() -> Vec<RoleType> {
         use RoleType::*;
@@ -220,10 +238,12 @@ impl TacticalRole {
             WingBack,
             CompleteWingBack,
             InvertedWingBack,
+            InvertedFullBack,
             CentralDefender,
             BallPlayingDefender,
             Libero,
             NoChallengeDefender,
+            WideCentreBack,
             // DM
             Anchor,
             BallWinningMidfielder,
@@ -238,6 +258,7 @@ impl TacticalRole {
             DeepLyingMidfielderSupport,
             Mezzala,
             RoamingPlaymaker,
+            ChannelMidfielder,
             // Wide
             Winger,
             InvertedWinger,
@@ -279,10 +300,12 @@ impl TacticalRole {
             WingBack => "Wing Back",
             CompleteWingBack => "Complete Wing Back",
             InvertedWingBack => "Inverted Wing Back",
+            InvertedFullBack => "Inverted Full Back",
             CentralDefender => "Central Defender",
             BallPlayingDefender => "Ball-Playing Defender",
             Libero => "Libero",
             NoChallengeDefender => "No-Challenge Defender",
+            WideCentreBack => "Wide Centre-Back",
             Anchor => "Anchor",
             BallWinningMidfielder => "Ball-Winning Midfielder",
             DeepLyingPlaymaker => "Deep-Lying Playmaker",
@@ -295,6 +318,7 @@ impl TacticalRole {
             DeepLyingMidfielderSupport => "Deep-Lying Midfielder",
             Mezzala => "Mezzala",
             RoamingPlaymaker => "Roaming Playmaker",
+            ChannelMidfielder => "Channel Midfielder",
             Winger => "Winger",
             InvertedWinger => "Inverted Winger",
             WideMidfielder => "Wide Midfielder",
@@ -335,29 +359,66 @@ mod tests {
 
     #[test]
     fn test_valid_goalkeeper() {
-        assert!(TacticalRole::new(Position::GK, RoleType::Goalkeeper, Duty::Automatic).is_ok());
-        assert!(TacticalRole::new(Position::GK, RoleType::Goalkeeper, Duty::Defend).is_err());
+        assert!(TacticalRole::new(Position::GK, RoleType::Goalkeeper, Duty::Automatic, GameVersion::FM23).is_ok());
+        assert!(TacticalRole::new(Position::GK, RoleType::Goalkeeper, Duty::Defend, GameVersion::FM23).is_err());
     }
 
     #[test]
     fn test_valid_fullback() {
-        assert!(TacticalRole::new(Position::DR, RoleType::FullBack, Duty::Support).is_ok());
-        assert!(TacticalRole::new(Position::DR, RoleType::FullBack, Duty::Attack).is_ok());
-        assert!(TacticalRole::new(Position::DR, RoleType::FullBack, Duty::Automatic).is_err());
+        assert!(TacticalRole::new(Position::DR, RoleType::FullBack, Duty::Support, GameVersion::FM23).is_ok());
+        assert!(TacticalRole::new(Position::DR, RoleType::FullBack, Duty::Attack, GameVersion::FM23).is_ok());
+        assert!(TacticalRole::new(Position::DR, RoleType::FullBack, Duty::Automatic, GameVersion::FM23).is_err());
     }
 
     #[test]
     fn test_invalid_position_role() {
         // Can't have a striker role at defender position
-        assert!(TacticalRole::new(Position::DC, RoleType::Poacher, Duty::Attack).is_err());
+        assert!(TacticalRole::new(Position::DC, RoleType::Poacher, Duty::Attack, GameVersion::FM23).is_err());
     }
 
     #[test]
     fn test_get_all_for_position() {
-        let gk_roles = TacticalRole::get_all_for_position(Position::GK);
+        let gk_roles = TacticalRole::get_all_for_position(Position::GK, GameVersion::FM24);
         assert_eq!(gk_roles.len(), 2); // Goalkeeper and Sweeper Keeper
 
-        let striker_roles = TacticalRole::get_all_for_position(Position::STC);
+        let striker_roles = TacticalRole::get_all_for_position(Position::STC, GameVersion::FM24);
         assert!(striker_roles.len() > 5); // Multiple striker roles
     }
+
+    #[test]
+    fn test_role_type_round_trip() {
+        for role in TacticalRole::all_role_types() {
+            assert_eq!(role.as_str().parse::<RoleType>(), Ok(role));
+            assert_eq!(serde_json::to_string(&role).unwrap(), format!("\"{}\"", role.as_str()));
+        }
+        assert!("Sweeper".parse::<RoleType>().is_err());
+    }
+
+    #[test]
+    fn test_get_all_for_position_follows_version() {
+        let fm23 = TacticalRole::get_all_for_position(Position::DC, GameVersion::FM23);
+        let fm24 = TacticalRole::get_all_for_position(Position::DC, GameVersion::FM24);
+        assert!(!fm23.iter().any(|r| r.role == RoleType::WideCentreBack));
+        assert_eq!(fm24.iter().filter(|r| r.role == RoleType::WideCentreBack).count(), 3);
+    }
+
+    #[test]
+    fn test_default_roles_are_valid() {
+        for version in GameVersion::ALL {
+            for position in Position::ALL {
+                let default = TacticalRole::default_for(position);
+                assert!(TacticalRole::new(position, default.role, default.duty, version).is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn test_all_valid() {
+        assert_eq!(TacticalRole::all_valid(GameVersion::FM23).len(), 131);
+        assert_eq!(TacticalRole::all_valid(GameVersion::FM24).len(), 137);
+        for version in GameVersion::ALL {
+            let all = TacticalRole::all_valid(version);
+            assert!(all.iter().all(|r| TacticalRole::new(r.position, r.role, r.duty, version).is_ok()));
+        }
+    }
 }
@@ -27,6 +27,23 @@ impl Mentality {
     }
 }
 
+impl std::str::FromStr for Mentality {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Very Defensive" => Ok(Mentality::VeryDefensive),
+            "Defensive" => Ok(Mentality::Defensive),
+            "Cautious" => Ok(Mentality::Cautious),
+            "Balanced" => Ok(Mentality::Balanced),
+            "Positive" => Ok(Mentality::Positive),
+            "Attacking" => Ok(Mentality::Attacking),
+            "Very Attacking" => Ok(Mentality::VeryAttacking),
+            _ => Err(format!("Invalid mentality: {}", s)),
+        }
+    }
+}
+
 /// Team width setting
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum Width {
@@ -37,6 +54,33 @@ pub enum Width {
     VeryWide,
 }
 
+impl Width {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Width::VeryNarrow => "Very Narrow",
+            Width::Narrow => "Narrow",
+            Width::Standard => "Standard",
+            Width::Wide => "Wide",
+            Width::VeryWide => "Very Wide",
+        }
+    }
+}
+
+impl std::str::FromStr for Width {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Very Narrow" => Ok(Width::VeryNarrow),
+            "Narrow" => Ok(Width::Narrow),
+            "Standard" => Ok(Width::Standard),
+            "Wide" => Ok(Width::Wide),
+            "Very Wide" => Ok(Width::VeryWide),
+            _ => Err(format!("Invalid width: {}", s)),
+        }
+    }
+}
+
 /// Team tempo setting
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum Tempo {
@@ -47,6 +91,33 @@ pub enum Tempo {
     VeryFast,
 }
 
+impl Tempo {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Tempo::VerySlow => "Very Slow",
+            Tempo::Slow => "Slow",
+            Tempo::Standard => "Standard",
+            Tempo::Fast => "Fast",
+            Tempo::VeryFast => "Very Fast",
+        }
+    }
+}
+
+impl std::str::FromStr for Tempo {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Very Slow" => Ok(Tempo::VerySlow),
+            "Slow" => Ok(Tempo::Slow),
+            "Standard" => Ok(Tempo::Standard),
+            "Fast" => Ok(Tempo::Fast),
+            "Very Fast" => Ok(Tempo::VeryFast),
+            _ => Err(format!("Invalid tempo: {}", s)),
+        }
+    }
+}
+
 /// Pressing intensity
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum PressingIntensity {
@@ -57,6 +128,33 @@ pub enum PressingIntensity {
     MuchMore,
 }
 
+impl PressingIntensity {
+    pub fn as_str(&self) -> &str {
+        match self {
+            PressingIntensity::MuchLess => "Much Less",
+            PressingIntensity::Less => "Less",
+            PressingIntensity::Standard => "Standard",
+            PressingIntensity::More => "More",
+            PressingIntensity::MuchMore => "Much More",
+        }
+    }
+}
+
+impl std::str::FromStr for PressingIntensity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Much Less" => Ok(PressingIntensity::MuchLess),
+            "Less" => Ok(PressingIntensity::Less),
+            "Standard" => Ok(PressingIntensity::Standard),
+            "More" => Ok(PressingIntensity::More),
+            "Much More" => Ok(PressingIntensity::MuchMore),
+            _ => Err(format!("Invalid pressing intensity: {}", s)),
+        }
+    }
+}
+
 /// Defensive line height
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum DefensiveLine {
@@ -67,6 +165,33 @@ pub enum DefensiveLine {
     MuchHigher,
 }
 
+impl DefensiveLine {
+    pub fn as_str(&self) -> &str {
+        match self {
+            DefensiveLine::MuchDeeper => "Much Deeper",
+            DefensiveLine::Deeper => "Deeper",
+            DefensiveLine::Standard => "Standard",
+            DefensiveLine::Higher => "Higher",
+            DefensiveLine::MuchHigher => "Much Higher",
+        }
+    }
+}
+
+impl std::str::FromStr for DefensiveLine {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Much Deeper" => Ok(DefensiveLine::MuchDeeper),
+            "Deeper" => Ok(DefensiveLine::Deeper),
+            "Standard" => Ok(DefensiveLine::Standard),
+            "Higher" => Ok(DefensiveLine::Higher),
+            "Much Higher" => Ok(DefensiveLine::MuchHigher),
+            _ => Err(format!("Invalid defensive line: {}", s)),
+        }
+    }
+}
+
 /// Tactical configuration for a team
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tactics {
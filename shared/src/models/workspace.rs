@@ -0,0 +1,29 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use super::game_version::GameVersion;
+
+/// One FM save (career) on the server. Players, squads, tactics and custom
+/// formations, roles and mapping profiles all belong to a workspace.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Workspace {
+    pub id: Option<i64>,
+    pub name: String, // e.g., "Wrexham 2031"
+
+    /// The save's in-game date
+    pub game_date: NaiveDate,
+
+    /// The FM release the save comes from, which decides the roles on offer
+    #[serde(default)]
+    pub game_version: GameVersion,
+}
+
+impl Workspace {
+    pub fn new(name: String, game_date: NaiveDate) -> Self {
+        Self {
+            id: None,
+            name,
+            game_date,
+            game_version: GameVersion::default(),
+        }
+    }
+}
@@ -0,0 +1,258 @@
+//! Squad gap analysis and recruitment briefs.
+//!
+//! Every slot of a formation is measured against a target suitability by the player the
+//! best XI puts there: players are assigned to slots as in selection, so nobody fills two
+//! slots. Where a slot's player falls short of the target, the report names the attributes
+//! costing them the most and writes a brief for the player to sign: someone who knows the
+//! position, with each of the role's key attributes at the value that would reach the
+//! target if every attribute were there.
+
+use std::collections::HashMap;
+use crate::models::{
+    Attribute, AttributeShortfall, Familiarity, Formation, GapReport, Player, RecruitmentBrief, Role, SlotGap,
+};
+use crate::scoring::{ScoringOptions, MAX_ATTRIBUTE_VALUE, MIN_ATTRIBUTE_VALUE};
+use crate::selection::{assign_players, check_roles};
+
+/// Limiting attributes reported per slot
+pub const LIMITING_ATTRIBUTES: usize = 3;
+
+/// Attributes weighted at least this share of a role's heaviest weight are its key attributes
+pub const KEY_WEIGHT_SHARE: f32 = 0.75;
+
+/// Suitability percentage slots are measured against unless told otherwise
+pub const DEFAULT_TARGET: f32 = 70.0;
+
+/// Oldest age a brief asks for unless told otherwise
+pub const DEFAULT_MAX_AGE: i32 = 28;
+
+/// Find the slots of a formation where the best XI's player falls short of `target` (a
+/// suitability percentage). `roles` holds the role scored for each slot, in order. Briefs cap age at `max_age`
+/// (`DEFAULT_MAX_AGE` when unset) and wages at `max_wage`, or at the highest wage any of
+/// the players earns when unset.
+pub fn gap_report(
+    formation: &Formation,
+    roles: &[Role],
+    players: &[Player],
+    options: &ScoringOptions,
+    target: f32,
+    max_age: Option<i32>,
+    max_wage: Option<i64>,
+) -> Result<GapReport, String> {
+    check_roles(formation, roles)?;
+    let max_wage = max_wage.or_else(|| players.iter().filter_map(|player| player.wage).max());
+
+    let mut players: Vec<&Player> = players.iter().collect();
+    players.sort_by_key(|player| player.id);
+    let assigned = assign_players(&roles.iter().collect::<Vec<_>>(), &players, options);
+
+    let mut gaps = Vec::new();
+    for (slot, ((position, role), best)) in formation.positions.iter().zip(roles).zip(assigned).enumerate() {
+        if best.is_some_and(|(_, percentage)| percentage >= target) {
+            continue;
+        }
+
+        let tactical_role = position.tactical_role_or_default();
+        gaps.push(SlotGap {
+            slot,
+            position: position.position,
+            tactical_role,
+            role_name: role.name.clone(),
+            best_player_id: best.and_then(|(player, _)| player.id),
+            best_suitability: best.map(|(_, percentage)| percentage),
+            limiting_attributes: best
+                .map(|(player, _)| limiting_attributes(player, role, options))
+                .unwrap_or_default(),
+            unknown_attributes: best
+                .map(|(player, _)| unknown_attributes(player, role, options))
+                .unwrap_or_default(),
+            brief: RecruitmentBrief {
+                position: position.position,
+                tactical_role,
+                min_familiarity: Familiarity::Accomplished,
+                min_attributes: key_attribute_minimums(role, options, target),
+                max_age: Some(max_age.unwrap_or(DEFAULT_MAX_AGE)),
+                max_wage,
+            },
+        });
+    }
+
+    Ok(GapReport {
+        formation_id: formation.id.unwrap_or_default(),
+        target,
+        gaps,
+    })
+}
+
+/// The value each weighted attribute of a role is scored with for a player, as `score_role`
+/// scores it, or `None` for an unknown attribute the missing attribute policy leaves out
+fn scored_values(player: &Player, role: &Role, options: &ScoringOptions) -> Vec<(Attribute, f32, Option<f32>)> {
+    role.effective_weights(&options.tier_multipliers)
+        .into_iter()
+        .map(|(attribute, weight)| {
+            let value = match attribute.range(player) {
+                Some(range) => Some(attribute.value(player).unwrap_or(range.midpoint()) as f32),
+                None => options.fill(player, attribute),
+            };
+            (attribute, weight, value)
+        })
+        .collect()
+}
+
+/// The attributes costing a player the most suitability in a role, worst first. Only
+/// attributes that were scored count, each against its share of the scored weight.
+fn limiting_attributes(player: &Player, role: &Role, options: &ScoringOptions) -> Vec<AttributeShortfall> {
+    let values = scored_values(player, role, options);
+    let scored_weight: f32 = values.iter().filter(|(_, _, value)| value.is_some()).map(|(_, weight, _)| weight).sum();
+    if scored_weight <= 0.0 {
+        return Vec::new();
+    }
+
+    let mut shortfalls: Vec<AttributeShortfall> = values
+        .into_iter()
+        .filter_map(|(attribute, weight, value)| {
+            let missing = (MAX_ATTRIBUTE_VALUE as f32 - value?) / MAX_ATTRIBUTE_VALUE as f32;
+            Some(AttributeShortfall {
+                attribute,
+                value: attribute.value(player),
+                lost: weight / scored_weight * missing * 100.0,
+            })
+        })
+        .filter(|shortfall| shortfall.lost > 0.0)
+        .collect();
+    shortfalls.sort_by(|a, b| b.lost.total_cmp(&a.lost).then(a.attribute.cmp(&b.attribute)));
+    shortfalls.truncate(LIMITING_ATTRIBUTES);
+    shortfalls
+}
+
+/// A role's attributes the player has no value for and that were left out of their score
+fn unknown_attributes(player: &Player, role: &Role, options: &ScoringOptions) -> Vec<Attribute> {
+    scored_values(player, role, options)
+        .into_iter()
+        .filter(|(_, _, value)| value.is_none())
+        .map(|(attribute, _, _)| attribute)
+        .collect()
+}
+
+/// The value each of a role's key attributes needs for a player to reach `target`
+fn key_attribute_minimums(role: &Role, options: &ScoringOptions, target: f32) -> HashMap<Attribute, i32> {
+    let weights = role.effective_weights(&options.tier_multipliers);
+    let heaviest = weights.values().copied().fold(0.0, f32::max);
+    let minimum = ((target / 100.0 * MAX_ATTRIBUTE_VALUE as f32).ceil() as i32).clamp(MIN_ATTRIBUTE_VALUE, MAX_ATTRIBUTE_VALUE);
+    weights
+        .into_iter()
+        .filter(|(_, weight)| heaviest > 0.0 && *weight >= heaviest * KEY_WEIGHT_SHARE)
+        .map(|(attribute, _)| (attribute, minimum))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::models::{AttributeTier, Position};
+    use crate::scoring::MissingAttributePolicy;
+
+    fn formation() -> Formation {
+        let mut formation = fixtures::formation("Two-a-side", &[Position::GK, Position::DC]);
+        formation.id = Some(4);
+        formation
+    }
+
+    fn roles() -> Vec<Role> {
+        let role = |position: Position, tiers: &[(Attribute, AttributeTier)]| {
+            fixtures::role(position.as_str(), position, &[]).with_tiers(tiers.iter().copied().collect())
+        };
+        vec![
+            role(Position::GK, &[(Attribute::Reflexes, AttributeTier::Key)]),
+            role(
+                Position::DC,
+                &[
+                    (Attribute::Tackling, AttributeTier::Key),
+                    (Attribute::Marking, AttributeTier::Key),
+                    (Attribute::Pace, AttributeTier::Preferred),
+                ],
+            ),
+        ]
+    }
+
+    fn players() -> Vec<Player> {
+        let mut keeper = fixtures::player(1, "Keeper", Position::GK, &[(Attribute::Reflexes, 17)]);
+        keeper.age = 30;
+        keeper.wage = Some(20_000);
+
+        let defender = [(Attribute::Tackling, 15), (Attribute::Marking, 9), (Attribute::Pace, 11)];
+        let mut defender = fixtures::player(2, "Defender", Position::DC, &defender);
+        defender.age = 26;
+        defender.wage = Some(35_000);
+        vec![keeper, defender]
+    }
+
+    #[test]
+    fn test_gap_report() {
+        let report = gap_report(&formation(), &roles(), &players(), &ScoringOptions::default(), 70.0, None, None).unwrap();
+        assert_eq!(report.formation_id, 4);
+
+        // The keeper reaches 85%; the defender only 59%
+        assert_eq!(report.gaps.len(), 1);
+        let gap = &report.gaps[0];
+        assert_eq!((gap.slot, gap.position), (1, Position::DC));
+        assert_eq!(gap.best_player_id, Some(2));
+        assert!((gap.best_suitability.unwrap() - 59.0).abs() < 0.01);
+
+        // Marking costs 11/20 of its 40% share, tackling 5/20 of 40% and pace 9/20 of 20%
+        let limiting: Vec<Attribute> = gap.limiting_attributes.iter().map(|s| s.attribute).collect();
+        assert_eq!(limiting, vec![Attribute::Marking, Attribute::Tackling, Attribute::Pace]);
+        assert!((gap.limiting_attributes[0].lost - 22.0).abs() < 0.01);
+
+        let brief = &gap.brief;
+        assert_eq!(brief.min_attributes, HashMap::from([(Attribute::Tackling, 14), (Attribute::Marking, 14)]));
+        assert_eq!(brief.max_age, Some(DEFAULT_MAX_AGE));
+        assert_eq!(brief.max_wage, Some(35_000));
+        assert!(!brief.matches(&players()[1]));
+    }
+
+    #[test]
+    fn test_unknown_attributes_follow_policy() {
+        let mut players = players();
+        players[1].pace = None;
+
+        // Ignored: pace didn't lower the score, so it's listed apart and the rest share 100%
+        let report = gap_report(&formation(), &roles(), &players, &ScoringOptions::default(), 70.0, None, None).unwrap();
+        let gap = &report.gaps[0];
+        assert_eq!(gap.unknown_attributes, vec![Attribute::Pace]);
+        let limiting: Vec<(Attribute, f32)> = gap.limiting_attributes.iter().map(|s| (s.attribute, s.lost)).collect();
+        assert_eq!(limiting, vec![(Attribute::Marking, 27.5), (Attribute::Tackling, 12.5)]);
+
+        // Scored as the minimum, it costs 19/20 of its 20% share
+        let minimum = ScoringOptions::new(MissingAttributePolicy::Minimum, 0.0, &[]);
+        let report = gap_report(&formation(), &roles(), &players, &minimum, 70.0, None, None).unwrap();
+        let gap = &report.gaps[0];
+        assert!(gap.unknown_attributes.is_empty());
+        let pace = gap.limiting_attributes.iter().find(|s| s.attribute == Attribute::Pace).unwrap();
+        assert_eq!(pace.value, None);
+        assert!((pace.lost - 19.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_player_fills_one_slot() {
+        // Good enough for both slots, but can only fill one
+        let attributes = [(Attribute::Reflexes, 17), (Attribute::Tackling, 16), (Attribute::Marking, 16), (Attribute::Pace, 16)];
+        let allrounder = fixtures::player(3, "Allrounder", Position::GK, &attributes);
+
+        let report = gap_report(&formation(), &roles(), &[allrounder], &ScoringOptions::default(), 70.0, None, None).unwrap();
+        assert_eq!(report.gaps.len(), 1);
+        assert_eq!(report.gaps[0].position, Position::DC);
+        assert_eq!(report.gaps[0].best_player_id, None);
+    }
+
+    #[test]
+    fn test_gap_report_without_candidates() {
+        let report = gap_report(&formation(), &roles(), &[], &ScoringOptions::default(), 50.0, Some(24), Some(10_000)).unwrap();
+        assert_eq!(report.gaps.len(), 2);
+        assert_eq!(report.gaps[0].best_player_id, None);
+        assert!(report.gaps[0].limiting_attributes.is_empty());
+        assert_eq!(report.gaps[0].brief.min_attributes, HashMap::from([(Attribute::Reflexes, 10)]));
+        assert_eq!((report.gaps[1].brief.max_age, report.gaps[1].brief.max_wage), (Some(24), Some(10_000)));
+    }
+}
@@ -0,0 +1,373 @@
+//! Role suitability scoring.
+//!
+//! A player's score for a role is the sum of each weighted attribute's value times its
+//! weight; the maximum is what a player with 20 in every weighted attribute would score.
+//! Both the backend and the frontend score through this module so they always agree.
+//!
+//! Scouted players often have gaps, so how missing attributes are scored is chosen with
+//! a [`MissingAttributePolicy`], and every result reports its confidence: the share of
+//! the role's weight whose attributes were actually known. Attributes scouted only as a
+//! range score their midpoint, with pessimistic and optimistic percentages taken from
+//! the low and high ends. Players slotted outside their natural position can be scored
+//! down by their familiarity with it through [`FamiliarityMultipliers`].
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use crate::models::{Attribute, Familiarity, Player, Position, Role, RoleSuitability, TierMultipliers};
+
+/// Highest value a 1-20 attribute can take
+pub const MAX_ATTRIBUTE_VALUE: i32 = 20;
+
+/// Lowest value a 1-20 attribute can take
+pub const MIN_ATTRIBUTE_VALUE: i32 = 1;
+
+/// How attributes a player has no value for are scored
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MissingAttributePolicy {
+    /// Leave them out of both the score and the maximum
+    #[default]
+    Ignore,
+    /// Use the average of players in the same position, or of every player when
+    /// nobody in that position has the attribute; ignored when nobody has it
+    Impute,
+    /// Score them as the lowest possible value
+    Minimum,
+}
+
+/// Average value of each attribute across a group of players, per position and overall
+#[derive(Debug, Clone, Default)]
+pub struct AttributeAverages {
+    by_position: HashMap<Position, HashMap<Attribute, f32>>,
+    overall: HashMap<Attribute, f32>,
+}
+
+impl AttributeAverages {
+    pub fn from_players(players: &[Player]) -> Self {
+        let mut by_position: HashMap<Position, HashMap<Attribute, (f32, f32)>> = HashMap::new();
+        let mut overall: HashMap<Attribute, (f32, f32)> = HashMap::new();
+
+        for player in players {
+            for attribute in Attribute::ALL {
+                let Some(value) = attribute.value(player) else { continue };
+                for totals in [by_position.entry(player.position).or_default(), &mut overall] {
+                    let (sum, count) = totals.entry(attribute).or_default();
+                    *sum += value as f32;
+                    *count += 1.0;
+                }
+            }
+        }
+
+        let mean = |totals: HashMap<Attribute, (f32, f32)>| -> HashMap<Attribute, f32> {
+            totals.into_iter().map(|(attribute, (sum, count))| (attribute, sum / count)).collect()
+        };
+        Self {
+            by_position: by_position.into_iter().map(|(position, totals)| (position, mean(totals))).collect(),
+            overall: mean(overall),
+        }
+    }
+
+    /// Average for players in the position, falling back to every player
+    pub fn get(&self, position: Position, attribute: Attribute) -> Option<f32> {
+        self.by_position
+            .get(&position)
+            .and_then(|averages| averages.get(&attribute))
+            .or_else(|| self.overall.get(&attribute))
+            .copied()
+    }
+}
+
+/// Share of the score a player keeps in a role's position at each familiarity level
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FamiliarityMultipliers {
+    pub natural: f32,
+    pub accomplished: f32,
+    pub competent: f32,
+    pub unconvincing: f32,
+    pub awkward: f32,
+}
+
+impl Default for FamiliarityMultipliers {
+    fn default() -> Self {
+        Self {
+            natural: 1.0,
+            accomplished: 0.95,
+            competent: 0.85,
+            unconvincing: 0.7,
+            awkward: 0.5,
+        }
+    }
+}
+
+impl FamiliarityMultipliers {
+    pub fn get(&self, familiarity: Familiarity) -> f32 {
+        match familiarity {
+            Familiarity::Natural => self.natural,
+            Familiarity::Accomplished => self.accomplished,
+            Familiarity::Competent => self.competent,
+            Familiarity::Unconvincing => self.unconvincing,
+            Familiarity::Awkward => self.awkward,
+        }
+    }
+}
+
+/// How suitability is scored and which results are kept
+#[derive(Debug, Clone, Default)]
+pub struct ScoringOptions {
+    pub missing: MissingAttributePolicy,
+    /// Averages the `Impute` policy draws on
+    pub averages: AttributeAverages,
+    /// Results whose confidence (0-1) falls below this are left out
+    pub min_confidence: f32,
+    /// Weights for roles that are weighted by tiers
+    pub tier_multipliers: TierMultipliers,
+    /// Scale scores by the player's familiarity with the role's position; no penalty when unset
+    pub familiarity: Option<FamiliarityMultipliers>,
+}
+
+impl ScoringOptions {
+    /// Options for a policy; `players` is only used to compute averages for `Impute`
+    pub fn new(missing: MissingAttributePolicy, min_confidence: f32, players: &[Player]) -> Self {
+        let averages = match missing {
+            MissingAttributePolicy::Impute => AttributeAverages::from_players(players),
+            _ => AttributeAverages::default(),
+        };
+        Self {
+            missing,
+            averages,
+            min_confidence,
+            tier_multipliers: TierMultipliers::default(),
+            familiarity: None,
+        }
+    }
+
+    /// Whether a result is confident enough to be used
+    pub fn accepts(&self, suitability: &RoleSuitability) -> bool {
+        suitability.confidence >= self.min_confidence
+    }
+
+    /// The value to score a missing attribute with, or `None` to leave it out
+    pub(crate) fn fill(&self, player: &Player, attribute: Attribute) -> Option<f32> {
+        match self.missing {
+            MissingAttributePolicy::Ignore => None,
+            MissingAttributePolicy::Impute => self.averages.get(player.position, attribute),
+            MissingAttributePolicy::Minimum => Some(MIN_ATTRIBUTE_VALUE as f32),
+        }
+    }
+}
+
+/// How well a player suits a role
+pub fn score_role(player: &Player, role: &Role, options: &ScoringOptions) -> RoleSuitability {
+    let mut score = 0.0;
+    let mut pessimistic = 0.0;
+    let mut optimistic = 0.0;
+    let mut scored_weight = 0.0;
+    let mut known_weight = 0.0;
+    let mut total_weight = 0.0;
+
+    for (attribute, weight) in &role.effective_weights(&options.tier_multipliers) {
+        total_weight += weight;
+        // (low, expected, high)
+        let values = match attribute.range(player) {
+            Some(range) => {
+                known_weight += weight;
+                let expected = attribute.value(player).unwrap_or(range.midpoint());
+                Some((range.low as f32, expected as f32, range.high as f32))
+            }
+            None => options.fill(player, *attribute).map(|value| (value, value, value)),
+        };
+        if let Some((low, expected, high)) = values {
+            pessimistic += low * weight;
+            score += expected * weight;
+            optimistic += high * weight;
+            scored_weight += weight;
+        }
+    }
+
+    if let Some(multipliers) = &options.familiarity {
+        let multiplier = multipliers.get(player.familiarity(role.position));
+        score *= multiplier;
+        pessimistic *= multiplier;
+        optimistic *= multiplier;
+    }
+
+    let max_score = MAX_ATTRIBUTE_VALUE as f32 * scored_weight;
+    let confidence = if total_weight > 0.0 { known_weight / total_weight } else { 1.0 };
+    RoleSuitability::new(role.id.unwrap_or_default(), role.name.clone(), score, max_score, confidence)
+        .with_spread(pessimistic, optimistic, max_score)
+}
+
+/// How well a player suits each of the given roles, best suited first.
+/// Roles scored with less than the minimum confidence are left out.
+pub fn score_roles(player: &Player, roles: &[Role], options: &ScoringOptions) -> Vec<RoleSuitability> {
+    let mut scores: Vec<RoleSuitability> = roles
+        .iter()
+        .map(|role| score_role(player, role, options))
+        .filter(|suitability| options.accepts(suitability))
+        .collect();
+    scores.sort_by(|a, b| b.percentage.total_cmp(&a.percentage));
+    scores
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{AttributeRange, AttributeTier, Duty, RoleType};
+
+    fn role(id: i64, name: &str, weights: &[(Attribute, f32)]) -> Role {
+        let weights: HashMap<Attribute, f32> = weights.iter().copied().collect();
+        let mut role = Role::new(name.to_string(), RoleType::Poacher, Position::STC, Duty::Attack, weights);
+        role.id = Some(id);
+        role
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-4, "{} != {}", actual, expected);
+    }
+
+    #[test]
+    fn test_score_role() {
+        let mut player = Player::new("Striker".to_string(), 24, Position::STC);
+        player.finishing = Some(16);
+        player.pace = Some(10);
+
+        let poacher = role(1, "Poacher", &[(Attribute::Finishing, 1.0), (Attribute::Pace, 0.5)]);
+        let suitability = score_role(&player, &poacher, &ScoringOptions::default());
+        assert_eq!(suitability.role_id, 1);
+        assert_eq!(suitability.score, 21.0);
+        assert_close(suitability.percentage, 70.0);
+        assert_eq!(suitability.confidence, 1.0);
+    }
+
+    #[test]
+    fn test_missing_attribute_policies() {
+        let mut player = Player::new("Scouted".to_string(), 21, Position::STC);
+        player.finishing = Some(16);
+        let poacher = role(1, "Poacher", &[(Attribute::Finishing, 1.0), (Attribute::Pace, 0.5)]);
+
+        // Ignored: only finishing is scored, out of 20
+        let ignored = score_role(&player, &poacher, &ScoringOptions::default());
+        assert_eq!(ignored.score, 16.0);
+        assert_close(ignored.percentage, 80.0);
+        assert_close(ignored.confidence, 1.0 / 1.5);
+
+        let minimum = ScoringOptions::new(MissingAttributePolicy::Minimum, 0.0, &[]);
+        let minimum = score_role(&player, &poacher, &minimum);
+        assert_eq!(minimum.score, 16.5);
+        assert_close(minimum.percentage, 16.5 / 30.0 * 100.0);
+        assert_close(minimum.confidence, 1.0 / 1.5);
+
+        // Imputed from the other strikers rather than the quick winger
+        let mut striker = Player::new("Other Striker".to_string(), 25, Position::STC);
+        striker.pace = Some(12);
+        let mut winger = Player::new("Winger".to_string(), 22, Position::AMR);
+        winger.pace = Some(18);
+        let impute = ScoringOptions::new(MissingAttributePolicy::Impute, 0.0, &[striker, winger]);
+        assert_eq!(score_role(&player, &poacher, &impute).score, 22.0);
+
+        // A position nobody else plays falls back to every player
+        player.position = Position::DC;
+        assert_eq!(score_role(&player, &poacher, &impute).score, 23.5);
+
+        // Nothing to impute from: ignored
+        let impute = ScoringOptions::new(MissingAttributePolicy::Impute, 0.0, &[player.clone()]);
+        assert_close(score_role(&player, &poacher, &impute).percentage, 80.0);
+    }
+
+    #[test]
+    fn test_scouted_range_spread() {
+        let mut player = Player::new("Target".to_string(), 20, Position::STC);
+        player.set_attribute_range(Attribute::Finishing, AttributeRange::new(11, 15));
+        player.pace = Some(10);
+        let poacher = role(1, "Poacher", &[(Attribute::Finishing, 1.0), (Attribute::Pace, 1.0)]);
+
+        let suitability = score_role(&player, &poacher, &ScoringOptions::default());
+        assert_eq!(suitability.score, 23.0);
+        assert_close(suitability.percentage, 57.5);
+        assert_close(suitability.pessimistic, 52.5);
+        assert_close(suitability.optimistic, 62.5);
+        assert_eq!(suitability.confidence, 1.0);
+
+        // Fully known players have no spread
+        player.set_attribute_range(Attribute::Finishing, AttributeRange::exact(13));
+        let suitability = score_role(&player, &poacher, &ScoringOptions::default());
+        assert_eq!(suitability.pessimistic, suitability.optimistic);
+    }
+
+    #[test]
+    fn test_tier_weighting() {
+        let mut player = Player::new("Poacher".to_string(), 26, Position::STC);
+        player.finishing = Some(18);
+        player.pace = Some(8);
+
+        let tiers = HashMap::from([
+            (Attribute::Finishing, AttributeTier::Key),
+            (Attribute::Pace, AttributeTier::Preferred),
+        ]);
+        // The hand-set weights are ignored once the role is weighted by tiers
+        let poacher = role(1, "Poacher", &[(Attribute::Pace, 1.0)]).with_tiers(tiers);
+
+        let mut options = ScoringOptions::default();
+        assert_eq!(score_role(&player, &poacher, &options).score, 22.0);
+
+        options.tier_multipliers = TierMultipliers { key: 2.0, preferred: 1.0 };
+        let suitability = score_role(&player, &poacher, &options);
+        assert_eq!(suitability.score, 44.0);
+        assert_close(suitability.percentage, 44.0 / 60.0 * 100.0);
+    }
+
+    #[test]
+    fn test_familiarity_penalty() {
+        let mut player = Player::new("Winger".to_string(), 23, Position::AMR);
+        player.finishing = Some(16);
+        player.position_familiarity.insert(Position::STC, Familiarity::Competent);
+        let poacher = role(1, "Poacher", &[(Attribute::Finishing, 1.0)]);
+
+        // Role positions are ignored unless a penalty is asked for
+        assert_close(score_role(&player, &poacher, &ScoringOptions::default()).percentage, 80.0);
+
+        let options = ScoringOptions {
+            familiarity: Some(FamiliarityMultipliers::default()),
+            ..ScoringOptions::default()
+        };
+        let suitability = score_role(&player, &poacher, &options);
+        assert_close(suitability.score, 16.0 * 0.85);
+        assert_close(suitability.percentage, 80.0 * 0.85);
+
+        player.position = Position::STC;
+        player.position_familiarity.clear();
+        assert_close(score_role(&player, &poacher, &options).percentage, 80.0);
+    }
+
+    #[test]
+    fn test_score_roles_best_first() {
+        let mut player = Player::new("Playmaker".to_string(), 27, Position::MC);
+        player.passing = Some(18);
+        player.tackling = Some(6);
+
+        let roles = vec![
+            role(1, "Ball Winner", &[(Attribute::Tackling, 1.0)]),
+            role(2, "Playmaker", &[(Attribute::Passing, 1.0)]),
+            role(3, "Empty", &[]),
+        ];
+        let scores = score_roles(&player, &roles, &ScoringOptions::default());
+        let names: Vec<&str> = scores.iter().map(|s| s.role_name.as_str()).collect();
+        assert_eq!(names, vec!["Playmaker", "Ball Winner", "Empty"]);
+        assert_eq!(scores[2].percentage, 0.0);
+    }
+
+    #[test]
+    fn test_min_confidence() {
+        let mut player = Player::new("Unknown".to_string(), 19, Position::MC);
+        player.passing = Some(15);
+
+        let roles = vec![
+            role(1, "Playmaker", &[(Attribute::Passing, 1.0), (Attribute::Vision, 1.0)]),
+            role(2, "Passer", &[(Attribute::Passing, 1.0)]),
+        ];
+        let options = ScoringOptions::new(MissingAttributePolicy::Ignore, 0.75, &[]);
+        let scores = score_roles(&player, &roles, &options);
+        assert_eq!(scores.len(), 1);
+        assert_eq!(scores[0].role_name, "Passer");
+    }
+}
@@ -0,0 +1,575 @@
+//! Starting XI selection.
+//!
+//! Every player is scored for every slot of a formation, and players are assigned to slots
+//! so that the total suitability is as high as possible. This is the assignment problem,
+//! solved exactly with the Hungarian algorithm rather than by filling slots one at a time,
+//! which can leave a later slot with nobody suitable. Players are considered in id order,
+//! so identical inputs always produce the same XI.
+//!
+//! Lineup constraints are applied first: locked players take their slots whatever they
+//! score, and excluded players, or those capped below a starter's minutes, are left out.
+//!
+//! Formations are compared by picking the best XI for each of them.
+//!
+//! The depth chart ranks the best players for every slot independently, so a player can
+//! cover several slots.
+
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use crate::models::{
+    DepthChartPlayer, Formation, FormationComparison, FormationFit, LineupConstraints, Player, Role, SlotDepth, Squad,
+    SquadPlayer, UnfitFormation,
+};
+use crate::scoring::{score_role, ScoringOptions};
+
+/// Pick the starting XI for a formation. `roles` holds the role scored for each of the
+/// formation's slots, in order. When the constraints can't be met the error lists every
+/// conflict.
+pub fn select_starting_xi(
+    formation: &Formation,
+    roles: &[Role],
+    players: &[Player],
+    options: &ScoringOptions,
+    constraints: &LineupConstraints,
+) -> Result<Squad, String> {
+    check_roles(formation, roles)?;
+    if players.len() < roles.len() {
+        return Err(format!(
+            "{} needs {} players but only {} are available",
+            formation.name,
+            roles.len(),
+            players.len()
+        ));
+    }
+    let conflicts = constraints.conflicts(formation, players);
+    if !conflicts.is_empty() {
+        return Err(format!("The lineup constraints can't be met: {}", conflicts.join("; ")));
+    }
+
+    let mut players: Vec<&Player> = players.iter().collect();
+    players.sort_by_key(|player| player.id);
+
+    // The player picked for each slot, with their suitability
+    let mut picked: Vec<Option<(&Player, f32)>> = vec![None; roles.len()];
+    for locked in &constraints.locked {
+        let player = players.iter().copied().find(|player| player.id == Some(locked.player_id));
+        if let Some(player) = player {
+            picked[locked.slot] = Some((player, score_role(player, &roles[locked.slot], options).percentage));
+        }
+    }
+
+    let open_slots: Vec<usize> = (0..roles.len()).filter(|slot| picked[*slot].is_none()).collect();
+    let candidates: Vec<&Player> = players
+        .iter()
+        .copied()
+        .filter(|player| {
+            let id = player.id.unwrap_or_default();
+            constraints.can_start(id) && constraints.locked_slot(id).is_none()
+        })
+        .collect();
+
+    let open_roles: Vec<&Role> = open_slots.iter().map(|slot| &roles[*slot]).collect();
+    for (slot, assigned) in open_slots.iter().zip(assign_players(&open_roles, &candidates, options)) {
+        let Some(assigned) = assigned else {
+            return Err(format!(
+                "Not enough players can be scored confidently for {} ({})",
+                roles[*slot].name,
+                formation.positions[*slot].position.as_str()
+            ));
+        };
+        picked[*slot] = Some(assigned);
+    }
+
+    let mut squad = Squad::new(format!("Best XI ({})", formation.name), formation.id.unwrap_or_default());
+    let mut starters = Vec::new();
+    for (position, (player, percentage)) in formation.positions.iter().zip(picked.into_iter().flatten()) {
+        squad.add_starter(SquadPlayer {
+            player_id: player.id.unwrap_or_default(),
+            position: position.position,
+            tactical_role: Some(position.tactical_role_or_default()),
+            suitability: Some(percentage),
+        });
+        starters.push(player);
+    }
+
+    squad.calculate_average_rating();
+    squad.total_value = Some(starters.iter().filter_map(|p| p.value).sum());
+    squad.total_wage = Some(starters.iter().filter_map(|p| p.wage).sum());
+    squad.constraints = constraints.clone();
+    Ok(squad)
+}
+
+/// Fail unless there is a role for each of the formation's slots
+pub(crate) fn check_roles(formation: &Formation, roles: &[Role]) -> Result<(), String> {
+    if roles.len() != formation.positions.len() {
+        return Err(format!(
+            "Expected a role for each of the formation's {} slots, got {}",
+            formation.positions.len(),
+            roles.len()
+        ));
+    }
+    Ok(())
+}
+
+/// Assign players to roles so the total suitability is as high as possible, each player
+/// filling at most one role. Returns the player picked for each role with their suitability,
+/// or `None` when the players run out or nobody left can be scored confidently for it.
+/// Ties go to the earlier player.
+pub fn assign_players<'a>(roles: &[&Role], players: &[&'a Player], options: &ScoringOptions) -> Vec<Option<(&'a Player, f32)>> {
+    // suitability[role][player]; None when the player's score is not confident enough. Short
+    // of players, the rows are padded with nobody so every role can still be assigned.
+    let suitability: Vec<Vec<Option<f32>>> = roles
+        .iter()
+        .map(|role| {
+            let mut row: Vec<Option<f32>> = players
+                .iter()
+                .map(|player| {
+                    let suitability = score_role(player, role, options);
+                    options.accepts(&suitability).then_some(suitability.percentage)
+                })
+                .collect();
+            row.resize(players.len().max(roles.len()), None);
+            row
+        })
+        .collect();
+
+    maximum_assignment(&suitability)
+        .into_iter()
+        .enumerate()
+        .map(|(row, column)| Some((*players.get(column)?, suitability[row][column]?)))
+        .collect()
+}
+
+/// What formations are ranked by. Ties go to the other measures, then the formation's name.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FormationRanking {
+    /// The best XI's average suitability
+    #[default]
+    Average,
+    /// The best XI's summed suitability
+    Total,
+    /// The suitability of the best XI's worst fitting starter
+    Weakest,
+}
+
+impl FormationRanking {
+    /// The measures to compare, most important first
+    fn measures(&self, fit: &FormationFit) -> [f32; 3] {
+        let weakest = fit.weakest_slot.suitability.unwrap_or_default();
+        match self {
+            FormationRanking::Average => [fit.average_suitability, weakest, fit.total_suitability],
+            FormationRanking::Total => [fit.total_suitability, fit.average_suitability, weakest],
+            FormationRanking::Weakest => [weakest, fit.average_suitability, fit.total_suitability],
+        }
+    }
+}
+
+/// Pick the best XI for every formation and rank the formations by it. Each formation comes
+/// with the role scored for each of its slots, in order.
+pub fn compare_formations(
+    formations: &[(Formation, Vec<Role>)],
+    players: &[Player],
+    options: &ScoringOptions,
+    ranking: FormationRanking,
+) -> FormationComparison {
+    let mut fits = Vec::new();
+    let mut unfit = Vec::new();
+    for (formation, roles) in formations {
+        let formation_id = formation.id.unwrap_or_default();
+        let squad = select_starting_xi(formation, roles, players, options, &LineupConstraints::default());
+        let weakest = squad.as_ref().ok().and_then(|squad| {
+            squad
+                .starting_xi
+                .iter()
+                .min_by(|a, b| a.suitability.unwrap_or_default().total_cmp(&b.suitability.unwrap_or_default()))
+                .cloned()
+        });
+        match (squad, weakest) {
+            (Ok(squad), Some(weakest_slot)) => fits.push(FormationFit {
+                formation_id,
+                formation_name: formation.name.clone(),
+                average_suitability: squad.average_rating.unwrap_or_default(),
+                total_suitability: squad.starting_xi.iter().filter_map(|starter| starter.suitability).sum(),
+                weakest_slot,
+                squad,
+            }),
+            (Ok(_), None) => unfit.push(UnfitFormation {
+                formation_id,
+                formation_name: formation.name.clone(),
+                reason: format!("{} has no slots", formation.name),
+            }),
+            (Err(reason), _) => unfit.push(UnfitFormation {
+                formation_id,
+                formation_name: formation.name.clone(),
+                reason,
+            }),
+        }
+    }
+
+    fits.sort_by(|a, b| {
+        let (a_measures, b_measures) = (ranking.measures(a), ranking.measures(b));
+        a_measures
+            .iter()
+            .zip(&b_measures)
+            .map(|(a, b)| b.total_cmp(a))
+            .find(|order| *order != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.formation_name.cmp(&b.formation_name))
+    });
+    FormationComparison { ranking: fits, unfit }
+}
+
+/// Players ranked per slot when no depth is asked for
+pub const DEFAULT_DEPTH: usize = 3;
+
+/// Suitability percentage a slot's second choice should reach when no threshold is asked for
+pub const DEFAULT_COVER_THRESHOLD: f32 = 60.0;
+
+/// Rank the `depth` best suited players for each of a formation's slots. `roles` holds the
+/// role scored for each slot, in order. A slot is flagged as thin when its second choice
+/// is missing or scores below `cover_threshold`.
+pub fn depth_chart(
+    formation: &Formation,
+    roles: &[Role],
+    players: &[Player],
+    options: &ScoringOptions,
+    depth: usize,
+    cover_threshold: f32,
+) -> Vec<SlotDepth> {
+    formation
+        .positions
+        .iter()
+        .zip(roles)
+        .map(|(slot, role)| {
+            let mut ranked: Vec<DepthChartPlayer> = players
+                .iter()
+                .filter_map(|player| {
+                    let suitability = score_role(player, role, options);
+                    options.accepts(&suitability).then(|| DepthChartPlayer {
+                        player_id: player.id.unwrap_or_default(),
+                        suitability: suitability.percentage,
+                    })
+                })
+                .collect();
+            ranked.sort_by(|a, b| b.suitability.total_cmp(&a.suitability).then(a.player_id.cmp(&b.player_id)));
+
+            let thin_cover = ranked.get(1).is_none_or(|second| second.suitability < cover_threshold);
+            ranked.truncate(depth);
+            SlotDepth {
+                position: slot.position,
+                tactical_role: slot.tactical_role_or_default(),
+                players: ranked,
+                thin_cover,
+            }
+        })
+        .collect()
+}
+
+/// Assign each row (slot) a distinct column (player) so the total is as high as possible.
+/// `None` entries are only used when there is no other way to fill every row.
+/// Requires at least as many columns as rows; returns the column chosen for each row.
+pub fn maximum_assignment(scores: &[Vec<Option<f32>>]) -> Vec<usize> {
+    // Turn the maximisation into a minimisation; unusable pairs cost more than any
+    // combination of usable ones
+    let unusable = 1.0 + scores
+        .iter()
+        .flatten()
+        .flatten()
+        .map(|score| score.abs() as f64)
+        .sum::<f64>()
+        * 2.0;
+    let cost: Vec<Vec<f64>> = scores
+        .iter()
+        .map(|row| row.iter().map(|score| score.map_or(unusable, |s| -(s as f64))).collect())
+        .collect();
+    hungarian(&cost)
+}
+
+/// Minimum-cost assignment of rows to distinct columns (rows <= columns), using the
+/// Hungarian algorithm with potentials in O(rows² · columns).
+fn hungarian(cost: &[Vec<f64>]) -> Vec<usize> {
+    let rows = cost.len();
+    let columns = cost.first().map_or(0, Vec::len);
+
+    // 1-based, with column 0 as a virtual start; row_of[column] is the row assigned to it
+    let mut row_potential = vec![0.0; rows + 1];
+    let mut column_potential = vec![0.0; columns + 1];
+    let mut row_of = vec![0; columns + 1];
+    let mut previous = vec![0; columns + 1];
+
+    for row in 1..=rows {
+        row_of[0] = row;
+        let mut column = 0;
+        let mut min_slack = vec![f64::INFINITY; columns + 1];
+        let mut visited = vec![false; columns + 1];
+
+        // Grow an alternating path until it reaches a free column
+        loop {
+            visited[column] = true;
+            let current_row = row_of[column];
+            let mut delta = f64::INFINITY;
+            let mut next_column = 0;
+            for candidate in 1..=columns {
+                if visited[candidate] {
+                    continue;
+                }
+                let slack = cost[current_row - 1][candidate - 1]
+                    - row_potential[current_row]
+                    - column_potential[candidate];
+                if slack < min_slack[candidate] {
+                    min_slack[candidate] = slack;
+                    previous[candidate] = column;
+                }
+                if min_slack[candidate] < delta {
+                    delta = min_slack[candidate];
+                    next_column = candidate;
+                }
+            }
+            for candidate in 0..=columns {
+                if visited[candidate] {
+                    row_potential[row_of[candidate]] += delta;
+                    column_potential[candidate] -= delta;
+                } else {
+                    min_slack[candidate] -= delta;
+                }
+            }
+            column = next_column;
+            if row_of[column] == 0 {
+                break;
+            }
+        }
+
+        // Flip the path
+        while column != 0 {
+            let previous_column = previous[column];
+            row_of[column] = row_of[previous_column];
+            column = previous_column;
+        }
+    }
+
+    let mut assignment = vec![0; rows];
+    for (column, &row) in row_of.iter().enumerate().skip(1) {
+        if row != 0 {
+            assignment[row - 1] = column - 1;
+        }
+    }
+    assignment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{formation, players, role, two_a_side};
+    use crate::models::{
+        Attribute, Duty, ExcludedPlayer, ExclusionReason, LockedPlayer, MinutesCap, Position, RoleType, TacticalRole,
+    };
+
+    fn total(scores: &[Vec<Option<f32>>], assignment: &[usize]) -> f32 {
+        assignment.iter().enumerate().map(|(row, &column)| scores[row][column].unwrap()).sum()
+    }
+
+    /// Best total by trying every assignment
+    fn brute_force(scores: &[Vec<Option<f32>>], row: usize, used: &mut Vec<bool>) -> f32 {
+        if row == scores.len() {
+            return 0.0;
+        }
+        let mut best = f32::NEG_INFINITY;
+        for column in 0..used.len() {
+            if !used[column] {
+                used[column] = true;
+                best = best.max(scores[row][column].unwrap() + brute_force(scores, row + 1, used));
+                used[column] = false;
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn test_beats_greedy() {
+        // Filling row 0 first with its best column (0) would leave row 1 with 10
+        let scores = vec![vec![Some(90.0), Some(85.0)], vec![Some(80.0), Some(10.0)]];
+        let assignment = maximum_assignment(&scores);
+        assert_eq!(assignment, vec![1, 0]);
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        // Deterministic pseudo-random matrices
+        let mut seed: u32 = 7;
+        let mut next = || {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            (seed >> 16) % 100
+        };
+        for (rows, columns) in [(3, 3), (4, 6), (5, 5), (6, 8)] {
+            let scores: Vec<Vec<Option<f32>>> = (0..rows)
+                .map(|_| (0..columns).map(|_| Some(next() as f32)).collect())
+                .collect();
+            let assignment = maximum_assignment(&scores);
+
+            let mut distinct = assignment.clone();
+            distinct.sort();
+            distinct.dedup();
+            assert_eq!(distinct.len(), rows);
+            assert_eq!(total(&scores, &assignment), brute_force(&scores, 0, &mut vec![false; columns]));
+        }
+    }
+
+    #[test]
+    fn test_avoids_unusable_pairs() {
+        let scores = vec![vec![None, Some(40.0)], vec![Some(10.0), Some(90.0)]];
+        assert_eq!(maximum_assignment(&scores), vec![1, 0]);
+    }
+
+    #[test]
+    fn test_select_starting_xi() {
+        let (mut formation, roles) = two_a_side();
+        formation.id = Some(3);
+        let mut players = players();
+        players.swap(0, 1);
+
+        let squad = select_starting_xi(&formation, &roles, &players, &ScoringOptions::default(), &LineupConstraints::default()).unwrap();
+        assert_eq!(squad.formation_id, 3);
+        let picked: Vec<(i64, Option<f32>)> = squad.starting_xi.iter().map(|s| (s.player_id, s.suitability)).collect();
+        assert_eq!(picked, vec![(1, Some(80.0)), (2, Some(75.0))]);
+        assert_eq!(squad.starting_xi[1].tactical_role.unwrap().role, RoleType::AdvancedForward);
+        assert_eq!(squad.starting_xi[1].tactical_role.unwrap().duty, Duty::Attack);
+        assert_eq!(squad.average_rating, Some(77.5));
+        assert_eq!(squad.total_value, Some(3_000_000));
+
+        // The same inputs in another order give the same XI
+        let mut reversed = players.clone();
+        reversed.reverse();
+        let again = select_starting_xi(&formation, &roles, &reversed, &ScoringOptions::default(), &LineupConstraints::default()).unwrap();
+        let again: Vec<i64> = again.starting_xi.iter().map(|s| s.player_id).collect();
+        assert_eq!(again, vec![1, 2]);
+
+        let none = LineupConstraints::default();
+        assert!(select_starting_xi(&formation, &roles, &players[..1], &ScoringOptions::default(), &none).is_err());
+    }
+
+    #[test]
+    fn test_select_starting_xi_with_constraints() {
+        let (formation, roles) = two_a_side();
+        let players = players();
+        let pick = |constraints: &LineupConstraints| {
+            select_starting_xi(&formation, &roles, &players, &ScoringOptions::default(), constraints)
+                .map(|squad| squad.starting_xi.iter().map(|s| s.player_id).collect::<Vec<_>>())
+        };
+
+        let injured = LineupConstraints {
+            excluded: vec![ExcludedPlayer { player_id: 2, reason: ExclusionReason::Injured }],
+            ..Default::default()
+        };
+        assert_eq!(pick(&injured), Ok(vec![1, 3]));
+
+        let capped = LineupConstraints {
+            minutes_caps: vec![MinutesCap { player_id: 2, max_minutes: 30 }],
+            ..Default::default()
+        };
+        assert_eq!(pick(&capped), Ok(vec![1, 3]));
+
+        // Locked players start where they are put, however poorly they fit
+        let locked = LineupConstraints {
+            locked: vec![LockedPlayer { player_id: 2, slot: 0 }],
+            ..Default::default()
+        };
+        assert_eq!(pick(&locked), Ok(vec![2, 3]));
+
+        let conflicting = LineupConstraints {
+            locked: vec![LockedPlayer { player_id: 2, slot: 1 }],
+            excluded: vec![ExcludedPlayer { player_id: 2, reason: ExclusionReason::Suspended }],
+            ..Default::default()
+        };
+        let error = pick(&conflicting).unwrap_err();
+        assert!(error.contains("Striker is locked into slot 1 (STC) but excluded (suspended)"), "{}", error);
+    }
+
+    /// The fixture players with the sub able to play centre-back, and formations their best
+    /// XIs rank differently by each measure
+    fn compare(ranking: FormationRanking, formations: &[(Formation, Vec<Role>)]) -> FormationComparison {
+        let mut players = players();
+        players[2].tackling = Some(14);
+        compare_formations(formations, &players, &ScoringOptions::default(), ranking)
+    }
+
+    fn formations() -> Vec<(Formation, Vec<Role>)> {
+        let keeper = || role("Goalkeeper", Position::GK, &[(Attribute::Reflexes, 1.0)]);
+        let striker = || role("Advanced Forward", Position::STC, &[(Attribute::Finishing, 1.0)]);
+        let defender = || role("Central Defender", Position::DC, &[(Attribute::Tackling, 1.0)]);
+        let with_id = |id: i64, name: &str, positions: &[Position], roles: Vec<Role>| {
+            let mut formation = formation(name, positions);
+            formation.id = Some(id);
+            (formation, roles)
+        };
+        vec![
+            // 80%, 75% and 60%
+            with_id(1, "Attacking", &[Position::GK, Position::STC, Position::STC], vec![keeper(), striker(), striker()]),
+            // 80% and 75%
+            with_id(2, "Balanced", &[Position::GK, Position::STC], vec![keeper(), striker()]),
+            // 80% and the sub's 70%
+            with_id(3, "Defensive", &[Position::GK, Position::DC], vec![keeper(), defender()]),
+            // The sub's 70%
+            with_id(4, "Sweeper", &[Position::DC], vec![defender()]),
+            (Formation::new("Empty".to_string(), Vec::new()), Vec::new()),
+        ]
+    }
+
+    #[test]
+    fn test_compare_formations() {
+        let names = |comparison: &FormationComparison| {
+            comparison.ranking.iter().map(|fit| fit.formation_name.clone()).collect::<Vec<_>>()
+        };
+
+        let average = compare(FormationRanking::Average, &formations());
+        assert_eq!(names(&average), vec!["Balanced", "Defensive", "Attacking", "Sweeper"]);
+        assert_eq!(average.ranking[0].total_suitability, 155.0);
+        assert_eq!(average.ranking[1].weakest_slot.player_id, 3);
+        assert_eq!(average.unfit[0].formation_name, "Empty");
+
+        // The extra striker adds more than the others' whole XIs, though at only 60%
+        let total = compare(FormationRanking::Total, &formations());
+        assert_eq!(names(&total), vec!["Attacking", "Balanced", "Defensive", "Sweeper"]);
+        assert_eq!(total.ranking[0].total_suitability, 215.0);
+
+        // A lone 70% beats a 60% slot however good the rest are; ties go to the average
+        let weakest = compare(FormationRanking::Weakest, &formations());
+        assert_eq!(names(&weakest), vec!["Balanced", "Defensive", "Sweeper", "Attacking"]);
+        assert!((weakest.ranking[3].weakest_slot.suitability.unwrap() - 60.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_compare_with_default_roles() {
+        let poacher = TacticalRole { position: Position::STC, role: RoleType::Poacher, duty: Duty::Attack };
+        let mut formations = formations();
+        formations[1].0.positions[1].tactical_role = Some(poacher);
+        let picked_role = |formations: &[(Formation, Vec<Role>)]| {
+            let comparison = compare(FormationRanking::Average, formations);
+            comparison.ranking[0].squad.starting_xi[1].tactical_role
+        };
+        assert_eq!(picked_role(&formations), Some(poacher));
+
+        let defaults: Vec<(Formation, Vec<Role>)> = formations
+            .iter()
+            .map(|(formation, roles)| (formation.with_default_roles(), roles.clone()))
+            .collect();
+        assert_eq!(picked_role(&defaults), Some(TacticalRole::default_for(Position::STC)));
+        assert!(defaults.iter().all(|(formation, _)| formation.positions.iter().all(|slot| slot.tactical_role.is_none())));
+    }
+
+    #[test]
+    fn test_depth_chart() {
+        let (formation, roles) = two_a_side();
+        let players = players();
+
+        let chart = depth_chart(&formation, &roles, &players, &ScoringOptions::default(), 2, 50.0);
+        let ranked = |slot: &SlotDepth| slot.players.iter().map(|p| p.player_id).collect::<Vec<_>>();
+        assert_eq!(ranked(&chart[0]), vec![1, 2]);
+        assert_eq!(ranked(&chart[1]), vec![2, 3]);
+        assert!((chart[1].players[1].suitability - 60.0).abs() < 0.01);
+
+        // The backup keeper only scores 25%
+        assert!(chart[0].thin_cover);
+        assert!(!chart[1].thin_cover);
+    }
+}